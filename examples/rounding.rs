@@ -1,6 +1,6 @@
 //! Rounding modes demonstration.
 //!
-//! This example demonstrates all 7 rounding modes available in the library,
+//! This example demonstrates all rounding modes available in the library,
 //! showing when to use each one and how they behave with different values.
 //!
 //! Run with: `cargo run --example rounding`
@@ -11,7 +11,7 @@ fn main() {
     println!("=== Rounding Modes Demonstration ===\n");
 
     // ========================================
-    // The Seven Rounding Modes
+    // The Rounding Modes
     // ========================================
     println!("1. ALL ROUNDING MODES");
     println!("---------------------");
@@ -48,6 +48,18 @@ fn main() {
         "  Ceiling:   {} (towards +∞)",
         amount.round(RoundingMode::Ceiling)
     );
+    println!(
+        "  HalfOdd:   {} (ties to odd)",
+        amount.round(RoundingMode::HalfOdd)
+    );
+    println!(
+        "  Round05Up: {} (truncate, unless last digit is 0 or 5)",
+        amount.round(RoundingMode::Round05Up)
+    );
+    println!(
+        "  Stochastic: {} (random, weighted by distance)",
+        amount.round(RoundingMode::Stochastic { seed: 42 })
+    );
 
     // ========================================
     // HalfUp - Most Common
@@ -196,6 +208,15 @@ fn main() {
     println!("  ✓ Always round up for safety margin");
     println!("  ✓ Ensure coverage of costs");
 
+    println!("\nHalfAwayFromZero / HalfTowardZero:");
+    println!("  ✓ Same as HalfUp / HalfDown, explicit about negatives");
+
+    println!("\nRound05Up:");
+    println!("  ✓ Intermediate results that will be rounded again");
+
+    println!("\nStochastic:");
+    println!("  ✓ Many small fees whose rounded total must stay unbiased");
+
     // ========================================
     // Real-World: Tax Calculation
     // ========================================
@@ -3,11 +3,15 @@
 //! All operations are pure functions that create new values (immutable).
 //! Cross-currency operations are prevented at compile time by the type system.
 
-use super::type_def::Amount;
-use crate::Currency;
+use super::rounding::{apply_context, round_dp, round_to_context};
+use super::type_def::{value_ref, Amount};
+use crate::{Currency, MoneyError, MoneyResult, RoundingMode};
 use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Sub};
 
+#[cfg(feature = "operation_tracking")]
+use crate::conversion_tracking::{Operation, OperationEvent};
+
 #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
 use rust_decimal::Decimal;
 
@@ -52,10 +56,18 @@ impl<C: Currency> Add for Amount<C> {
 
     #[inline]
     fn add(self, other: Self) -> Self {
-        Self {
-            value: self.value + other.value,
+        let result = Self {
+            value: value_ref(&self.value) + value_ref(&other.value),
             _currency: PhantomData,
-        }
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Add, C::CODE, &self.value, &result.value)
+                .with_operand(&other.value)
+        });
+
+        result
     }
 }
 
@@ -93,10 +105,18 @@ impl<C: Currency> Sub for Amount<C> {
 
     #[inline]
     fn sub(self, other: Self) -> Self {
-        Self {
-            value: self.value - other.value,
+        let result = Self {
+            value: value_ref(&self.value) - value_ref(&other.value),
             _currency: PhantomData,
-        }
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Subtract, C::CODE, &self.value, &result.value)
+                .with_operand(&other.value)
+        });
+
+        result
     }
 }
 
@@ -121,10 +141,18 @@ impl<C: Currency> Mul<i64> for Amount<C> {
 
     #[inline]
     fn mul(self, scalar: i64) -> Self {
-        Self {
-            value: self.value * Decimal::from(scalar),
+        let result = Self {
+            value: value_ref(&self.value) * Decimal::from(scalar),
             _currency: PhantomData,
-        }
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Multiply, C::CODE, &self.value, &result.value)
+                .with_operand(&Decimal::from(scalar))
+        });
+
+        result
     }
 }
 
@@ -166,6 +194,13 @@ impl<C: Currency> Mul<Amount<C>> for i64 {
 /// assert_eq!(per_person.to_major_floor(), 25);
 /// ```
 ///
+/// # Context
+///
+/// The quotient is rounded to the precision of the current
+/// [`MoneyContext`](crate::MoneyContext), if one is set.
+/// Conditions the quotient raises are flagged on the context but never trapped;
+/// use [`Amount::checked_div`] to have trapped conditions reported as errors.
+///
 /// # Panics
 ///
 /// Panics if dividing by zero.
@@ -176,13 +211,213 @@ impl<C: Currency> Div<i64> for Amount<C> {
     fn div(self, scalar: i64) -> Self {
         assert!(scalar != 0, "Cannot divide amount by zero");
 
-        Self {
-            value: self.value / Decimal::from(scalar),
+        let (quotient, exact) = self.quotient(scalar);
+        self.divided(round_to_context::<C>("division", quotient, exact), scalar)
+    }
+}
+
+impl<C: Currency> Amount<C> {
+    /// Divides the amount by a scalar, reporting errors instead of panicking.
+    ///
+    /// The quotient is rounded to the precision of the current
+    /// [`MoneyContext`](crate::MoneyContext), if one is set.
+    ///
+    /// # Errors
+    ///
+    /// - `MoneyError::InvalidAmount` if `divisor` is zero
+    /// - `MoneyError::RoundingError` if the context traps `Inexact` and the quotient
+    ///   had to be rounded to the context precision
+    /// - `MoneyError::PrecisionError` if the context traps `ExcessPrecision` and the
+    ///   quotient has more decimal places than the currency supports
+    /// - `MoneyError::Overflow` if the context traps `Overflow` and the quotient
+    ///   does not fit in `i64` minor units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyContext, MoneyError, Trap, USD};
+    ///
+    /// let total = Amount::<USD>::from_major(100);
+    /// assert_eq!(total.checked_div(4).unwrap().to_major_floor(), 25);
+    /// assert!(matches!(total.checked_div(0), Err(MoneyError::InvalidAmount { .. })));
+    ///
+    /// let strict = MoneyContext::new().with_trap(Trap::ExcessPrecision);
+    /// strict.scope(|| {
+    ///     assert!(total.checked_div(4).is_ok()); // $25.00
+    ///     assert!(matches!(total.checked_div(3), Err(MoneyError::PrecisionError { .. })));
+    /// });
+    /// ```
+    pub fn checked_div(&self, divisor: i64) -> MoneyResult<Self> {
+        if divisor == 0 {
+            return Err(MoneyError::InvalidAmount {
+                reason: "Cannot divide amount by zero".to_string(),
+                currency: Some(C::CODE),
+            });
+        }
+
+        let (quotient, exact) = self.quotient(divisor);
+        Ok(self.divided(apply_context::<C>("division", quotient, exact)?, divisor))
+    }
+
+    /// Divides by a non-zero scalar, returning the backend's quotient and
+    /// whether it was computed without rounding.
+    fn quotient(&self, divisor: i64) -> (Decimal, bool) {
+        let divisor = Decimal::from(divisor);
+        let quotient = value_ref(&self.value) / value_ref(&divisor);
+        let exact = is_exact_quotient(&self.value, &divisor, &quotient);
+        (quotient, exact)
+    }
+
+    /// Wraps a quotient and reports the division to the operation tracker.
+    #[cfg_attr(not(feature = "operation_tracking"), allow(unused_variables))]
+    fn divided(&self, value: Decimal, divisor: i64) -> Self {
+        let result = Self {
+            value,
             _currency: PhantomData,
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Divide, C::CODE, &self.value, &result.value)
+                .with_operand(&Decimal::from(divisor))
+        });
+
+        result
+    }
+
+    /// Divides the amount by a scalar, failing unless the quotient is exactly
+    /// representable with `scale` decimal places.
+    ///
+    /// Use this where a split must not lose a fraction of a minor unit, e.g.
+    /// `div_exact(n, C::DECIMALS)` before distributing a total evenly. The current
+    /// [`MoneyContext`](crate::MoneyContext) is not consulted.
+    ///
+    /// # Errors
+    ///
+    /// - `MoneyError::InvalidAmount` if `divisor` is zero
+    /// - `MoneyError::RoundingError` if the quotient has non-zero digits beyond
+    ///   `scale`, including non-terminating quotients such as `10 / 3`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyError, USD};
+    ///
+    /// let total = Amount::<USD>::from_major(10);
+    /// assert_eq!(total.div_exact(4, 2).unwrap().to_minor(), 250);
+    /// assert!(matches!(total.div_exact(3, 2), Err(MoneyError::RoundingError { .. })));
+    /// assert!(total.div_exact(8, 1).is_err()); // $1.25 needs two decimal places
+    /// ```
+    pub fn div_exact(&self, divisor: i64, scale: u32) -> MoneyResult<Self> {
+        if divisor == 0 {
+            return Err(MoneyError::InvalidAmount {
+                reason: "Cannot divide amount by zero".to_string(),
+                currency: Some(C::CODE),
+            });
+        }
+
+        let divisor_value = Decimal::from(divisor);
+        let quotient = value_ref(&self.value) / value_ref(&divisor_value);
+        if !is_exact_quotient(&self.value, &divisor_value, &quotient)
+            || round_dp(&quotient, scale, RoundingMode::Down) != quotient
+        {
+            return Err(MoneyError::RoundingError {
+                currency: C::CODE,
+                reason: format!(
+                    "{} / {} is not exactly representable with {} decimal places",
+                    self.value, divisor, scale
+                ),
+            });
         }
+
+        let result = Self {
+            value: quotient,
+            _currency: PhantomData,
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Divide, C::CODE, &self.value, &result.value)
+                .with_operand(&divisor_value)
+        });
+
+        Ok(result)
     }
 }
 
+// ============================================================================
+// Exactness Checks
+// ============================================================================
+
+/// Returns whether the backend computed `dividend / divisor` without rounding.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn is_exact_quotient(dividend: &Decimal, divisor: &Decimal, quotient: &Decimal) -> bool {
+    // The multiplication back can itself round (3.33...3 × 3 → 10), so it must
+    // be exact as well
+    quotient.checked_mul(*divisor).is_some_and(|product| {
+        product == *dividend && is_exact_product(quotient, divisor, &product)
+    })
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn is_exact_quotient(dividend: &Decimal, divisor: &Decimal, quotient: &Decimal) -> bool {
+    &(quotient * divisor) == dividend
+}
+
+/// Returns whether the backend computed `a * b` without rounding.
+///
+/// `rust_decimal` keeps the full scale of a product unless it would exceed 28
+/// decimal places or 96 bits, in which case the product is rounded. Products too
+/// large to verify with 128-bit integers are conservatively reported as inexact.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+pub(super) fn is_exact_product(a: &Decimal, b: &Decimal, product: &Decimal) -> bool {
+    let exact_scale = a.scale() + b.scale();
+    if product.scale() == exact_scale {
+        return true;
+    }
+
+    let Some(exact_mantissa) = a.mantissa().checked_mul(b.mantissa()) else {
+        return false;
+    };
+    10_i128
+        .checked_pow(exact_scale - product.scale())
+        .and_then(|factor| product.mantissa().checked_mul(factor))
+        == Some(exact_mantissa)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+pub(super) fn is_exact_product(_a: &Decimal, _b: &Decimal, _product: &Decimal) -> bool {
+    true
+}
+
+// ============================================================================
+// Overflow Handling
+// ============================================================================
+
+/// Multiplies two values, reporting overflow as an error instead of panicking.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+pub(super) fn checked_mul_values(
+    a: &Decimal,
+    b: &Decimal,
+    operation: &str,
+    currency: &'static str,
+) -> MoneyResult<Decimal> {
+    a.checked_mul(*b).ok_or_else(|| MoneyError::Overflow {
+        operation: operation.to_string(),
+        currency,
+    })
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+pub(super) fn checked_mul_values(
+    a: &Decimal,
+    b: &Decimal,
+    _operation: &str,
+    _currency: &'static str,
+) -> MoneyResult<Decimal> {
+    Ok(a * b)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -399,4 +634,176 @@ mod tests {
         assert_eq!(original.to_major_floor(), 100);
         assert_eq!(doubled.to_major_floor(), 200);
     }
+
+    // Context tests
+    #[test]
+    fn test_checked_div() {
+        let total = Amount::<USD>::from_major(100);
+        assert_eq!(total.checked_div(4).unwrap(), total / 4);
+        assert!(matches!(
+            total.checked_div(0),
+            Err(MoneyError::InvalidAmount {
+                currency: Some("USD"),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_div_rounds_to_context_precision() {
+        use crate::{MoneyContext, RoundingMode};
+
+        let total = Amount::<USD>::from_major(100);
+        let context = MoneyContext::new()
+            .with_precision(2)
+            .with_rounding(RoundingMode::Ceiling);
+        let share = context.scope(|| total / 3);
+
+        assert_eq!(share, Amount::<USD>::from_minor(3_334));
+        assert!(!share.has_excess_precision());
+        // Outside the scope, division is unrounded again
+        assert!((total / 3).has_excess_precision());
+    }
+
+    #[test]
+    fn test_checked_div_traps() {
+        use crate::{MoneyContext, Trap};
+
+        let total = Amount::<USD>::from_major(100);
+
+        let inexact = MoneyContext::new()
+            .with_precision(2)
+            .with_trap(Trap::Inexact);
+        inexact.scope(|| {
+            assert!(total.checked_div(4).is_ok());
+            assert!(matches!(
+                total.checked_div(3),
+                Err(MoneyError::RoundingError { .. })
+            ));
+        });
+
+        let excess = MoneyContext::new().with_trap(Trap::ExcessPrecision);
+        excess.scope(|| {
+            assert!(matches!(
+                total.checked_div(3),
+                Err(MoneyError::PrecisionError { .. })
+            ));
+        });
+
+        // A quotient beyond i64 minor units, e.g. $9.2e18 / 1
+        let huge = Amount::<USD>::from_major(i64::MAX);
+        MoneyContext::new().scope(|| {
+            assert!(huge.checked_div(1).is_ok());
+            assert!(MoneyContext::current().is_flagged(Trap::Overflow));
+        });
+        let overflow = MoneyContext::new().with_trap(Trap::Overflow);
+        overflow.scope(|| {
+            assert!(total.checked_div(4).is_ok());
+            assert!(matches!(
+                huge.checked_div(1),
+                Err(MoneyError::Overflow {
+                    currency: "USD",
+                    ..
+                })
+            ));
+        });
+    }
+
+    #[test]
+    fn test_div_operator_ignores_traps() {
+        use crate::{MoneyContext, Trap};
+
+        let strict = MoneyContext::new()
+            .with_precision(2)
+            .with_trap(Trap::Inexact)
+            .with_trap(Trap::ExcessPrecision);
+        strict.scope(|| {
+            let share = Amount::<USD>::from_major(100) / 3;
+            assert_eq!(share, Amount::<USD>::from_minor(3_333));
+            assert!(MoneyContext::current().is_flagged(Trap::Inexact));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow")]
+    fn test_overflow_panics() {
+        let huge = Amount::<USD>::from_major(i64::MAX) * 1_000_000_000; // ~9.2e27
+        let _ = huge * 100;
+    }
+
+    // Exactness tests
+    #[test]
+    fn test_div_exact() {
+        let total = Amount::<USD>::from_major(10);
+        assert_eq!(
+            total.div_exact(4, 2).unwrap(),
+            Amount::<USD>::from_minor(250)
+        );
+        assert_eq!(
+            total.div_exact(8, 3).unwrap(),
+            Amount::<USD>::from_minor(125)
+        );
+        assert!(matches!(
+            total.div_exact(8, 1),
+            Err(MoneyError::RoundingError {
+                currency: "USD",
+                ..
+            })
+        ));
+        assert!(matches!(
+            total.div_exact(3, 28),
+            Err(MoneyError::RoundingError { .. })
+        ));
+        assert!(matches!(
+            total.div_exact(0, 2),
+            Err(MoneyError::InvalidAmount { .. })
+        ));
+    }
+
+    #[test]
+    fn test_division_flags_inexact_at_backend_limit() {
+        use crate::{MoneyContext, Trap};
+
+        MoneyContext::new().scope(|| {
+            let _ = Amount::<USD>::from_major(10) / 8;
+            assert!(!MoneyContext::current().is_flagged(Trap::Inexact));
+
+            let _ = Amount::<USD>::from_major(10) / 3;
+            assert!(MoneyContext::current().is_flagged(Trap::Inexact));
+            assert!(MoneyContext::current().is_flagged(Trap::ExcessPrecision));
+        });
+    }
+
+    #[test]
+    fn test_division_traps_inexact_at_backend_limit() {
+        use crate::{MoneyContext, Trap};
+
+        let strict = MoneyContext::new().with_trap(Trap::Inexact);
+        strict.scope(|| {
+            let total = Amount::<USD>::from_major(10);
+            // 10 / 8 = 1.25 is computed exactly
+            assert!(total.checked_div(8).is_ok());
+            assert!(matches!(
+                total.checked_div(3),
+                Err(MoneyError::RoundingError { .. })
+            ));
+        });
+    }
+
+    #[test]
+    fn test_is_exact_product() {
+        // 0.5 × 0.5 = 0.25 keeps its full scale
+        let half = Decimal::new(5, 1);
+        assert!(is_exact_product(&half, &half, &(half * half)));
+
+        // A product needing more than 28 decimal places is rounded
+        let tiny = Decimal::new(1, 20);
+        let small = Decimal::new(3, 10);
+        assert!(!is_exact_product(&tiny, &small, &(tiny * small)));
+
+        // Trailing zeros dropped by the backend do not count as rounding
+        let a = Decimal::new(20, 15);
+        let b = Decimal::new(50, 15);
+        assert!(is_exact_product(&a, &b, &(a * b)));
+    }
 }
@@ -0,0 +1,448 @@
+//! Compact binary encodings of Amount for storage and wire transport.
+
+use super::type_def::Amount;
+use crate::{known_currencies, Currency, MoneyError, MoneyResult};
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// Length of the fixed-size format.
+const BINARY_LEN: usize = 28;
+
+/// Length of the currency code field in the fixed-size format.
+const CODE_LEN: usize = 8;
+
+/// Longest zigzag LEB128 encoding of an `i64`.
+const MAX_VARINT_LEN: usize = 10;
+
+impl<C: Currency> Amount<C> {
+    /// Length in bytes of [`to_bytes`](Self::to_bytes) output.
+    pub const BINARY_LEN: usize = BINARY_LEN;
+
+    /// Version of the fixed-size format written by [`to_bytes`](Self::to_bytes).
+    pub const BINARY_VERSION: u8 = 1;
+
+    /// Encodes the amount in a fixed-size, versioned binary format.
+    ///
+    /// Version 1 is exactly [`BINARY_LEN`](Self::BINARY_LEN) (28) bytes:
+    ///
+    /// | Offset | Length | Field |
+    /// |--------|--------|-------|
+    /// | 0 | 1 | Format version, currently [`BINARY_VERSION`](Self::BINARY_VERSION) (1) |
+    /// | 1 | 2 | `ISO_4217_NUMBER`, unsigned big-endian (0 for currencies without one) |
+    /// | 3 | 8 | Currency code, ASCII, padded with zero bytes |
+    /// | 11 | 1 | Scale (number of decimal places) |
+    /// | 12 | 16 | Mantissa, two's-complement signed big-endian |
+    ///
+    /// The value is `mantissa × 10^-scale`, so the encoding is exact and does
+    /// not depend on the decimal backend. Both the code and the numeric code
+    /// are checked on decode, so cryptocurrencies (which share the number 0)
+    /// are still told apart. Later versions will change the version byte;
+    /// version 1 bytes will keep decoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the currency code is longer
+    /// than 8 bytes, and [`MoneyError::Overflow`] if the value does not fit in
+    /// a 128-bit mantissa with a scale of at most 255.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD};
+    ///
+    /// let bytes = Amount::<USD>::from_minor(1234).to_bytes()?;
+    /// assert_eq!(bytes.len(), Amount::<USD>::BINARY_LEN);
+    /// assert_eq!(&bytes[..6], &[1, 0x03, 0x48, b'U', b'S', b'D']);
+    /// assert_eq!(Amount::<USD>::from_bytes(&bytes)?, Amount::<USD>::from_minor(1234));
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_bytes(&self) -> MoneyResult<[u8; BINARY_LEN]> {
+        if C::CODE.len() > CODE_LEN {
+            return Err(MoneyError::InvalidAmount {
+                reason: format!(
+                    "Currency code '{}' is longer than {} bytes",
+                    C::CODE,
+                    CODE_LEN
+                ),
+                currency: Some(C::CODE),
+            });
+        }
+        let (mantissa, scale) = mantissa_and_scale(&self.value)
+            .and_then(|(mantissa, scale)| Some((mantissa, u8::try_from(scale).ok()?)))
+            .ok_or_else(|| MoneyError::Overflow {
+                operation: "binary encoding".to_string(),
+                currency: C::CODE,
+            })?;
+
+        let mut bytes = [0; BINARY_LEN];
+        bytes[0] = Self::BINARY_VERSION;
+        bytes[1..3].copy_from_slice(&C::ISO_4217_NUMBER.to_be_bytes());
+        bytes[3..3 + C::CODE.len()].copy_from_slice(C::CODE.as_bytes());
+        bytes[11] = scale;
+        bytes[12..].copy_from_slice(&mantissa.to_be_bytes());
+        Ok(bytes)
+    }
+
+    /// Decodes an amount written by [`to_bytes`](Self::to_bytes), checking
+    /// that it is in this currency.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the bytes hold another
+    /// known currency, and [`MoneyError::ParseError`] if they have the wrong
+    /// length, an unsupported version, an unknown or inconsistent currency, or
+    /// a value the decimal backend cannot represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyError, EUR, USD};
+    ///
+    /// let bytes = Amount::<EUR>::from_major(5).to_bytes()?;
+    /// assert!(matches!(
+    ///     Amount::<USD>::from_bytes(&bytes),
+    ///     Err(MoneyError::CurrencyMismatch { found: "EUR", .. })
+    /// ));
+    /// assert!(Amount::<EUR>::from_bytes(&bytes[..20]).is_err());
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> MoneyResult<Self> {
+        let corrupt = |reason: String| MoneyError::ParseError {
+            input: hex(bytes),
+            expected_currency: Some(C::CODE),
+            reason,
+        };
+
+        if bytes.len() != Self::BINARY_LEN {
+            return Err(corrupt(format!(
+                "Expected {} bytes, found {}",
+                Self::BINARY_LEN,
+                bytes.len()
+            )));
+        }
+        if bytes[0] != Self::BINARY_VERSION {
+            return Err(corrupt(format!(
+                "Unsupported binary format version {}",
+                bytes[0]
+            )));
+        }
+
+        let code_field = &bytes[3..11];
+        let code_len = code_field
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(CODE_LEN);
+        let (code, padding) = code_field.split_at(code_len);
+        if code.is_empty()
+            || !code.iter().all(u8::is_ascii_alphanumeric)
+            || padding.iter().any(|byte| *byte != 0)
+        {
+            return Err(corrupt("Corrupted currency code".to_string()));
+        }
+        // Checked above to be ASCII
+        let code = std::str::from_utf8(code).unwrap_or_default();
+        if code != C::CODE {
+            return match known_currencies()
+                .into_iter()
+                .find(|info| info.code() == code)
+            {
+                Some(info) => Err(MoneyError::CurrencyMismatch {
+                    expected: C::CODE,
+                    found: info.code(),
+                    context: "decoding a binary amount".to_string(),
+                }),
+                None => Err(corrupt(format!("Unknown currency code '{}'", code))),
+            };
+        }
+
+        let number = u16::from_be_bytes([bytes[1], bytes[2]]);
+        if number != C::ISO_4217_NUMBER {
+            return Err(corrupt(format!(
+                "ISO 4217 number {} does not match {} ({})",
+                number,
+                C::CODE,
+                C::ISO_4217_NUMBER
+            )));
+        }
+
+        let mut mantissa = [0; 16];
+        mantissa.copy_from_slice(&bytes[12..]);
+        let value = from_mantissa_and_scale(i128::from_be_bytes(mantissa), bytes[11].into())
+            .ok_or_else(|| corrupt("Value out of range for the decimal backend".to_string()))?;
+        Ok(Self::new(value))
+    }
+
+    /// Encodes the amount as whole minor units in a zigzag LEB128 varint.
+    ///
+    /// Values in `-64..64` take one byte and any `i64` at most ten. The
+    /// varint has no header and no currency, so it suits logs whose currency
+    /// is known from context; use [`to_bytes`](Self::to_bytes) otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::PrecisionError`] if the amount has more decimal
+    /// places than the currency, and [`MoneyError::Overflow`] if its minor
+    /// units do not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD};
+    ///
+    /// assert_eq!(Amount::<USD>::from_minor(-1).to_minor_varint()?, [0x01]);
+    /// assert_eq!(Amount::<USD>::from_minor(1234).to_minor_varint()?, [0xA4, 0x13]);
+    /// assert!((Amount::<USD>::from_major(1) / 3).to_minor_varint().is_err());
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_minor_varint(&self) -> MoneyResult<Vec<u8>> {
+        if !self.is_exact_at_currency_precision() {
+            return Err(MoneyError::PrecisionError {
+                currency: C::CODE,
+                expected: C::DECIMALS,
+                actual: mantissa_and_scale(&self.value).map_or(u32::MAX, |(_, scale)| scale),
+                suggestion: "Round to the currency precision before encoding minor units"
+                    .to_string(),
+            });
+        }
+        let minor = self
+            .checked_to_minor()
+            .ok_or_else(|| MoneyError::Overflow {
+                operation: "minor-unit varint encoding".to_string(),
+                currency: C::CODE,
+            })?;
+
+        let mut zigzag = ((minor << 1) ^ (minor >> 63)) as u64;
+        let mut bytes = Vec::with_capacity(MAX_VARINT_LEN);
+        while zigzag >= 0x80 {
+            bytes.push((zigzag as u8) | 0x80);
+            zigzag >>= 7;
+        }
+        bytes.push(zigzag as u8);
+        Ok(bytes)
+    }
+
+    /// Decodes a varint written by [`to_minor_varint`](Self::to_minor_varint)
+    /// from the start of `bytes`.
+    ///
+    /// Returns the amount and the number of bytes read, so consecutive varints
+    /// can be decoded from one buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`] if the varint is truncated or longer
+    /// than an `i64` allows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD};
+    ///
+    /// let log = [0xA4, 0x13, 0x01];
+    /// let (first, read) = Amount::<USD>::from_minor_varint(&log)?;
+    /// let (second, _) = Amount::<USD>::from_minor_varint(&log[read..])?;
+    /// assert_eq!(first, Amount::<USD>::from_minor(1234));
+    /// assert_eq!(second, Amount::<USD>::from_minor(-1));
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn from_minor_varint(bytes: &[u8]) -> MoneyResult<(Self, usize)> {
+        let corrupt = |reason: &str| MoneyError::ParseError {
+            input: hex(&bytes[..bytes.len().min(MAX_VARINT_LEN)]),
+            expected_currency: Some(C::CODE),
+            reason: reason.to_string(),
+        };
+
+        let mut zigzag = 0_u64;
+        for (index, byte) in bytes.iter().take(MAX_VARINT_LEN).enumerate() {
+            let bits = u64::from(byte & 0x7F);
+            // The tenth byte may only carry the top bit of a u64
+            if index == MAX_VARINT_LEN - 1 && bits > 1 {
+                return Err(corrupt("Varint too long for a 64-bit value"));
+            }
+            zigzag |= bits << (7 * index);
+            if byte & 0x80 == 0 {
+                let minor = ((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64);
+                return Ok((Self::from_minor(minor), index + 1));
+            }
+        }
+        if bytes.len() >= MAX_VARINT_LEN {
+            Err(corrupt("Varint too long for a 64-bit value"))
+        } else {
+            Err(corrupt("Truncated varint"))
+        }
+    }
+}
+
+/// Lowercase hex of `bytes`, used as the input of decoding errors.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn mantissa_and_scale(value: &Decimal) -> Option<(i128, u32)> {
+    Some((value.mantissa(), value.scale()))
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn mantissa_and_scale(value: &Decimal) -> Option<(i128, u32)> {
+    use bigdecimal::num_bigint::BigInt;
+    use bigdecimal::ToPrimitive;
+
+    let (unscaled, scale) = value.as_bigint_and_exponent();
+    if scale >= 0 {
+        return Some((unscaled.to_i128()?, u32::try_from(scale).ok()?));
+    }
+    // A negative scale means trailing zeros; 10^39 already overflows an i128
+    let exponent = u32::try_from(-scale)
+        .ok()
+        .filter(|exponent| *exponent < 39)?;
+    Some(((unscaled * BigInt::from(10).pow(exponent)).to_i128()?, 0))
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn from_mantissa_and_scale(mantissa: i128, scale: u32) -> Option<Decimal> {
+    Decimal::try_from_i128_with_scale(mantissa, scale).ok()
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn from_mantissa_and_scale(mantissa: i128, scale: u32) -> Option<Decimal> {
+    use bigdecimal::num_bigint::BigInt;
+
+    Some(Decimal::new(BigInt::from(mantissa), scale.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BTC, ETH, EUR, JPY, USD};
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let amounts = [
+            Amount::<USD>::from_minor(0),
+            Amount::<USD>::from_minor(-1234),
+            Amount::<USD>::from_major(1) / 3,
+            Amount::<USD>::from_minor(i64::MAX),
+        ];
+        for amount in amounts {
+            let bytes = amount.to_bytes().unwrap();
+            assert_eq!(Amount::<USD>::from_bytes(&bytes).unwrap(), amount);
+        }
+
+        let yen = Amount::<JPY>::from_major(500);
+        assert_eq!(
+            Amount::<JPY>::from_bytes(&yen.to_bytes().unwrap()).unwrap(),
+            yen
+        );
+    }
+
+    #[test]
+    fn test_bytes_layout_is_stable() {
+        let bytes = Amount::<EUR>::from_minor(-150).to_bytes().unwrap();
+        let mut expected = vec![1, 0x03, 0xD2, b'E', b'U', b'R', 0, 0, 0, 0, 0, 2];
+        expected.extend_from_slice(&(-150_i128).to_be_bytes());
+        assert_eq!(bytes.to_vec(), expected);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_mismatch_and_corruption() {
+        let btc = Amount::<BTC>::from_major(1).to_bytes().unwrap();
+        assert!(matches!(
+            Amount::<ETH>::from_bytes(&btc),
+            Err(MoneyError::CurrencyMismatch {
+                expected: "ETH",
+                found: "BTC",
+                ..
+            })
+        ));
+
+        let usd = Amount::<USD>::from_minor(1234).to_bytes().unwrap();
+        let reason = |bytes: &[u8]| match Amount::<USD>::from_bytes(bytes) {
+            Err(MoneyError::ParseError { reason, .. }) => reason,
+            other => panic!("expected a parse error, got {:?}", other),
+        };
+
+        assert!(reason(&usd[..27]).contains("Expected 28 bytes"));
+
+        let mut version = usd;
+        version[0] = 2;
+        assert!(reason(&version).contains("version 2"));
+
+        let mut number = usd;
+        number[2] ^= 1;
+        assert!(reason(&number).contains("ISO 4217 number"));
+
+        let mut code = usd;
+        code[4] = 0;
+        assert!(reason(&code).contains("Corrupted currency code"));
+
+        let mut unknown = usd;
+        unknown[3..6].copy_from_slice(b"ZZZ");
+        assert!(reason(&unknown).contains("Unknown currency code 'ZZZ'"));
+
+        // rust_decimal supports at most 28 decimal places
+        #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+        {
+            let mut scale = usd;
+            scale[11] = 29;
+            assert!(reason(&scale).contains("out of range"));
+        }
+    }
+
+    #[test]
+    fn test_minor_varint_round_trip() {
+        for minor in [0, 1, -1, 63, -64, 64, 1234, i64::MAX, i64::MIN] {
+            let amount = Amount::<USD>::from_minor(minor);
+            let bytes = amount.to_minor_varint().unwrap();
+            assert!(bytes.len() <= MAX_VARINT_LEN);
+            assert_eq!(
+                Amount::<USD>::from_minor_varint(&bytes).unwrap(),
+                (amount, bytes.len())
+            );
+        }
+        assert_eq!(
+            Amount::<USD>::from_minor(63)
+                .to_minor_varint()
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            Amount::<USD>::from_minor(64)
+                .to_minor_varint()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_minor_varint_errors() {
+        let precise = Amount::<USD>::from_major(1) / 3;
+        assert!(matches!(
+            precise.to_minor_varint(),
+            Err(MoneyError::PrecisionError { .. })
+        ));
+        let huge = Amount::<USD>::from_major(i64::MAX);
+        assert!(matches!(
+            huge.to_minor_varint(),
+            Err(MoneyError::Overflow { .. })
+        ));
+        // Scaling to minor units overflows the decimal itself
+        let max: Amount<USD> = "79228162514264337593543950335".parse().unwrap();
+        assert!(matches!(
+            max.to_minor_varint(),
+            Err(MoneyError::Overflow { .. })
+        ));
+
+        assert!(Amount::<USD>::from_minor_varint(&[]).is_err());
+        assert!(Amount::<USD>::from_minor_varint(&[0x80, 0x80]).is_err());
+        assert!(Amount::<USD>::from_minor_varint(&[0xFF; 11]).is_err());
+        let mut too_big = vec![0xFF; 9];
+        too_big.push(0x02);
+        assert!(Amount::<USD>::from_minor_varint(&too_big).is_err());
+    }
+}
@@ -79,8 +79,7 @@ impl<C: Currency> Amount<C> {
 
     #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
     pub fn from_minor(amount: i64) -> Self {
-        use bigdecimal::BigInt;
-        use bigdecimal::ToPrimitive;
+        use bigdecimal::num_bigint::BigInt;
 
         let value = if C::DECIMALS == 0 {
             Decimal::from(amount)
@@ -1,5 +1,6 @@
 //! Conversion methods for Amount.
 
+use super::rounding::round_dp;
 use super::type_def::Amount;
 use crate::{Currency, RoundingMode};
 
@@ -25,59 +26,11 @@ impl<C: Currency> Amount<C> {
     /// assert_eq!(amount2.to_major_rounded(RoundingMode::Floor), 123);
     /// assert_eq!(amount2.to_major_rounded(RoundingMode::HalfUp), 124);
     /// ```
-    #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
-    pub fn to_major_rounded(&self, mode: RoundingMode) -> i64 {
-        let rounded = match mode {
-            RoundingMode::HalfUp => self.value.round_dp(0),
-            RoundingMode::HalfDown => {
-                // Round half down: round to nearest, ties toward zero
-                let abs_value = self.value.abs();
-                let rounded_abs = abs_value
-                    .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointTowardZero);
-                if self.value.is_sign_negative() {
-                    -rounded_abs
-                } else {
-                    rounded_abs
-                }
-            }
-            RoundingMode::HalfEven => {
-                // Banker's rounding
-                self.value
-                    .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointNearestEven)
-            }
-            RoundingMode::Up => {
-                // Round away from zero
-                self.value
-                    .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::AwayFromZero)
-            }
-            RoundingMode::Down => self.value.trunc(),
-            RoundingMode::Floor => {
-                // Round towards negative infinity
-                self.value
-                    .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::ToNegativeInfinity)
-            }
-            RoundingMode::Ceiling => self.value.ceil(),
-        };
-
-        rounded.to_string().parse().unwrap_or(0)
-    }
-
-    #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
     pub fn to_major_rounded(&self, mode: RoundingMode) -> i64 {
-        use bigdecimal::RoundingMode as BigDecimalRoundingMode;
-
-        let bigdecimal_mode = match mode {
-            RoundingMode::HalfUp => BigDecimalRoundingMode::HalfUp,
-            RoundingMode::HalfDown => BigDecimalRoundingMode::HalfDown,
-            RoundingMode::HalfEven => BigDecimalRoundingMode::HalfEven,
-            RoundingMode::Up => BigDecimalRoundingMode::Up,
-            RoundingMode::Down => BigDecimalRoundingMode::Down,
-            RoundingMode::Floor => BigDecimalRoundingMode::Floor,
-            RoundingMode::Ceiling => BigDecimalRoundingMode::Ceiling,
-        };
-
-        let rounded = self.value.with_scale_round(0, bigdecimal_mode);
-        rounded.to_string().parse().unwrap_or(0)
+        round_dp(&self.value, 0, mode)
+            .to_string()
+            .parse()
+            .unwrap_or(0)
     }
 
     /// Returns the amount in major units, truncating (flooring) any decimals.
@@ -93,7 +46,6 @@ impl<C: Currency> Amount<C> {
     /// let amount = Amount::<USD>::from_minor(12399);  // $123.99
     /// assert_eq!(amount.to_major_floor(), 123);  // Lost $0.99!
     /// ```
-    #[cfg(feature = "use_rust_decimal")]
     pub fn to_major_floor(&self) -> i64 {
         self.to_major_rounded(RoundingMode::Floor)
     }
@@ -113,7 +65,6 @@ impl<C: Currency> Amount<C> {
     /// let amount2 = Amount::<USD>::from_minor(12349);  // $123.49
     /// assert_eq!(amount2.to_major_half_up(), 123);     // 0.49 rounds down
     /// ```
-    #[cfg(feature = "use_rust_decimal")]
     pub fn to_major_half_up(&self) -> i64 {
         self.to_major_rounded(RoundingMode::HalfUp)
     }
@@ -133,7 +84,6 @@ impl<C: Currency> Amount<C> {
     /// let amount2 = Amount::<USD>::from_minor(12351);  // $123.51
     /// assert_eq!(amount2.to_major_half_down(), 124);   // 0.51 rounds up
     /// ```
-    #[cfg(feature = "use_rust_decimal")]
     pub fn to_major_half_down(&self) -> i64 {
         self.to_major_rounded(RoundingMode::HalfDown)
     }
@@ -154,7 +104,6 @@ impl<C: Currency> Amount<C> {
     /// let amount2 = Amount::<USD>::from_minor(12250);  // $122.50
     /// assert_eq!(amount2.to_major_half_even(), 122);   // Rounds to even (122)
     /// ```
-    #[cfg(feature = "use_rust_decimal")]
     pub fn to_major_half_even(&self) -> i64 {
         self.to_major_rounded(RoundingMode::HalfEven)
     }
@@ -177,7 +126,6 @@ impl<C: Currency> Amount<C> {
     /// let amount3 = Amount::<USD>::from_minor(12300);  // $123.00
     /// assert_eq!(amount3.to_major_ceiling(), 123);     // No decimals, stays same
     /// ```
-    #[cfg(feature = "use_rust_decimal")]
     pub fn to_major_ceiling(&self) -> i64 {
         self.to_major_rounded(RoundingMode::Ceiling)
     }
@@ -217,6 +165,28 @@ impl<C: Currency> Amount<C> {
                 .unwrap_or(0)
         }
     }
+
+    /// Returns the amount in minor units like [`to_minor`](Self::to_minor),
+    /// or `None` if they do not fit in an `i64`.
+    #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+    pub(crate) fn checked_to_minor(&self) -> Option<i64> {
+        let scaled = self
+            .value
+            .checked_mul(Decimal::from(10_i64.pow(C::DECIMALS.into())))?;
+        scaled.trunc().to_string().parse().ok()
+    }
+
+    #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+    pub(crate) fn checked_to_minor(&self) -> Option<i64> {
+        use bigdecimal::RoundingMode;
+
+        let scaled = &self.value * Decimal::from(10_i64.pow(C::DECIMALS.into()));
+        scaled
+            .with_scale_round(0, RoundingMode::Down)
+            .to_string()
+            .parse()
+            .ok()
+    }
 }
 
 #[cfg(test)]
@@ -243,6 +213,12 @@ mod tests {
 
         let amount3 = Amount::<USD>::from_minor(12351); // $123.51
         assert_eq!(amount3.to_major_half_up(), 124); // 0.51 rounds up
+
+        let amount4 = Amount::<USD>::from_minor(12250); // $122.50
+        assert_eq!(amount4.to_major_half_up(), 123); // Ties away from zero, not to even
+
+        let amount5 = Amount::<USD>::from_minor(-12250); // -$122.50
+        assert_eq!(amount5.to_major_half_up(), -123);
     }
 
     #[test]
@@ -297,6 +273,17 @@ mod tests {
         assert_eq!(amount.to_major_rounded(RoundingMode::HalfEven), 124);
         assert_eq!(amount.to_major_rounded(RoundingMode::Floor), 123);
         assert_eq!(amount.to_major_rounded(RoundingMode::Ceiling), 124);
+        assert_eq!(amount.to_major_rounded(RoundingMode::HalfOdd), 123);
+        assert_eq!(amount.to_major_rounded(RoundingMode::HalfAwayFromZero), 124);
+        assert_eq!(amount.to_major_rounded(RoundingMode::HalfTowardZero), 123);
+        assert_eq!(amount.to_major_rounded(RoundingMode::Round05Up), 123);
+
+        let negative = Amount::<USD>::from_minor(-12550); // -$125.50
+        assert_eq!(negative.to_major_rounded(RoundingMode::HalfOdd), -125);
+        assert_eq!(negative.to_major_rounded(RoundingMode::Round05Up), -126);
+
+        let stochastic = amount.to_major_rounded(RoundingMode::Stochastic { seed: 1 });
+        assert!(stochastic == 123 || stochastic == 124);
     }
 
     #[test]
@@ -2,13 +2,24 @@
 //!
 //! Provides explicit currency conversion using exchange rates.
 
-use super::type_def::Amount;
-use crate::{Currency, Rate};
+use super::arithmetic::{checked_mul_values, is_exact_product};
+use super::rounding::{apply_context, round_to_context};
+use super::type_def::{value_ref, Amount};
+use crate::{Currency, MoneyContext, MoneyResult, Rate, Trap};
 use std::marker::PhantomData;
 
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
 #[cfg(feature = "conversion_tracking")]
 use crate::conversion_tracking::{ConversionEvent, ConversionTracker};
 
+#[cfg(feature = "operation_tracking")]
+use crate::conversion_tracking::{Operation, OperationEvent};
+
 impl<C: Currency> Amount<C> {
     /// Converts this amount to another currency using an explicit exchange rate.
     ///
@@ -56,20 +67,92 @@ impl<C: Currency> Amount<C> {
     /// // This won't compile - can't add different currencies!
     /// let invalid = usd + eur;
     /// ```
-    #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+    ///
+    /// # Tracking
+    ///
+    /// With the `conversion_tracking` feature enabled, the conversion is reported to
+    /// the process-wide default tracker, if one was installed with
+    /// [`set_default_tracker`](crate::conversion_tracking::set_default_tracker).
+    ///
+    /// # Context
+    ///
+    /// The result is rounded to the precision of the current
+    /// [`MoneyContext`](crate::MoneyContext), if one is set. Conditions the
+    /// result raises are flagged but never trapped; use
+    /// [`try_convert`](Self::try_convert) to have trapped conditions reported
+    /// as errors.
     pub fn convert<To: Currency>(&self, rate: &Rate<C, To>) -> Amount<To> {
-        Amount {
-            value: self.value * rate.value(),
+        let result = Amount {
+            value: self.converted_value(rate),
             _currency: PhantomData,
-        }
+        };
+        self.track_conversion(rate, &result);
+        result
     }
 
-    #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
-    pub fn convert<To: Currency>(&self, rate: &Rate<C, To>) -> Amount<To> {
-        Amount {
-            value: &self.value * rate.value(),
+    /// Converts this amount to another currency, reporting conditions trapped by
+    /// the current [`MoneyContext`](crate::MoneyContext) as errors.
+    ///
+    /// # Errors
+    ///
+    /// - `MoneyError::Overflow` if the result exceeds the backend's range, or if
+    ///   the context traps `Overflow` and it does not fit in `i64` minor units
+    /// - `MoneyError::RoundingError` if the context traps `Inexact` and the result
+    ///   had to be rounded to the context precision
+    /// - `MoneyError::PrecisionError` if the context traps `ExcessPrecision` and the
+    ///   result has more decimal places than the target currency supports
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyContext, MoneyError, Rate, Trap, USD, EUR};
+    ///
+    /// let usd = Amount::<USD>::from_minor(1_001); // $10.01
+    /// let rate = Rate::<USD, EUR>::new(0.85);
+    /// assert!(usd.try_convert(&rate).is_ok());
+    ///
+    /// let strict = MoneyContext::new().with_trap(Trap::ExcessPrecision);
+    /// let result = strict.scope(|| usd.try_convert(&rate)); // €8.5085
+    /// assert!(matches!(result, Err(MoneyError::PrecisionError { .. })));
+    /// ```
+    pub fn try_convert<To: Currency>(&self, rate: &Rate<C, To>) -> MoneyResult<Amount<To>> {
+        let value = checked_mul_values(&self.value, rate.value(), "conversion", To::CODE)
+            .inspect_err(|_| {
+                MoneyContext::signal(Trap::Overflow);
+            })?;
+        let exact = is_exact_product(&self.value, rate.value(), &value);
+        let result = Amount {
+            value: apply_context::<To>("conversion", value, exact)?,
             _currency: PhantomData,
-        }
+        };
+        self.track_conversion(rate, &result);
+        Ok(result)
+    }
+
+    /// Multiplies by the rate and applies the current context without trapping.
+    ///
+    /// Overflow panics, as the multiplication operators do.
+    fn converted_value<To: Currency>(&self, rate: &Rate<C, To>) -> Decimal {
+        let value = value_ref(&self.value) * value_ref(rate.value());
+        let exact = is_exact_product(&self.value, rate.value(), &value);
+        round_to_context::<To>("conversion", value, exact)
+    }
+
+    /// Reports a conversion to the default and operation trackers.
+    #[cfg_attr(
+        not(any(feature = "conversion_tracking", feature = "operation_tracking")),
+        allow(unused_variables)
+    )]
+    fn track_conversion<To: Currency>(&self, rate: &Rate<C, To>, result: &Amount<To>) {
+        #[cfg(feature = "conversion_tracking")]
+        crate::conversion_tracking::track_default(self, rate, result);
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Convert, C::CODE, &self.value, &result.value)
+                .with_operand(rate.value())
+                .with_output_currency(To::CODE)
+        });
     }
 
     /// Converts this amount to another currency using an explicit exchange rate,
@@ -100,20 +183,13 @@ impl<C: Currency> Amount<C> {
     /// # }
     /// ```
     #[cfg(feature = "conversion_tracking")]
-    pub fn convert_with_tracking<To: Currency, T: ConversionTracker>(
+    pub fn convert_with_tracking<To: Currency, T: ConversionTracker + ?Sized>(
         &self,
         rate: &Rate<C, To>,
         tracker: &T,
     ) -> Amount<To> {
-        #[cfg(feature = "use_rust_decimal")]
         let result = Amount {
-            value: self.value * rate.value(),
-            _currency: PhantomData,
-        };
-
-        #[cfg(feature = "use_bigdecimal")]
-        let result = Amount {
-            value: &self.value * rate.value(),
+            value: self.converted_value(rate),
             _currency: PhantomData,
         };
 
@@ -138,6 +214,13 @@ impl<C: Currency> Amount<C> {
 
         tracker.track(&event);
 
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Convert, C::CODE, &self.value, &result.value)
+                .with_operand(rate.value())
+                .with_output_currency(To::CODE)
+        });
+
         result
     }
 }
@@ -290,6 +373,128 @@ mod tests {
         // Invalid rates are caught at Rate construction (see rate.rs tests for panics)
     }
 
+    #[test]
+    fn test_try_convert_matches_convert() {
+        let usd = Amount::<USD>::from_minor(12_345);
+        let rate = Rate::<USD, EUR>::new(0.85);
+
+        assert_eq!(usd.try_convert(&rate).unwrap(), usd.convert(&rate));
+    }
+
+    #[test]
+    fn test_convert_rounds_to_context_precision() {
+        use crate::{MoneyContext, RoundingMode};
+
+        let usd = Amount::<USD>::from_minor(1_001); // $10.01
+        let rate = Rate::<USD, EUR>::new(0.85); // €8.5085
+
+        let context = MoneyContext::new().with_precision(2);
+        let eur = context.scope(|| usd.convert(&rate));
+        assert_eq!(eur.to_minor(), 851); // EUR rounds half-up by default
+
+        let context = context.with_rounding(RoundingMode::Floor);
+        let eur = context.scope(|| usd.convert(&rate));
+        assert_eq!(eur.to_minor(), 850);
+    }
+
+    #[test]
+    fn test_try_convert_reports_trapped_conditions() {
+        use crate::{MoneyContext, MoneyError, Trap};
+
+        let usd = Amount::<USD>::from_minor(1_001);
+        let rate = Rate::<USD, EUR>::new(0.85);
+
+        let inexact = MoneyContext::new()
+            .with_precision(2)
+            .with_trap(Trap::Inexact);
+        let result = inexact.scope(|| usd.try_convert(&rate));
+        assert!(matches!(
+            result,
+            Err(MoneyError::RoundingError {
+                currency: "EUR",
+                ..
+            })
+        ));
+
+        let excess = MoneyContext::new().with_trap(Trap::ExcessPrecision);
+        let result = excess.scope(|| usd.try_convert(&rate));
+        assert!(matches!(
+            result,
+            Err(MoneyError::PrecisionError {
+                currency: "EUR",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_conversion_flags_inexact_products() {
+        use crate::{MoneyContext, MoneyError, Trap};
+        use rust_decimal::Decimal;
+
+        let dust = Amount::<BTC> {
+            value: Decimal::new(1, 20),
+            _currency: PhantomData,
+        };
+        let rate = Rate::<BTC, USD>::from_decimal(Decimal::new(3, 10));
+
+        MoneyContext::new().scope(|| {
+            let _ = Amount::<USD>::from_minor(1_001).convert(&Rate::<USD, EUR>::new(0.85));
+            assert!(!MoneyContext::current().is_flagged(Trap::Inexact));
+            let _ = dust.convert(&rate);
+            assert!(MoneyContext::current().is_flagged(Trap::Inexact));
+        });
+
+        let strict = MoneyContext::new().with_trap(Trap::Inexact);
+        let result = strict.scope(|| dust.try_convert(&rate));
+        assert!(matches!(result, Err(MoneyError::RoundingError { .. })));
+    }
+
+    #[test]
+    fn test_convert_ignores_traps() {
+        use crate::{MoneyContext, Trap};
+
+        let usd = Amount::<USD>::from_minor(1_001);
+        let rate = Rate::<USD, EUR>::new(0.85);
+        MoneyContext::new()
+            .with_trap(Trap::ExcessPrecision)
+            .scope(|| {
+                assert!(usd.convert(&rate).has_excess_precision());
+                assert!(MoneyContext::current().is_flagged(Trap::ExcessPrecision));
+            });
+    }
+
+    #[test]
+    fn test_try_convert_reports_overflow() {
+        use crate::MoneyError;
+
+        let huge = Amount::<USD>::from_major(i64::MAX) * 1_000_000_000;
+        let rate = Rate::<USD, EUR>::new(100.0);
+        MoneyContext::new().scope(|| {
+            assert!(matches!(
+                huge.try_convert(&rate),
+                Err(MoneyError::Overflow {
+                    currency: "EUR",
+                    ..
+                })
+            ));
+            assert!(MoneyContext::current().is_flagged(Trap::Overflow));
+        });
+
+        // Within the backend's range but beyond i64 minor units
+        let large = Amount::<USD>::from_major(i64::MAX / 10);
+        let rate = Rate::<USD, EUR>::new(100.0);
+        assert!(large.try_convert(&rate).is_ok());
+        let strict = MoneyContext::new().with_trap(Trap::Overflow);
+        assert!(matches!(
+            strict.scope(|| large.try_convert(&rate)),
+            Err(MoneyError::Overflow {
+                currency: "EUR",
+                ..
+            })
+        ));
+    }
+
     #[cfg(feature = "conversion_tracking")]
     mod tracking_tests {
         use super::*;
@@ -363,5 +568,33 @@ mod tests {
             assert_eq!(*tracker.last_timestamp.borrow(), Some(1_700_000_000));
             assert_eq!(*tracker.last_source.borrow(), Some("ECB"));
         }
+
+        #[test]
+        fn test_convert_reports_to_default_tracker() {
+            use crate::conversion_tracking::{self, DynConversionEvent};
+            use std::sync::{Arc, Mutex};
+
+            let seen = Arc::new(Mutex::new(Vec::new()));
+            let sink = seen.clone();
+            conversion_tracking::set_default_tracker(move |event: &DynConversionEvent| {
+                // Other tests convert concurrently; only keep our own conversion
+                if event.rate_source == Some("default-tracker-test") {
+                    sink.lock().unwrap().push(event.clone());
+                }
+            });
+
+            let usd = Amount::<USD>::from_major(100);
+            let rate = Rate::<USD, EUR>::new(0.85).with_source("default-tracker-test");
+            let _eur = usd.convert(&rate);
+
+            conversion_tracking::clear_default_tracker();
+            let _not_tracked = usd.convert(&rate);
+
+            let events = seen.lock().unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].from_currency_code, "USD");
+            assert_eq!(events[0].to_currency_code, "EUR");
+            assert_eq!(events[0].to_amount.to_string(), "85.00");
+        }
     }
 }
@@ -1,7 +1,11 @@
 //! Display implementation for Amount.
 
+use super::rounding::round_dp;
 use super::type_def::Amount;
-use crate::Currency;
+use crate::formatter::write_padded;
+use crate::{
+    CompactStyle, Currency, Locale, MoneyFormatter, RoundingMode, SignDisplay, SymbolPosition,
+};
 use std::fmt;
 
 #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
@@ -22,24 +26,101 @@ fn truncate_to_integer(value: &Decimal) -> String {
     format!("{}", value.with_scale_round(0, RoundingMode::Down))
 }
 
+// Helper function to divide a decimal by 10^exponent
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn scale_down(value: &Decimal, exponent: u32) -> Decimal {
+    value / Decimal::from_i128_with_scale(10_i128.pow(exponent), 0)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn scale_down(value: &Decimal, exponent: u32) -> Decimal {
+    let (digits, scale) = value.as_bigint_and_exponent();
+    Decimal::new(digits, scale + i64::from(exponent))
+}
+
+/// Number of digits before the decimal point, at least one.
+fn integer_digits(value: &Decimal) -> u32 {
+    let digits = truncate_to_integer(value);
+    digits.trim_start_matches('-').len() as u32
+}
+
+/// Writes `amount` with the currency name in the language of `locale`.
+///
+/// See [`CurrencyMetadata::format_long`](crate::CurrencyMetadata::format_long).
+pub(crate) fn format_long<C: Currency>(amount: &Amount<C>, locale: &Locale) -> String {
+    let plain = amount
+        .round_to_scale(u32::from(C::DECIMALS), C::DEFAULT_ROUNDING)
+        .format_plain();
+    // Whole amounts read better without decimals: "2 US dollars"
+    let plain = match plain.split_once('.') {
+        Some((integer, fraction)) if fraction.bytes().all(|digit| digit == b'0') => {
+            integer.to_string()
+        }
+        _ => plain,
+    };
+    locale.format_long(&plain, C::CODE)
+}
+
+/// Prints [`format_full`](Amount::format_full), or
+/// [`format_native`](Amount::format_native) with the `#` flag.
+///
+/// The standard formatting flags are honoured:
+///
+/// - width, fill and alignment pad the whole string (right-aligned by default)
+/// - `+` shows a sign on non-negative amounts
+/// - precision overrides the number of decimal places, rounding with the
+///   currency's `DEFAULT_ROUNDING`
+/// - `#` switches to `format_native`
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{Amount, USD};
+///
+/// let amount = Amount::<USD>::from_minor(123_456);
+/// assert_eq!(format!("[{:>16}]", amount), "[    $1234.56 USD]");
+/// assert_eq!(format!("[{:<14.1}]", amount), "[$1234.6 USD   ]");
+/// assert_eq!(format!("{:+#}", amount), "+$1,234.56");
+/// assert_eq!(format!("[{:*^#12.0}]", amount), "[***$1,235***]");
+/// ```
 impl<C: Currency> fmt::Display for Amount<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Format: {symbol}{amount} {code}
-        // e.g., "$100.00 USD" or "€85.50 EUR"
-        let formatted_value = if C::DECIMALS == 0 {
-            truncate_to_integer(&self.value)
+        let native = f.alternate();
+        let precision = f.precision().map(|p| u32::try_from(p).unwrap_or(u32::MAX));
+
+        let formatted = if native {
+            let mut formatter = MoneyFormatter::new();
+            if let Some(decimals) = precision {
+                formatter = formatter.with_decimals(decimals);
+            }
+            if f.sign_plus() {
+                formatter = formatter.with_sign_display(SignDisplay::Always);
+            }
+            formatter.format(self)
         } else {
-            format!("{:.prec$}", self.value, prec = C::DECIMALS as usize)
+            let plain = match precision {
+                Some(decimals) => self
+                    .round_to_scale(decimals, C::DEFAULT_ROUNDING)
+                    .format_plain_scale(decimals),
+                None => self.format_plain(),
+            };
+            let sign = if f.sign_plus() && !plain.starts_with('-') {
+                "+"
+            } else {
+                ""
+            };
+            format!("{}{}{} {}", C::SYMBOL, sign, plain, C::CODE)
         };
 
-        write!(f, "{}{} {}", C::SYMBOL, formatted_value, C::CODE)
+        write_padded(f, &formatted)
     }
 }
 
 impl<C: Currency> Amount<C> {
     /// Formats the amount as a string with symbol and currency code.
     ///
-    /// This is equivalent to calling `.to_string()` via the Display trait.
+    /// This is what `Display` prints; the alternate form `{:#}` prints
+    /// [`format_native`](Self::format_native) instead.
     ///
     /// # Examples
     ///
@@ -50,7 +131,171 @@ impl<C: Currency> Amount<C> {
     /// assert_eq!(amount.format_full(), "$100.00 USD");
     /// ```
     pub fn format_full(&self) -> String {
-        format!("{}", self)
+        // Format: {symbol}{amount} {code}
+        // e.g., "$100.00 USD" or "€85.50 EUR"
+        format!("{}{} {}", C::SYMBOL, self.format_plain(), C::CODE)
+    }
+
+    /// Formats the amount following the currency's own conventions.
+    ///
+    /// Applies the currency's thousands and decimal separators, places the
+    /// symbol before or after the number, and inserts a space between them
+    /// where the currency calls for one. Negative amounts start with a minus
+    /// sign.
+    ///
+    /// This is also what the alternate `Display` form `{:#}` prints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, EUR, JPY, SEK, USD};
+    ///
+    /// assert_eq!(Amount::<USD>::from_minor(123456).format_native(), "$1,234.56");
+    /// assert_eq!(Amount::<EUR>::from_minor(123456).format_native(), "1.234,56 €");
+    /// assert_eq!(Amount::<SEK>::from_major(100).format_native(), "100,00 kr");
+    /// assert_eq!(Amount::<JPY>::from_major(-5000).format_native(), "-¥5,000");
+    /// ```
+    pub fn format_native(&self) -> String {
+        let plain = self.format_plain();
+        let (negative, digits) = match plain.strip_prefix('-') {
+            // Amounts that display as zero never get a sign
+            Some(digits) => (digits.chars().any(|ch| matches!(ch, '1'..='9')), digits),
+            None => (false, plain.as_str()),
+        };
+
+        let number =
+            self.add_thousands_separator(digits, C::THOUSANDS_SEPARATOR, C::DECIMAL_SEPARATOR);
+        let sign = if negative { "-" } else { "" };
+        let space = if C::SPACE_BETWEEN { " " } else { "" };
+
+        match C::SYMBOL_POSITION {
+            SymbolPosition::Before => format!("{}{}{}{}", sign, C::SYMBOL, space, number),
+            SymbolPosition::After => format!("{}{}{}{}", sign, number, space, C::SYMBOL),
+        }
+    }
+
+    /// Formats the amount following the rules of a [`Locale`].
+    ///
+    /// The locale decides the separators, digit grouping, digits, symbol
+    /// placement and how negative amounts are written; the symbol itself is
+    /// the currency's. Spaces between symbol and number are non-breaking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, Locale, EUR, USD};
+    ///
+    /// let eur = Amount::<EUR>::from_minor(-123_456);
+    /// assert_eq!(eur.format_in(&Locale::parse("fr-FR")?), "-1\u{202f}234,56\u{a0}€");
+    /// assert_eq!(eur.format_in(&Locale::parse("nl-NL")?), "€\u{a0}-1.234,56");
+    ///
+    /// let usd = Amount::<USD>::from_minor(123_456);
+    /// assert_eq!(usd.format_in(&Locale::parse("ja")?), "$1,234.56");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn format_in(&self, locale: &Locale) -> String {
+        locale.format_number(&self.format_plain(), C::SYMBOL)
+    }
+
+    /// Formats the amount compactly, abbreviating large magnitudes with the
+    /// locale's units (thousand, million, lakh, 万, ...).
+    ///
+    /// Shows two significant digits, rounded with the currency's
+    /// `DEFAULT_ROUNDING`; see [`format_compact_with`](Self::format_compact_with)
+    /// to choose both. Separators, digits and symbol placement follow the
+    /// locale as in [`format_in`](Self::format_in).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, CompactStyle, Locale, EUR, INR, JPY, USD};
+    ///
+    /// let en = Locale::parse("en-US")?;
+    /// let usd = Amount::<USD>::from_major(1_234_567);
+    /// assert_eq!(usd.format_compact(&en, CompactStyle::Short), "$1.2M");
+    /// assert_eq!(usd.format_compact(&en, CompactStyle::Long), "$1.2\u{a0}million");
+    ///
+    /// let eur = Amount::<EUR>::from_major(3_400_000);
+    /// let de = Locale::parse("de-DE")?;
+    /// assert_eq!(eur.format_compact(&de, CompactStyle::Short), "3,4\u{a0}Mio.\u{a0}€");
+    ///
+    /// let jpy = Amount::<JPY>::from_major(123_456_789);
+    /// assert_eq!(jpy.format_compact(&Locale::parse("ja")?, CompactStyle::Short), "¥1.2億");
+    ///
+    /// let inr = Amount::<INR>::from_major(4_500_000);
+    /// assert_eq!(inr.format_compact(&Locale::parse("en-IN")?, CompactStyle::Short), "₹45L");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn format_compact(&self, locale: &Locale, style: CompactStyle) -> String {
+        self.format_compact_with(locale, style, 2, C::DEFAULT_ROUNDING)
+    }
+
+    /// Formats the amount compactly with `significant_digits` significant
+    /// digits, rounding with `mode`.
+    ///
+    /// Integer digits of the abbreviated number are never dropped, so
+    /// $123,456 with two significant digits is "$123K". Trailing zeros are
+    /// removed, and a result that rounds up to the next unit moves to it
+    /// ($999,999 is "$1M", not "$1000K"). Numbers below 10,000 are not
+    /// grouped. A `significant_digits` of zero is treated as one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, CompactStyle, Locale, RoundingMode, USD};
+    ///
+    /// let en = Locale::parse("en")?;
+    /// let amount = Amount::<USD>::from_major(1_987_654);
+    /// let short = CompactStyle::Short;
+    ///
+    /// assert_eq!(amount.format_compact_with(&en, short, 3, RoundingMode::HalfEven), "$1.99M");
+    /// assert_eq!(amount.format_compact_with(&en, short, 2, RoundingMode::Floor), "$1.9M");
+    /// assert_eq!(amount.format_compact_with(&en, short, 1, RoundingMode::HalfUp), "$2M");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn format_compact_with(
+        &self,
+        locale: &Locale,
+        style: CompactStyle,
+        significant_digits: u32,
+        mode: RoundingMode,
+    ) -> String {
+        let rules = locale.compact_rules();
+        // Compact numbers are only grouped from five integer digits on
+        let grouping = locale.grouping();
+        let locale = locale
+            .clone()
+            .with_grouping(grouping.with_min_grouping(grouping.min_grouping().max(2)));
+        let significant = significant_digits.max(1);
+        let mut unit = rules.unit_for(integer_digits(&self.value) - 1, style);
+
+        loop {
+            let exponent = unit.map_or(0, |unit| unit.exponent);
+            let scaled = scale_down(&self.value, exponent);
+            let decimals = significant.saturating_sub(integer_digits(&scaled));
+            let rounded = round_dp(&scaled, decimals, mode);
+
+            // Rounding may carry into the next unit (999.96K -> 1M)
+            let magnitude = exponent + integer_digits(&rounded) - 1;
+            match rules.unit_for(magnitude, style) {
+                Some(next) if next.exponent > exponent => unit = Some(next),
+                _ => {
+                    let mut plain = if decimals == 0 {
+                        truncate_to_integer(&rounded)
+                    } else {
+                        format!("{:.prec$}", rounded, prec = decimals as usize)
+                    };
+                    if plain.contains('.') {
+                        plain = plain
+                            .trim_end_matches('0')
+                            .trim_end_matches('.')
+                            .to_string();
+                    }
+                    let suffix = unit.map_or("", |unit| rules.suffix(unit, style, &plain));
+                    return locale.format_number_with_suffix(&plain, suffix, C::SYMBOL);
+                }
+            }
+        }
     }
 
     /// Formats the amount with symbol only (no currency code).
@@ -107,15 +352,23 @@ impl<C: Currency> Amount<C> {
     /// assert_eq!(amount.format_plain(), "100.00");
     /// ```
     pub fn format_plain(&self) -> String {
-        if C::DECIMALS == 0 {
+        self.format_plain_scale(u32::from(C::DECIMALS))
+    }
+
+    /// Formats the bare number with exactly `scale` decimal places.
+    pub(crate) fn format_plain_scale(&self, scale: u32) -> String {
+        if scale == 0 {
             truncate_to_integer(&self.value)
         } else {
-            format!("{:.prec$}", self.value, prec = C::DECIMALS as usize)
+            format!("{:.prec$}", self.value, prec = scale as usize)
         }
     }
 
     /// Formats the amount with locale-specific number formatting.
     ///
+    /// Prefer [`format_in`](Self::format_in), which supports many more
+    /// locales and places the symbol the way each locale expects.
+    ///
     /// This is a basic implementation that supports common locale patterns:
     /// - `en_US`: `1,234.56` (comma thousands, period decimal)
     /// - `de_DE`: `1.234,56` (period thousands, comma decimal)
@@ -191,16 +444,8 @@ impl<C: Currency> Amount<C> {
             (false, integer_part)
         };
 
-        // Add thousands separators to integer part
-        let mut result = String::new();
-        let len = digits.len();
-
-        for (i, ch) in digits.chars().enumerate() {
-            if i > 0 && (len - i) % 3 == 0 {
-                result.push(separator);
-            }
-            result.push(ch);
-        }
+        // Group the integer part following the currency's pattern
+        let result = C::GROUPING.apply(digits, separator);
 
         // Prepend negative sign if needed
         let formatted_integer = if is_negative {
@@ -221,7 +466,7 @@ impl<C: Currency> Amount<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{BTC, EUR, JPY, USD};
+    use crate::{BTC, CHF, EUR, JPY, USD};
 
     #[test]
     fn test_display_usd() {
@@ -247,6 +492,150 @@ mod tests {
         assert_eq!(format!("{}", amount), "₿1.00000000 BTC");
     }
 
+    fn compact<C: Currency>(major: i64, tag: &str, style: CompactStyle) -> String {
+        let locale = Locale::parse(tag).unwrap();
+        Amount::<C>::from_major(major).format_compact(&locale, style)
+    }
+
+    #[test]
+    fn test_format_compact_short() {
+        use crate::INR;
+        use CompactStyle::Short;
+
+        assert_eq!(compact::<USD>(999, "en", Short), "$999");
+        assert_eq!(compact::<USD>(1_234, "en", Short), "$1.2K");
+        assert_eq!(compact::<USD>(123_456, "en", Short), "$123K");
+        assert_eq!(compact::<USD>(3_400_000, "en", Short), "$3.4M");
+        assert_eq!(compact::<USD>(2_000_000_000, "en", Short), "$2B");
+        assert_eq!(compact::<USD>(-1_250_000, "en", Short), "-$1.2M");
+        assert_eq!(compact::<USD>(5_000_000_000_000_000, "en", Short), "$5000T");
+
+        assert_eq!(compact::<EUR>(1_234, "de", Short), "1234\u{a0}€");
+        assert_eq!(compact::<EUR>(12_345, "de", Short), "12.345\u{a0}€");
+        assert_eq!(
+            compact::<EUR>(1_200_000, "de", Short),
+            "1,2\u{a0}Mio.\u{a0}€"
+        );
+        assert_eq!(
+            compact::<EUR>(7_500_000_000, "fr", Short),
+            "7,5\u{a0}Md\u{a0}€"
+        );
+
+        assert_eq!(compact::<JPY>(1_234, "ja", Short), "¥1234");
+        assert_eq!(compact::<JPY>(12_345, "ja", Short), "¥1.2万");
+        assert_eq!(compact::<JPY>(340_000_000, "ja", Short), "¥3.4億");
+
+        assert_eq!(compact::<INR>(150_000, "en-IN", Short), "₹1.5L");
+        assert_eq!(compact::<INR>(123_456_789, "en-IN", Short), "₹12Cr");
+        assert_eq!(
+            compact::<INR>(1_230_000_000_000, "en-IN", Short),
+            "₹1,23,000Cr"
+        );
+        assert_eq!(compact::<INR>(250_000, "hi", Short), "₹2.5\u{a0}लाख");
+    }
+
+    #[test]
+    fn test_format_compact_long() {
+        use CompactStyle::Long;
+
+        assert_eq!(compact::<USD>(1_234, "en", Long), "$1.2\u{a0}thousand");
+        assert_eq!(compact::<USD>(1_000_000, "en", Long), "$1\u{a0}million");
+        assert_eq!(compact::<EUR>(1_000, "de", Long), "1\u{a0}Tausend\u{a0}€");
+        assert_eq!(
+            compact::<EUR>(1_000_000, "de", Long),
+            "1\u{a0}Million\u{a0}€"
+        );
+        assert_eq!(
+            compact::<EUR>(2_500_000, "de", Long),
+            "2,5\u{a0}Millionen\u{a0}€"
+        );
+        assert_eq!(
+            compact::<EUR>(1_500_000, "fr", Long),
+            "1,5\u{a0}million\u{a0}€"
+        );
+        assert_eq!(
+            compact::<EUR>(2_000_000, "fr", Long),
+            "2\u{a0}millions\u{a0}€"
+        );
+        assert_eq!(
+            compact::<EUR>(1_000_000, "es", Long),
+            "1\u{a0}millón\u{a0}€"
+        );
+    }
+
+    #[test]
+    fn test_format_compact_rounding_and_carry() {
+        let en = Locale::parse("en").unwrap();
+        let short = CompactStyle::Short;
+        let amount = Amount::<USD>::from_major(999_999);
+        assert_eq!(amount.format_compact(&en, short), "$1M");
+        assert_eq!(
+            amount.format_compact_with(&en, short, 2, RoundingMode::Down),
+            "$999K"
+        );
+        assert_eq!(
+            amount.format_compact_with(&en, short, 6, RoundingMode::HalfEven),
+            "$999.999K"
+        );
+
+        let amount = Amount::<USD>::from_major(-1_250_000);
+        assert_eq!(
+            amount.format_compact_with(&en, short, 2, RoundingMode::Floor),
+            "-$1.3M"
+        );
+        assert_eq!(
+            amount.format_compact_with(&en, short, 0, RoundingMode::HalfUp),
+            "-$1M"
+        );
+
+        let small = Amount::<USD>::from_minor(1_234);
+        assert_eq!(small.format_compact(&en, short), "$12");
+        // Two significant digits leave nothing of a single cent
+        assert_eq!(
+            Amount::<USD>::from_minor(-1).format_compact(&en, short),
+            "$0"
+        );
+    }
+
+    #[test]
+    fn test_format_compact_native_digits() {
+        let bn = Locale::parse("bn").unwrap();
+        let amount = Amount::<EUR>::from_major(2_500_000);
+        assert_eq!(
+            amount.format_compact(&bn, CompactStyle::Short),
+            "২৫\u{a0}লা€"
+        );
+    }
+
+    #[test]
+    fn test_display_flags() {
+        let amount = Amount::<USD>::from_minor(-123_456);
+        assert_eq!(format!("{:>15}", amount), "  $-1234.56 USD");
+        assert_eq!(format!("{:15}", amount), "  $-1234.56 USD");
+        assert_eq!(format!("{:<15}|", amount), "$-1234.56 USD  |");
+        assert_eq!(format!("{:.0}", amount), "$-1235 USD");
+        assert_eq!(format!("{:.4}", amount), "$-1234.5600 USD");
+        assert_eq!(format!("{:+}", amount), "$-1234.56 USD");
+        assert_eq!(format!("{:+}", Amount::<USD>::from_major(5)), "$+5.00 USD");
+        assert_eq!(format!("{:#}", amount), "-$1,234.56");
+        assert_eq!(format!("{:+#}", Amount::<EUR>::from_major(5)), "+5,00 €");
+        assert_eq!(format!("{:_>#12.1}", amount), "___-$1,234.6");
+    }
+
+    #[test]
+    fn test_display_precision_uses_default_rounding() {
+        // USD rounds half-even by default
+        assert_eq!(
+            format!("{:.0}", Amount::<USD>::from_minor(1_250)),
+            "$12 USD"
+        );
+        assert_eq!(
+            format!("{:.0}", Amount::<USD>::from_minor(1_350)),
+            "$14 USD"
+        );
+        assert_eq!(format!("{:.1}", Amount::<JPY>::from_major(7)), "¥7.0 JPY");
+    }
+
     // Determinism tests
     #[test]
     fn test_display_formatting_determinism() {
@@ -393,4 +782,157 @@ mod tests {
         assert_eq!(amount.format_locale("unknown"), "$1,234.00 USD");
         assert_eq!(amount.format_locale(""), "$1,234.00 USD");
     }
+
+    // ========================================================================
+    // Native Formatting Tests
+    // ========================================================================
+
+    fn check_native<C: Currency>(expected: &str) {
+        let amount = Amount::<C>::new(Decimal::new(123_456_789, 2));
+        assert_eq!(amount.format_native(), expected, "{}", C::CODE);
+    }
+
+    #[test]
+    fn test_format_native_every_builtin_currency() {
+        use crate::*;
+
+        check_native::<AAVE>("AAVE1,234,567.890000000000000000");
+        check_native::<ADA>("₳1,234,567.890000");
+        check_native::<AED>("1,234,567.89 د.إ");
+        check_native::<ARS>("$1.234.567,89");
+        check_native::<AUD>("A$1,234,567.89");
+        check_native::<BCH>("₿1,234,567.89000000");
+        check_native::<BGN>("1 234 567,89 лв");
+        check_native::<BHD>("1,234,567.890 د.ب");
+        check_native::<BOB>("Bs1.234.567,89");
+        check_native::<BRL>("R$1.234.567,89");
+        check_native::<BTC>("₿1,234,567.89000000");
+        check_native::<BUSD>("BUSD1,234,567.890000000000000000");
+        check_native::<CAD>("C$1,234,567.89");
+        check_native::<CHF>("1'234'567.89 CHF");
+        check_native::<CLP>("$1.234.567");
+        check_native::<CNY>("¥1,234,567.89");
+        check_native::<COMP>("1,234,567.890000000000000000 COMP");
+        check_native::<COP>("$1.234.567,89");
+        check_native::<CZK>("1 234 567,89 Kč");
+        check_native::<DAI>("DAI1,234,567.890000000000000000");
+        check_native::<DKK>("1.234.567,89 kr");
+        check_native::<DOT>("DOT1,234,567.8900000000");
+        check_native::<EGP>("£1,234,567.89");
+        check_native::<ETH>("Ξ1,234,567.890000000000000000");
+        check_native::<EUR>("1.234.567,89 €");
+        check_native::<GBP>("£1,234,567.89");
+        check_native::<GHS>("₵1,234,567.89");
+        check_native::<HKD>("HK$1,234,567.89");
+        check_native::<HRK>("1.234.567,89 kn");
+        check_native::<HUF>("1 234 567 Ft");
+        check_native::<IDR>("Rp1.234.567");
+        check_native::<ILS>("₪1,234,567.89");
+        check_native::<INR>("₹12,34,567.89");
+        check_native::<JOD>("1,234,567.890 د.ا");
+        check_native::<JPY>("¥1,234,567");
+        check_native::<KES>("KSh1,234,567.89");
+        check_native::<KRW>("₩1,234,567");
+        check_native::<KWD>("1,234,567.890 د.ك");
+        check_native::<LINK>("LINK1,234,567.890000000000000000");
+        check_native::<LTC>("Ł1,234,567.89000000");
+        check_native::<MAD>("1.234.567,89 د.م.");
+        check_native::<MKR>("1,234,567.890000000000000000 MKR");
+        check_native::<MXN>("$1,234,567.89");
+        check_native::<MYR>("RM1,234,567.89");
+        check_native::<NGN>("₦1,234,567.89");
+        check_native::<NOK>("1 234 567,89 kr");
+        check_native::<NZD>("NZ$1,234,567.89");
+        check_native::<OMR>("1,234,567.890 ﷼");
+        check_native::<PEN>("S/1.234.567,89");
+        check_native::<PHP>("₱1,234,567.89");
+        check_native::<PLN>("1 234 567,89 zł");
+        check_native::<PYG>("₲1.234.567");
+        check_native::<QAR>("1,234,567.89 ﷼");
+        check_native::<RON>("1.234.567,89 lei");
+        check_native::<RSD>("1.234.567,89 дин");
+        check_native::<SAR>("1,234,567.89 ﷼");
+        check_native::<SEK>("1 234 567,89 kr");
+        check_native::<SGD>("S$1,234,567.89");
+        check_native::<SUSHI>("1,234,567.890000000000000000 SUSHI");
+        check_native::<THB>("฿1,234,567.89");
+        check_native::<TND>("1.234.567,890 د.ت");
+        check_native::<TRY>("1.234.567,89 ₺");
+        check_native::<TWD>("NT$1,234,567.89");
+        check_native::<UAH>("1 234 567,89 ₴");
+        check_native::<UNI>("UNI1,234,567.890000000000000000");
+        check_native::<USD>("$1,234,567.89");
+        check_native::<USDC>("USDC1,234,567.890000");
+        check_native::<USDT>("USDT1,234,567.890000");
+        check_native::<UYU>("$U1.234.567,89");
+        check_native::<VND>("1.234.567 ₫");
+        check_native::<XAG>("Ag1,234,567.8900");
+        check_native::<XAL>("Al1,234,567.8900");
+        check_native::<XAU>("Au1,234,567.8900");
+        check_native::<XCU>("Cu1,234,567.8900");
+        check_native::<XDI>("♦1,234,567.8900");
+        check_native::<XNI>("Ni1,234,567.8900");
+        check_native::<XPD>("Pd1,234,567.8900");
+        check_native::<XPT>("Pt1,234,567.8900");
+        check_native::<XRP>("XRP1,234,567.890000");
+        check_native::<XZN>("Zn1,234,567.8900");
+        check_native::<YFI>("1,234,567.890000000000000000 YFI");
+        check_native::<ZAR>("R1,234,567.89");
+    }
+
+    #[test]
+    fn test_format_native_negative() {
+        assert_eq!(
+            Amount::<USD>::from_minor(-123_456).format_native(),
+            "-$1,234.56"
+        );
+        assert_eq!(
+            Amount::<EUR>::from_minor(-123_456).format_native(),
+            "-1.234,56 €"
+        );
+    }
+
+    #[test]
+    fn test_format_native_negative_zero_has_no_sign() {
+        let dust = Amount::<USD>::new(Decimal::new(-1, 3)); // -$0.001
+        assert_eq!(dust.format_native(), "$0.00");
+    }
+
+    #[test]
+    fn test_format_native_small_amounts() {
+        assert_eq!(Amount::<EUR>::from_minor(5).format_native(), "0,05 €");
+        assert_eq!(Amount::<CHF>::from_major(999).format_native(), "999.00 CHF");
+        assert_eq!(Amount::<JPY>::from_major(0).format_native(), "¥0");
+    }
+
+    #[test]
+    fn test_format_in_locales() {
+        let eur = Amount::<EUR>::from_minor(123_456);
+        let format = |tag: &str| eur.format_in(&Locale::parse(tag).unwrap());
+        assert_eq!(format("en-US"), "€1,234.56");
+        assert_eq!(format("de-DE"), "1.234,56\u{a0}€");
+        assert_eq!(format("de-CH"), "€\u{a0}1’234.56");
+        assert_eq!(format("pt-BR"), "€\u{a0}1.234,56");
+        assert_eq!(format("ar-EG"), "١٬٢٣٤٫٥٦\u{a0}€");
+    }
+
+    #[test]
+    fn test_format_in_negative_and_zero_decimals() {
+        let jpy = Amount::<JPY>::from_major(-1_234_567);
+        assert_eq!(
+            jpy.format_in(&Locale::parse("ja-JP").unwrap()),
+            "-¥1,234,567"
+        );
+        assert_eq!(
+            jpy.format_in(&Locale::parse("fi").unwrap()),
+            "\u{2212}1\u{a0}234\u{a0}567\u{a0}¥"
+        );
+    }
+
+    #[test]
+    fn test_alternate_display_is_native() {
+        let amount = Amount::<EUR>::from_minor(123_456);
+        assert_eq!(format!("{:#}", amount), amount.format_native());
+        assert_eq!(format!("{}", amount), amount.format_full());
+    }
 }
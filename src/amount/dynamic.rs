@@ -0,0 +1,509 @@
+//! Amounts whose currency is only known at runtime.
+
+use super::parsing::{longest_first, read_number, split_signed, strip_marker, NumberStyle};
+use super::type_def::{cloned_value, Amount};
+use crate::currency::currency_of_region;
+use crate::{
+    known_currencies, AmountPolicy, Currency, CurrencyInfo, Locale, MoneyError, MoneyResult,
+};
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// A monetary amount whose currency is only known at runtime.
+///
+/// Produced by parsing free-form input such as `"C$12"` or `"12 CAD"` with
+/// [`DynAmount::parse`] or [`DynAmount::detect`]. Once the currency has been
+/// checked, [`to_amount`](DynAmount::to_amount) turns it into a typed
+/// [`Amount`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{Amount, CurrencyHints, DynAmount, CAD};
+///
+/// let amount = DynAmount::parse("C$12.50", &CurrencyHints::new())?;
+/// assert_eq!(amount.code(), "CAD");
+///
+/// let cad: Amount<CAD> = amount.to_amount()?;
+/// assert_eq!(cad.to_minor(), 1250);
+/// # Ok::<(), typed_money::MoneyError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynAmount {
+    value: Decimal,
+    currency: CurrencyInfo,
+}
+
+impl DynAmount {
+    /// Creates an amount of `currency` from a value in major units.
+    pub fn new(value: Decimal, currency: CurrencyInfo) -> Self {
+        Self { value, currency }
+    }
+
+    /// Returns the value in major units.
+    pub const fn value(&self) -> &Decimal {
+        &self.value
+    }
+
+    /// Returns the currency of the amount.
+    pub const fn currency(&self) -> CurrencyInfo {
+        self.currency
+    }
+
+    /// Returns the currency code (e.g., "USD").
+    pub const fn code(&self) -> &'static str {
+        self.currency.code()
+    }
+
+    /// Converts to a typed amount of `C`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the amount is in another
+    /// currency.
+    pub fn to_amount<C: Currency>(&self) -> MoneyResult<Amount<C>> {
+        if self.code() != C::CODE {
+            return Err(MoneyError::CurrencyMismatch {
+                expected: C::CODE,
+                found: self.code(),
+                context: "converting a dynamic amount".to_string(),
+            });
+        }
+        Ok(Amount {
+            value: cloned_value(&self.value),
+            _currency: PhantomData,
+        })
+    }
+
+    /// Parses an amount whose currency is given by a code, symbol or alias
+    /// in the input, using `hints` to choose between currencies that share a
+    /// symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`] if the input names no currency,
+    /// names a symbol the hints cannot resolve, or is not a well-formed
+    /// number.
+    /// Values the current [`AmountPolicy`] rejects are reported as by
+    /// [`AmountPolicy::check`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{CurrencyHints, DynAmount, Locale};
+    ///
+    /// assert_eq!(DynAmount::parse("12 CAD", &CurrencyHints::new())?.code(), "CAD");
+    ///
+    /// // "¥" is used by both JPY and CNY
+    /// assert!(DynAmount::parse("¥500", &CurrencyHints::new()).is_err());
+    ///
+    /// let hints = CurrencyHints::new().with_locale(&Locale::parse("zh-CN")?);
+    /// assert_eq!(DynAmount::parse("¥500", &hints)?.code(), "CNY");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn parse(input: &str, hints: &CurrencyHints) -> MoneyResult<Self> {
+        match Self::detect(input, hints)? {
+            Detection::Found(amount) => Ok(amount),
+            Detection::Ambiguous { candidates, .. } => {
+                let codes: Vec<_> = candidates.iter().map(CurrencyInfo::code).collect();
+                Err(MoneyError::ParseError {
+                    input: input.to_string(),
+                    expected_currency: None,
+                    reason: format!("Ambiguous currency, could be {}", codes.join(", ")),
+                })
+            }
+        }
+    }
+
+    /// Parses an amount like [`parse`](Self::parse), but returns every
+    /// matching currency instead of failing when the hints cannot choose.
+    ///
+    /// Codes and aliases name a single currency. A symbol may be shared, in
+    /// which case the currencies using it as their symbol come before those
+    /// using it as their narrow symbol. The hints are then tried in order:
+    /// the preferred currencies, then the currency of the region or locale.
+    ///
+    /// The value is validated against the current thread's
+    /// [`AmountPolicy`] in each candidate currency; candidates it fails in
+    /// are dropped, and the input is rejected if none is left.
+    ///
+    /// Without a locale, numbers are read with a dot for decimals and commas
+    /// between thousands; with one, the locale's separators and digits apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`] if the input names no currency,
+    /// names two different ones, or is not a well-formed number.
+    /// Values the current [`AmountPolicy`] rejects are reported as by
+    /// [`AmountPolicy::check`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{CurrencyHints, Detection, DynAmount};
+    ///
+    /// let detection = DynAmount::detect("$1,200", &CurrencyHints::new())?;
+    /// let Detection::Ambiguous { candidates, .. } = &detection else { unreachable!() };
+    /// assert_eq!(candidates[0].code(), "USD");
+    /// assert!(candidates.iter().any(|info| info.code() == "AUD"));
+    ///
+    /// let hints = CurrencyHints::new().with_region("CA");
+    /// assert_eq!(DynAmount::parse("$1,200", &hints)?.code(), "CAD");
+    ///
+    /// let hints = CurrencyHints::new().with_preferred(&["MXN", "USD"]);
+    /// assert_eq!(DynAmount::parse("$1,200", &hints)?.code(), "MXN");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn detect(input: &str, hints: &CurrencyHints) -> MoneyResult<Detection> {
+        let error = |reason: String| MoneyError::ParseError {
+            input: input.to_string(),
+            expected_currency: None,
+            reason,
+        };
+
+        let known = known_currencies();
+        let markers: Vec<_> = longest_first(known.iter().flat_map(CurrencyInfo::markers)).collect();
+        let style = hints
+            .locale
+            .as_ref()
+            .map_or(NumberStyle::PLAIN, NumberStyle::of_locale);
+
+        let mut found = Vec::new();
+        let policy = AmountPolicy::current();
+        let (negative, working) =
+            split_signed(input, &style, &policy, |text, leading| {
+                match markers
+                    .iter()
+                    .find_map(|marker| Some((*marker, strip_marker(text, marker, leading)?)))
+                {
+                    Some((marker, rest)) => {
+                        found.push(marker);
+                        rest
+                    }
+                    None => text,
+                }
+            })
+            .map_err(error)?;
+        let value = read_number(working, negative, &style).map_err(error)?;
+
+        if found.is_empty() {
+            return Err(error("No currency code or symbol found".to_string()));
+        }
+        let mut candidates: Vec<_> = known
+            .into_iter()
+            .filter(|info| {
+                found
+                    .iter()
+                    .all(|marker| info.markers().any(|own| own == *marker))
+            })
+            .collect();
+        if candidates.is_empty() {
+            return Err(error(format!(
+                "Conflicting currencies: {}",
+                found.join(" and ")
+            )));
+        }
+        // Narrow symbols are the least specific match
+        candidates.sort_by_key(|info| {
+            found
+                .iter()
+                .any(|marker| info.narrow_symbol() == *marker && info.symbol() != *marker)
+        });
+
+        // Currencies the value is not acceptable in under the policy, e.g.
+        // with too many decimals, are no candidates
+        let mut rejected = None;
+        candidates.retain(|info| match policy.check_in(input, &value, *info) {
+            Ok(()) => true,
+            Err(error) => {
+                rejected.get_or_insert(error);
+                false
+            }
+        });
+        if let (Some(error), true) = (rejected, candidates.is_empty()) {
+            return Err(error);
+        }
+
+        let region = hints
+            .region
+            .clone()
+            .or_else(|| hints.locale.as_ref().and_then(Locale::region));
+        let mut preferred = hints
+            .preferred
+            .iter()
+            .map(String::as_str)
+            .chain(region.as_deref().and_then(currency_of_region));
+        let chosen = match candidates.as_slice() {
+            [only] => Some(*only),
+            _ => preferred.find_map(|code| {
+                candidates
+                    .iter()
+                    .find(|info| info.code().eq_ignore_ascii_case(code))
+                    .copied()
+            }),
+        };
+
+        Ok(match chosen {
+            Some(currency) => Detection::Found(DynAmount::new(value, currency)),
+            None => Detection::Ambiguous { value, candidates },
+        })
+    }
+}
+
+impl<C: Currency> From<Amount<C>> for DynAmount {
+    fn from(amount: Amount<C>) -> Self {
+        Self::new(amount.value, CurrencyInfo::of::<C>())
+    }
+}
+
+impl fmt::Display for DynAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, self.code())
+    }
+}
+
+impl FromStr for DynAmount {
+    type Err = MoneyError;
+
+    /// Parses a string into a DynAmount without hints.
+    ///
+    /// See [`DynAmount::parse`] for supported formats.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, &CurrencyHints::new())
+    }
+}
+
+/// The result of [`DynAmount::detect`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Detection {
+    /// The input names a single currency, or the hints chose one.
+    Found(DynAmount),
+    /// The input uses a symbol shared by several currencies and the hints
+    /// did not choose between them.
+    Ambiguous {
+        /// The parsed value in major units
+        value: Decimal,
+        /// The matching currencies, most likely first
+        candidates: Vec<CurrencyInfo>,
+    },
+}
+
+impl Detection {
+    /// Returns the detected amount, taking the most likely candidate if the
+    /// currency is ambiguous, or `None` if there is no candidate.
+    pub fn most_likely(self) -> Option<DynAmount> {
+        match self {
+            Detection::Found(amount) => Some(amount),
+            Detection::Ambiguous { value, candidates } => candidates
+                .first()
+                .map(|currency| DynAmount::new(value, *currency)),
+        }
+    }
+}
+
+/// Hints that resolve symbols shared by several currencies, such as `$` and
+/// `¥`, when parsing with [`DynAmount::detect`] or [`DynAmount::parse`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{CurrencyHints, Locale};
+///
+/// let hints = CurrencyHints::new()
+///     .with_preferred(&["USD"])
+///     .with_locale(&Locale::parse("en-CA")?);
+/// # Ok::<(), typed_money::MoneyError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurrencyHints {
+    preferred: Vec<String>,
+    region: Option<String>,
+    locale: Option<Locale>,
+}
+
+impl CurrencyHints {
+    /// Creates hints that resolve nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefers these currency codes, in order, over the region.
+    pub fn with_preferred(mut self, codes: &[&str]) -> Self {
+        self.preferred = codes.iter().map(|code| code.to_string()).collect();
+        self
+    }
+
+    /// Prefers the currency in use in `region`, an ISO 3166 code such as
+    /// "CA". Overrides the locale's region.
+    pub fn with_region(mut self, region: &str) -> Self {
+        self.region = Some(region.to_ascii_uppercase());
+        self
+    }
+
+    /// Reads numbers the way `locale` writes them, and prefers the currency
+    /// of its region.
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.locale = Some(locale.clone());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CAD, USD};
+
+    fn code(input: &str, hints: &CurrencyHints) -> &'static str {
+        DynAmount::parse(input, hints).unwrap().code()
+    }
+
+    fn candidates(input: &str) -> Vec<&'static str> {
+        match DynAmount::detect(input, &CurrencyHints::new()).unwrap() {
+            Detection::Ambiguous { candidates, .. } => {
+                candidates.iter().map(CurrencyInfo::code).collect()
+            }
+            Detection::Found(amount) => vec![amount.code()],
+        }
+    }
+
+    #[test]
+    fn test_unique_markers() {
+        let none = CurrencyHints::new();
+        assert_eq!(code("12 CAD", &none), "CAD");
+        assert_eq!(code("C$12", &none), "CAD");
+        assert_eq!(code("A$12", &none), "AUD");
+        assert_eq!(
+            code(
+                "R$ 10,00",
+                &none.clone().with_locale(&Locale::parse("pt-BR").unwrap())
+            ),
+            "BRL"
+        );
+        assert_eq!(code("RMB 88", &none), "CNY");
+        assert_eq!(code("€5", &none), "EUR");
+        assert_eq!(code("$12 USD", &none), "USD");
+    }
+
+    #[test]
+    fn test_ambiguous_symbols() {
+        let dollar = candidates("$12");
+        assert_eq!(dollar[0], "USD");
+        // Narrow symbols come last
+        let aud = dollar.iter().position(|code| *code == "AUD").unwrap();
+        let mxn = dollar.iter().position(|code| *code == "MXN").unwrap();
+        assert!(mxn < aud);
+
+        assert_eq!(candidates("¥500"), ["JPY", "CNY"]);
+        assert!(DynAmount::parse("¥500", &CurrencyHints::new()).is_err());
+        assert_eq!(
+            DynAmount::detect("¥500", &CurrencyHints::new())
+                .unwrap()
+                .most_likely()
+                .unwrap()
+                .code(),
+            "JPY"
+        );
+
+        let empty = Detection::Ambiguous {
+            value: Decimal::from(500),
+            candidates: Vec::new(),
+        };
+        assert!(empty.most_likely().is_none());
+    }
+
+    #[test]
+    fn test_hints() {
+        let region = CurrencyHints::new().with_region("ca");
+        assert_eq!(code("$12", &region), "CAD");
+        // A region whose currency does not use the symbol does not help
+        assert!(DynAmount::parse("$12", &CurrencyHints::new().with_region("DE")).is_err());
+
+        let locale = CurrencyHints::new().with_locale(&Locale::parse("ja-JP").unwrap());
+        assert_eq!(code("¥500", &locale), "JPY");
+        let locale = CurrencyHints::new().with_locale(&Locale::parse("zh-CN").unwrap());
+        assert_eq!(code("¥500", &locale), "CNY");
+
+        let preferred = CurrencyHints::new()
+            .with_preferred(&["EUR", "nzd"])
+            .with_region("CA");
+        assert_eq!(code("$12", &preferred), "NZD");
+        // Unambiguous input ignores the hints
+        assert_eq!(code("12 USD", &preferred), "USD");
+    }
+
+    #[test]
+    fn test_locale_numbers() {
+        let de = CurrencyHints::new().with_locale(&Locale::parse("de-DE").unwrap());
+        let amount = DynAmount::parse("-1.234,56 €", &de).unwrap();
+        assert_eq!(
+            amount.to_amount::<crate::EUR>().unwrap().to_minor(),
+            -123_456
+        );
+
+        let amount = DynAmount::parse("(C$1,234.50)", &CurrencyHints::new()).unwrap();
+        assert_eq!(amount.to_amount::<CAD>().unwrap().to_minor(), -123_450);
+    }
+
+    #[test]
+    fn test_errors() {
+        let none = CurrencyHints::new();
+        assert!(matches!(
+            DynAmount::parse("12.34", &none),
+            Err(MoneyError::ParseError {
+                expected_currency: None,
+                ..
+            })
+        ));
+        let err = DynAmount::parse("€12 USD", &none).unwrap_err();
+        assert!(err.to_string().contains("Conflicting currencies"));
+        assert!(DynAmount::parse("", &none).is_err());
+        assert!(DynAmount::parse("USD 1.2.3", &none).is_err());
+        assert!(DynAmount::parse("USD abc", &none).is_err());
+    }
+
+    #[test]
+    fn test_policy_applies() {
+        let none = CurrencyHints::new();
+        AmountPolicy::new().without_negatives().scope(|| {
+            assert!(DynAmount::parse("-5 USD", &none).is_err());
+            assert!("-5 USD".parse::<DynAmount>().is_err());
+            assert!(DynAmount::parse("5 USD", &none).is_ok());
+        });
+
+        // "¥500.5" has too many decimals for JPY, so only CNY is left
+        AmountPolicy::new().with_currency_scale().scope(|| {
+            assert_eq!(code("¥500.5", &none), "CNY");
+            assert!(matches!(
+                DynAmount::parse("¥0.001", &none),
+                Err(MoneyError::PrecisionError {
+                    currency: "JPY",
+                    ..
+                })
+            ));
+        });
+    }
+
+    #[test]
+    fn test_typed_round_trip() {
+        let usd = Amount::<USD>::from_minor(1234);
+        let dynamic = DynAmount::from(usd);
+        assert_eq!(dynamic.to_string(), "12.34 USD");
+        assert_eq!(dynamic.to_amount::<USD>().unwrap(), usd);
+        assert!(matches!(
+            dynamic.to_amount::<CAD>(),
+            Err(MoneyError::CurrencyMismatch {
+                expected: "CAD",
+                found: "USD",
+                ..
+            })
+        ));
+        assert_eq!("12.34 USD".parse::<DynAmount>().unwrap(), dynamic);
+    }
+}
@@ -1,6 +1,6 @@
 //! Currency metadata access methods for Amount.
 
-use crate::{Amount, Currency};
+use crate::{Amount, Currency, Locale};
 
 /// Extension trait for accessing currency metadata from Amount instances.
 ///
@@ -21,6 +21,62 @@ pub trait CurrencyMetadata {
     /// Returns the full name of the currency.
     fn currency_name(&self) -> &'static str;
 
+    /// Returns the name of the currency in the language of `locale`.
+    ///
+    /// Languages without names of their own use the English ones, and
+    /// currencies the language has no name for are written with their ISO
+    /// code, as in [`format_long`](Self::format_long). Defaults to
+    /// [`currency_name`](Self::currency_name).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use typed_money::{Amount, CurrencyMetadata, Locale, JPY, USD};
+    ///
+    /// let usd = Amount::<USD>::from_major(1);
+    /// assert_eq!(usd.currency_name_in(&Locale::parse("es-MX")?), "dólar estadounidense");
+    /// assert_eq!(usd.currency_name_in(&Locale::parse("de")?), "US-Dollar");
+    ///
+    /// let jpy = Amount::<JPY>::from_major(1);
+    /// assert_eq!(jpy.currency_name_in(&Locale::parse("ja")?), "日本円");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    fn currency_name_in(&self, _locale: &Locale) -> &'static str {
+        self.currency_name()
+    }
+
+    /// Formats the amount with the currency name written out, in the plural
+    /// form the number calls for in the language of `locale`.
+    ///
+    /// The amount is rounded to the currency's decimal places, and whole
+    /// amounts are written without decimals. Languages without names of
+    /// their own use the English ones, and currencies the language has no
+    /// name for are written with their ISO code. Defaults to the localized
+    /// currency name alone, for implementors that carry no amount.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use typed_money::{Amount, CurrencyMetadata, Locale, EUR, PLN, USD};
+    ///
+    /// let en = Locale::parse("en-US")?;
+    /// assert_eq!(Amount::<USD>::from_major(1).format_long(&en), "1\u{a0}US dollar");
+    /// assert_eq!(Amount::<USD>::from_major(2).format_long(&en), "2\u{a0}US dollars");
+    /// assert_eq!(Amount::<USD>::from_minor(150).format_long(&en), "1.50\u{a0}US dollars");
+    ///
+    /// let pl = Locale::parse("pl-PL")?;
+    /// assert_eq!(Amount::<PLN>::from_major(2).format_long(&pl), "2\u{a0}złote polskie");
+    /// assert_eq!(Amount::<PLN>::from_major(5).format_long(&pl), "5\u{a0}złotych polskich");
+    ///
+    /// // French uses the singular below two
+    /// let fr = Locale::parse("fr-FR")?;
+    /// assert_eq!(Amount::<EUR>::from_minor(150).format_long(&fr), "1,50\u{a0}euro");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    fn format_long(&self, locale: &Locale) -> String {
+        self.currency_name_in(locale).to_string()
+    }
+
     /// Returns the primary country or region that issues this currency.
     fn currency_country(&self) -> &'static str;
 
@@ -42,18 +98,46 @@ pub trait CurrencyMetadata {
     /// Returns the official ISO 4217 numeric code.
     fn currency_iso_number(&self) -> u16;
 
+    /// Returns the English name of the major unit.
+    ///
+    /// Defaults to [`UnitName::NONE`](crate::UnitName::NONE).
+    fn major_unit_name(&self) -> crate::UnitName {
+        crate::UnitName::NONE
+    }
+
+    /// Returns the English name of the minor unit.
+    ///
+    /// Defaults to [`UnitName::NONE`](crate::UnitName::NONE).
+    fn minor_unit_name(&self) -> crate::UnitName {
+        crate::UnitName::NONE
+    }
+
     /// Returns the character used to separate thousands.
     fn thousands_separator(&self) -> char;
 
     /// Returns the character used as decimal separator.
     fn decimal_separator(&self) -> char;
 
+    /// Returns the digit grouping pattern.
+    ///
+    /// Defaults to groups of three digits.
+    fn grouping(&self) -> crate::Grouping {
+        crate::Grouping::THOUSANDS
+    }
+
     /// Returns the position of currency symbol relative to the amount.
     fn symbol_position(&self) -> crate::SymbolPosition;
 
     /// Returns whether to include a space between symbol and amount.
     fn space_between_symbol(&self) -> bool;
 
+    /// Returns the cash rounding increment in minor units.
+    ///
+    /// Defaults to one minor unit, i.e. no cash rounding.
+    fn cash_rounding_increment(&self) -> u32 {
+        1
+    }
+
     /// Returns the static volatility rating.
     fn volatility_rating(&self) -> crate::VolatilityRating;
 
@@ -69,6 +153,14 @@ impl<C: Currency> CurrencyMetadata for Amount<C> {
         C::NAME
     }
 
+    fn currency_name_in(&self, locale: &Locale) -> &'static str {
+        locale.currency_name(C::CODE).unwrap_or(C::CODE)
+    }
+
+    fn format_long(&self, locale: &Locale) -> String {
+        super::display::format_long(self, locale)
+    }
+
     fn currency_country(&self) -> &'static str {
         C::COUNTRY
     }
@@ -97,6 +189,14 @@ impl<C: Currency> CurrencyMetadata for Amount<C> {
         C::ISO_4217_NUMBER
     }
 
+    fn major_unit_name(&self) -> crate::UnitName {
+        C::MAJOR_UNIT_NAME
+    }
+
+    fn minor_unit_name(&self) -> crate::UnitName {
+        C::MINOR_UNIT_NAME
+    }
+
     fn thousands_separator(&self) -> char {
         C::THOUSANDS_SEPARATOR
     }
@@ -105,6 +205,10 @@ impl<C: Currency> CurrencyMetadata for Amount<C> {
         C::DECIMAL_SEPARATOR
     }
 
+    fn grouping(&self) -> crate::Grouping {
+        C::GROUPING
+    }
+
     fn symbol_position(&self) -> crate::SymbolPosition {
         C::SYMBOL_POSITION
     }
@@ -113,6 +217,10 @@ impl<C: Currency> CurrencyMetadata for Amount<C> {
         C::SPACE_BETWEEN
     }
 
+    fn cash_rounding_increment(&self) -> u32 {
+        C::CASH_ROUNDING_INCREMENT
+    }
+
     fn volatility_rating(&self) -> crate::VolatilityRating {
         C::VOLATILITY_RATING
     }
@@ -191,6 +299,7 @@ mod tests {
         assert_eq!(amount.decimal_separator(), '.');
         assert_eq!(amount.symbol_position(), SymbolPosition::Before);
         assert!(!amount.space_between_symbol());
+        assert_eq!(amount.cash_rounding_increment(), 1);
         assert_eq!(amount.volatility_rating(), VolatilityRating::Medium);
         assert_eq!(amount.liquidity_rating(), LiquidityRating::Medium);
     }
@@ -228,4 +337,24 @@ mod tests {
             "Rich Test Currency (RICH) - Test Country - Fiat - Major"
         );
     }
+
+    #[test]
+    fn test_localized_names_fall_back() {
+        let locale = Locale::parse("fr-FR").unwrap();
+        let amount = Amount::<RichTestCurrency>::from_minor(-123_456);
+
+        // No localized name: the ISO code in both
+        assert_eq!(amount.currency_name_in(&locale), "RICH");
+        assert_eq!(amount.format_long(&locale), "-1\u{202f}234,56\u{a0}RICH");
+
+        let usd = Amount::<crate::USD>::from_minor(-100);
+        assert_eq!(usd.currency_name_in(&locale), "dollar des États-Unis");
+        assert_eq!(usd.format_long(&locale), "-1\u{a0}dollar des États-Unis");
+
+        // A language without names of its own uses the English ones
+        let ar = Locale::parse("ar-EG").unwrap();
+        let eur = Amount::<crate::EUR>::from_minor(150);
+        assert_eq!(eur.currency_name_in(&ar), "Euro");
+        assert_eq!(eur.format_long(&ar), "١٫٥٠\u{a0}euros");
+    }
 }
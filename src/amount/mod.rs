@@ -5,17 +5,24 @@
 //! enabling zero-cost type safety.
 
 mod arithmetic;
+mod binary;
 mod constructors;
 mod conversions;
 mod currency_conversion;
 mod display;
+mod dynamic;
 mod metadata;
 mod parsing;
+mod policy;
 mod precision;
 mod rounding;
 #[cfg(feature = "serde_support")]
 mod serialization;
 mod type_def;
 
+pub use dynamic::{CurrencyHints, Detection, DynAmount};
 pub use metadata::CurrencyMetadata;
+pub use policy::{AmountPolicy, InputFormat};
+pub(crate) use rounding::round_dp;
+pub(crate) use type_def::cloned_value;
 pub use type_def::Amount;
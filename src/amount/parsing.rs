@@ -1,7 +1,11 @@
 //! String parsing for Amount.
 
 use super::type_def::Amount;
-use crate::{Currency, MoneyError, MoneyResult};
+use crate::currency::with_known_currencies;
+use crate::{
+    AmountPolicy, Currency, CurrencyInfo, Grouping, InputFormat, Locale, MoneyError, MoneyResult,
+    LATIN_DIGITS,
+};
 use std::marker::PhantomData;
 use std::str::FromStr;
 
@@ -11,6 +15,36 @@ use rust_decimal::Decimal;
 #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
 use bigdecimal::BigDecimal as Decimal;
 
+/// How the number part of a localized amount is written.
+pub(super) struct NumberStyle {
+    decimal_separator: char,
+    grouping_separator: char,
+    grouping: Grouping,
+    digits: [char; 10],
+    minus_sign: char,
+}
+
+impl NumberStyle {
+    /// A dot for decimals and commas between thousands: 1,234.56.
+    pub(super) const PLAIN: NumberStyle = NumberStyle {
+        decimal_separator: '.',
+        grouping_separator: ',',
+        grouping: Grouping::THOUSANDS,
+        digits: LATIN_DIGITS,
+        minus_sign: '-',
+    };
+
+    pub(super) fn of_locale(locale: &Locale) -> Self {
+        Self {
+            decimal_separator: locale.decimal_separator(),
+            grouping_separator: locale.grouping_separator(),
+            grouping: locale.grouping(),
+            digits: locale.digits(),
+            minus_sign: locale.minus_sign(),
+        }
+    }
+}
+
 impl<C: Currency> Amount<C> {
     /// Parses a string into an Amount.
     ///
@@ -20,20 +54,31 @@ impl<C: Currency> Amount<C> {
     /// - `"12.34 USD"` - with currency code (validates currency matches)
     /// - `"USD 12.34"` - alternative format (validates currency matches)
     /// - `"$12.34 USD"` - with both symbol and code
+    /// - `"RMB 12.34"` - with an alias from [`Currency::ALIASES`]
     ///
-    /// Whitespace is trimmed automatically.
+    /// Whitespace is trimmed automatically. The input is validated against
+    /// the current thread's [`AmountPolicy`].
+    ///
+    /// A code, symbol or alias of any other known currency, built-in or added
+    /// with [`register_currency`](crate::register_currency), is reported as a
+    /// [`MoneyError::CurrencyMismatch`] naming that currency.
+    ///
+    /// Narrow symbols are not accepted here, so `Amount::<AUD>::parse("$10")`
+    /// reports USD rather than reading a US-dollar string as Australian
+    /// dollars. Use [`parse_locale`](Self::parse_locale) or
+    /// [`parse_native`](Self::parse_native) for text written with them.
     ///
     /// # Security
     ///
     /// This parser rejects ambiguous or potentially malicious inputs:
     /// - Multiple decimal points
     /// - Non-numeric characters (except currency symbols/codes)
-    /// - Excessively long strings
+    /// - Excessively long strings (over 100 bytes by default)
     ///
     /// # Examples
     ///
     /// ```
-    /// use typed_money::{Amount, USD};
+    /// use typed_money::{Amount, MoneyError, USD};
     ///
     /// let amount = Amount::<USD>::parse("12.34")?;
     /// assert_eq!(amount.to_minor(), 1234);
@@ -45,91 +90,477 @@ impl<C: Currency> Amount<C> {
     /// assert_eq!(amount3.to_minor(), 1234);
     ///
     /// // Mismatched currency returns error
-    /// assert!(Amount::<USD>::parse("€12.34").is_err());
+    /// let err = Amount::<USD>::parse("R$12.34").unwrap_err();
+    /// assert!(matches!(err, MoneyError::CurrencyMismatch { found: "BRL", .. }));
     /// # Ok::<(), typed_money::MoneyError>(())
     /// ```
     pub fn parse(input: &str) -> MoneyResult<Self> {
-        let trimmed = input.trim();
+        AmountPolicy::with_current(|policy| Self::parse_with_policy(input, policy))
+    }
+
+    /// Parses like [`parse`](Self::parse), validating against `policy`
+    /// instead of the current thread's policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, AmountPolicy, InputFormat, USD};
+    ///
+    /// let codes_only = AmountPolicy::new().with_formats(&[InputFormat::Code]);
+    /// assert!(Amount::<USD>::parse_with_policy("12.34 USD", &codes_only).is_ok());
+    /// assert!(Amount::<USD>::parse_with_policy("$12.34", &codes_only).is_err());
+    /// assert!(Amount::<USD>::parse_with_policy("12.34", &codes_only).is_err());
+    /// ```
+    pub fn parse_with_policy(input: &str, policy: &AmountPolicy) -> MoneyResult<Self> {
+        let error = |reason: String| MoneyError::ParseError {
+            input: input.to_string(),
+            expected_currency: Some(C::CODE),
+            reason,
+        };
 
-        if trimmed.is_empty() {
-            return Err(MoneyError::ParseError {
-                input: input.to_string(),
-                expected_currency: Some(C::CODE),
-                reason: "Empty string".to_string(),
-            });
+        let trimmed = input.trim();
+        check_length(trimmed, policy).map_err(error)?;
+
+        // Remove the currency symbol, then a code or alias at either end
+        let mut formats = Vec::new();
+        let info = CurrencyInfo::of::<C>();
+        let mut working = match strip_marker(trimmed, info.symbol(), true)
+            .filter(|_| !info.symbol().is_empty())
+        {
+            Some(rest) => {
+                formats.push(InputFormat::Symbol);
+                rest
+            }
+            None => trimmed,
+        };
+        let codes =
+            longest_first(std::iter::once(info.code()).chain(info.aliases().iter().copied()));
+        if let Some(rest) = codes
+            .clone()
+            .find_map(|code| strip_marker(working, code, false))
+        {
+            working = rest;
+            formats.push(InputFormat::Code);
+        } else if let Some(rest) = codes
+            .clone()
+            .find_map(|code| strip_marker(working, code, true))
+        {
+            working = rest;
+            formats.push(InputFormat::Code);
         }
 
-        // Check for excessively long input (security)
-        if trimmed.len() > 100 {
-            return Err(MoneyError::ParseError {
-                input: input.to_string(),
-                expected_currency: Some(C::CODE),
-                reason: "Input too long (max 100 characters)".to_string(),
-            });
+        // Parse the numeric value; only text that is not a number can hold
+        // another currency's marker
+        let parsed = Decimal::from_str(working);
+        if parsed.is_err() {
+            if let Some(found) = find_other_currency::<C>(working, false) {
+                return Err(mismatch::<C>(input, found));
+            }
         }
 
-        // Remove currency symbol if present and validate
-        let mut working = trimmed;
-        if working.starts_with(C::SYMBOL) {
-            working = &working[C::SYMBOL.len()..];
-        } else {
-            // Check if it starts with a different currency symbol
-            let other_symbols = ["$", "€", "£", "¥", "₿", "Ξ"];
-            for symbol in &other_symbols {
-                if working.starts_with(symbol) && *symbol != C::SYMBOL {
-                    return Err(MoneyError::ParseError {
-                        input: input.to_string(),
-                        expected_currency: Some(C::CODE),
-                        reason: format!(
-                            "Currency symbol mismatch: found {}, expected {}",
-                            symbol,
-                            C::SYMBOL
-                        ),
-                    });
+        check_formats(&formats, policy).map_err(error)?;
+
+        let decimal_value =
+            parsed.map_err(|_| error(format!("Invalid numeric value: '{}'", working)))?;
+
+        let amount = Self {
+            value: decimal_value,
+            _currency: PhantomData,
+        };
+        policy.check_value(input, &amount)?;
+        Ok(amount)
+    }
+
+    /// Parses an amount written the way `locale` formats it.
+    ///
+    /// Accepts everything [`format_in`](Self::format_in) produces: the
+    /// locale's decimal and grouping separators and digits, the currency
+    /// symbol, narrow symbol, code or alias before or after the number, and
+    /// negatives with a leading or trailing minus sign or in accounting
+    /// parentheses. Regular, non-breaking and narrow no-break spaces are
+    /// interchangeable, both around the symbol and as grouping separators.
+    ///
+    /// # Security
+    ///
+    /// The same protections as [`parse`](Self::parse) apply. In addition,
+    /// grouping separators must sit where the locale's grouping pattern puts
+    /// them, so an input such as `1,23` is rejected rather than guessed at.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the input names a
+    /// different currency, and [`MoneyError::ParseError`] if it is empty, too
+    /// long, or not a well-formed number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, Locale, EUR, INR, USD};
+    ///
+    /// let de = Locale::parse("de-DE")?;
+    /// let amount = Amount::<EUR>::parse_locale("1.234,56 €", &de)?;
+    /// assert_eq!(amount.to_minor(), 123_456);
+    ///
+    /// let en = Locale::parse("en-US")?;
+    /// assert_eq!(Amount::<USD>::parse_locale("($1,234.56)", &en)?.to_minor(), -123_456);
+    /// assert_eq!(Amount::<USD>::parse_locale("1,234.56- USD", &en)?.to_minor(), -123_456);
+    ///
+    /// let en_in = Locale::parse("en-IN")?;
+    /// assert_eq!(Amount::<INR>::parse_locale("₹12,34,567.89", &en_in)?.to_minor(), 123_456_789);
+    ///
+    /// // Separators in the wrong place are ambiguous
+    /// assert!(Amount::<USD>::parse_locale("1,23", &en).is_err());
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn parse_locale(input: &str, locale: &Locale) -> MoneyResult<Self> {
+        Self::parse_styled(input, &NumberStyle::of_locale(locale))
+    }
+
+    /// Parses an amount written with the currency's own separators
+    /// (`THOUSANDS_SEPARATOR`, `DECIMAL_SEPARATOR` and `GROUPING`).
+    ///
+    /// Accepts the same symbol, sign and spacing variations as
+    /// [`parse_locale`](Self::parse_locale).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`parse_locale`](Self::parse_locale).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, CHF, EUR, USD};
+    ///
+    /// assert_eq!(Amount::<EUR>::parse_native("1.234,56 €")?.to_minor(), 123_456);
+    /// assert_eq!(Amount::<USD>::parse_native("-$1,234.56")?.to_minor(), -123_456);
+    /// assert_eq!(Amount::<CHF>::parse_native("CHF 1'234.50")?.to_minor(), 123_450);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn parse_native(input: &str) -> MoneyResult<Self> {
+        Self::parse_styled(
+            input,
+            &NumberStyle {
+                decimal_separator: C::DECIMAL_SEPARATOR,
+                grouping_separator: C::THOUSANDS_SEPARATOR,
+                grouping: C::GROUPING,
+                digits: LATIN_DIGITS,
+                minus_sign: '-',
+            },
+        )
+    }
+
+    /// Parses an amount with a dot for decimals and optional commas between
+    /// thousands, accepting the same variations as
+    /// [`parse_locale`](Self::parse_locale).
+    pub(crate) fn parse_plain(input: &str) -> MoneyResult<Self> {
+        Self::parse_styled(input, &NumberStyle::PLAIN)
+    }
+
+    fn parse_styled(input: &str, style: &NumberStyle) -> MoneyResult<Self> {
+        let error = |reason: String| MoneyError::ParseError {
+            input: input.to_string(),
+            expected_currency: Some(C::CODE),
+            reason,
+        };
+
+        AmountPolicy::with_current(|policy| {
+            let mut formats = Vec::new();
+            let (negative, working) = split_signed(input, style, policy, |text, leading| {
+                let (rest, format) = strip_currency::<C>(text, leading);
+                formats.extend(format);
+                rest
+            })
+            .map_err(error)?;
+            let parsed = read_number(working, negative, style);
+            if parsed.is_err() {
+                if let Some(found) = find_other_currency::<C>(working, true) {
+                    return Err(mismatch::<C>(input, found));
                 }
             }
+            check_formats(&formats, policy).map_err(error)?;
+
+            let amount = Self {
+                value: parsed.map_err(error)?,
+                _currency: PhantomData,
+            };
+            policy.check_value(input, &amount)?;
+            Ok(amount)
+        })
+    }
+}
+
+/// Splits `input` into its sign and number, with `strip` removing the
+/// currency from the start (or end) of what is left.
+///
+/// Negatives may be written in accounting parentheses or with a sign before
+/// or after the currency, or trailing the number.
+pub(super) fn split_signed<'a>(
+    input: &'a str,
+    style: &NumberStyle,
+    policy: &AmountPolicy,
+    mut strip: impl FnMut(&'a str, bool) -> &'a str,
+) -> Result<(bool, &'a str), String> {
+    let trimmed = input.trim();
+    check_length(trimmed, policy)?;
+
+    // Accounting negatives: ($1,234.56)
+    let (parenthesized, mut working) = match trimmed
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        Some(inner) => (true, inner.trim()),
+        None if trimmed.starts_with('(') || trimmed.ends_with(')') => {
+            return Err("Unbalanced parentheses".to_string());
         }
+        None => (false, trimmed),
+    };
+
+    let mut sign = None;
+    working = take_sign(working, style, true, &mut sign)?;
+    working = strip(working, true);
+    working = take_sign(working, style, true, &mut sign)?;
+    working = take_sign(working, style, false, &mut sign)?;
+    working = strip(working, false);
+    working = take_sign(working, style, false, &mut sign)?;
+
+    if parenthesized && sign.is_some() {
+        return Err("Sign inside accounting parentheses".to_string());
+    }
+    Ok((parenthesized || sign == Some('-'), working))
+}
 
-        // Remove currency code if present (at start or end) and validate
-        working = working.trim();
+/// Reads the number left by [`split_signed`], negated if `negative`.
+pub(super) fn read_number(
+    text: &str,
+    negative: bool,
+    style: &NumberStyle,
+) -> Result<Decimal, String> {
+    let number = normalize_number(text, style)?;
+    let value =
+        Decimal::from_str(&number).map_err(|_| format!("Invalid numeric value: '{}'", text))?;
+    Ok(if negative { -value } else { value })
+}
 
-        // Check at the end first (more common: "12.34 USD")
-        if working.ends_with(C::CODE) {
-            working = working[..working.len() - C::CODE.len()].trim();
-        } else if working.starts_with(C::CODE) {
-            // Alternative format: "USD 12.34"
-            working = working[C::CODE.len()..].trim();
-        } else {
-            // Check if it contains a different currency code
-            let codes = ["USD", "EUR", "GBP", "JPY", "BTC", "ETH"];
-            for code in &codes {
-                if (working.ends_with(code) || working.starts_with(code)) && *code != C::CODE {
-                    return Err(MoneyError::ParseError {
-                        input: input.to_string(),
-                        expected_currency: Some(C::CODE),
-                        reason: format!(
-                            "Currency code mismatch: found {}, expected {}",
-                            code,
-                            C::CODE
-                        ),
-                    });
+/// Removes one sign from the start (or end) of `text`, recording it as
+/// `+` or `-`. A second sign is an error.
+fn take_sign<'a>(
+    text: &'a str,
+    style: &NumberStyle,
+    leading: bool,
+    sign: &mut Option<char>,
+) -> Result<&'a str, String> {
+    let first = if leading {
+        text.chars().next()
+    } else {
+        text.chars().next_back()
+    };
+    let found = match first {
+        Some('+') if leading => '+',
+        Some(ch) if ch == '-' || ch == '\u{2212}' || ch == style.minus_sign => '-',
+        _ => return Ok(text),
+    };
+    if sign.is_some() {
+        return Err("Multiple signs".to_string());
+    }
+    *sign = Some(found);
+    let width = first.map_or(0, char::len_utf8);
+    Ok(if leading {
+        text[width..].trim()
+    } else {
+        text[..text.len() - width].trim()
+    })
+}
+
+/// Rejects empty input and input longer than the policy allows.
+pub(super) fn check_length(trimmed: &str, policy: &AmountPolicy) -> Result<(), String> {
+    if trimmed.is_empty() {
+        return Err("Empty string".to_string());
+    }
+    if trimmed.len() > policy.max_input_len() {
+        return Err(format!(
+            "max_input_len: Input too long (max {} characters)",
+            policy.max_input_len()
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects currency markers, or their absence, that the policy does not
+/// accept.
+fn check_formats(used: &[InputFormat], policy: &AmountPolicy) -> Result<(), String> {
+    if used.is_empty() && !policy.allows_format(InputFormat::Plain) {
+        return Err("formats: a currency symbol or code is required".to_string());
+    }
+    match used.iter().find(|format| !policy.allows_format(**format)) {
+        Some(InputFormat::Symbol) => Err("formats: currency symbols are not accepted".to_string()),
+        Some(_) => Err("formats: currency codes are not accepted".to_string()),
+        None => Ok(()),
+    }
+}
+
+/// Removes the currency's code, symbol, narrow symbol or alias from the
+/// start (or end) of `text`, reporting which kind was found.
+fn strip_currency<C: Currency>(text: &str, leading: bool) -> (&str, Option<InputFormat>) {
+    let info = CurrencyInfo::of::<C>();
+    longest_first(info.markers())
+        .find_map(|marker| {
+            let rest = strip_marker(text, marker, leading)?;
+            let is_code = marker == info.code() || info.aliases().contains(&marker);
+            Some((
+                rest,
+                Some(if is_code {
+                    InputFormat::Code
+                } else {
+                    InputFormat::Symbol
+                }),
+            ))
+        })
+        .unwrap_or((text, None))
+}
+
+/// Sorts markers longest first, so that "A$" wins over "$", dropping empty
+/// ones.
+pub(super) fn longest_first(
+    markers: impl IntoIterator<Item = &'static str>,
+) -> impl Iterator<Item = &'static str> + Clone {
+    let mut markers: Vec<_> = markers
+        .into_iter()
+        .filter(|marker| !marker.is_empty())
+        .collect();
+    markers.sort_by_key(|marker| std::cmp::Reverse(marker.len()));
+    markers.into_iter()
+}
+
+/// Removes `marker` from the start (or end) of `text`, unless it runs into a
+/// letter, so that "USD" is not found at the start of "USDC 10".
+pub(super) fn strip_marker<'a>(text: &'a str, marker: &str, leading: bool) -> Option<&'a str> {
+    let (rest, next) = if leading {
+        let rest = text.strip_prefix(marker)?;
+        (rest, rest.chars().next())
+    } else {
+        let rest = text.strip_suffix(marker)?;
+        (rest, rest.chars().next_back())
+    };
+    match next {
+        Some(ch) if ch.is_alphabetic() => None,
+        _ => Some(rest.trim()),
+    }
+}
+
+/// Finds another known currency whose code, symbol or alias sits at either
+/// end of `text` next to a number, and returns its code.
+///
+/// The longest marker wins, so "R$10" names BRL rather than a dollar. Shared
+/// markers that `C` also uses are not a mismatch; `C`'s narrow symbol counts
+/// as one of them only if `narrow` is set, so "$" is AUD's own in localized
+/// parsing but names USD otherwise.
+fn find_other_currency<C: Currency>(text: &str, narrow: bool) -> Option<&'static str> {
+    let matched = |marker: &str| {
+        [true, false].into_iter().any(|leading| {
+            strip_marker(text, marker, leading)
+                .is_some_and(|rest| rest.chars().any(char::is_numeric))
+        })
+    };
+    let longest = |info: &CurrencyInfo, narrow: bool| {
+        info.markers()
+            .filter(|marker| narrow || *marker != info.narrow_symbol())
+            .filter(|marker| matched(marker))
+            .map(str::len)
+            .max()
+    };
+
+    let own = longest(&CurrencyInfo::of::<C>(), narrow).unwrap_or(0);
+    let mut found: Option<(&'static str, usize)> = None;
+    with_known_currencies(|currencies| {
+        for info in currencies.filter(|info| info.code() != C::CODE) {
+            if let Some(len) = longest(info, true) {
+                // Earlier currencies win ties, so "$" is read as USD
+                if len > own && found.map_or(true, |(_, best)| len > best) {
+                    found = Some((info.code(), len));
                 }
             }
         }
+    });
+    found.map(|(code, _)| code)
+}
 
-        working = working.trim();
+fn mismatch<C: Currency>(input: &str, found: &'static str) -> MoneyError {
+    MoneyError::CurrencyMismatch {
+        expected: C::CODE,
+        found,
+        context: format!("parsing '{}'", input.trim()),
+    }
+}
 
-        // Parse the numeric value
-        let decimal_value = Decimal::from_str(working).map_err(|_| MoneyError::ParseError {
-            input: input.to_string(),
-            expected_currency: Some(C::CODE),
-            reason: format!("Invalid numeric value: '{}'", working),
-        })?;
+fn is_space(ch: char) -> bool {
+    matches!(ch, ' ' | '\u{a0}' | '\u{202f}')
+}
 
-        Ok(Self {
-            value: decimal_value,
-            _currency: PhantomData,
-        })
+fn is_apostrophe(ch: char) -> bool {
+    matches!(ch, '\'' | '\u{2019}')
+}
+
+/// Turns a localized unsigned number into a plain one such as `1234.56`,
+/// checking that grouping separators sit where the grouping pattern puts
+/// them.
+fn normalize_number(text: &str, style: &NumberStyle) -> Result<String, String> {
+    let decimal = style.decimal_separator;
+    let grouping = style.grouping_separator;
+    let is_group = |ch: char| {
+        ch == grouping
+            || (is_space(grouping) && is_space(ch))
+            || (is_apostrophe(grouping) && is_apostrophe(ch))
+    };
+    if is_group(decimal) {
+        return Err("Decimal and grouping separators are the same".to_string());
+    }
+
+    let mut grouped = String::new();
+    let mut fraction: Option<String> = None;
+    for ch in text.chars() {
+        let digit = if ch.is_ascii_digit() {
+            Some(ch)
+        } else {
+            style
+                .digits
+                .iter()
+                .position(|candidate| *candidate == ch)
+                .and_then(|digit| char::from_digit(digit as u32, 10))
+        };
+        match (digit, &mut fraction) {
+            (Some(digit), Some(fraction)) => fraction.push(digit),
+            (Some(digit), None) => grouped.push(digit),
+            (None, None) if ch == decimal => fraction = Some(String::new()),
+            (None, Some(_)) if ch == decimal => {
+                return Err("Multiple decimal separators".to_string());
+            }
+            (None, None) if is_group(ch) => grouped.push(','),
+            (None, Some(_)) if is_group(ch) => {
+                return Err("Grouping separator after the decimal separator".to_string());
+            }
+            _ => return Err(format!("Invalid numeric value: '{}'", text)),
+        }
+    }
+
+    let integer: String = grouped.chars().filter(|ch| *ch != ',').collect();
+    if integer.len() != grouped.len() {
+        let expected = style.grouping.with_min_grouping(1).apply(&integer, ',');
+        if expected != grouped {
+            return Err(format!(
+                "Digit grouping does not match the locale: '{}'",
+                text
+            ));
+        }
+    }
+
+    match fraction {
+        Some(fraction) if fraction.is_empty() => {
+            Err("Missing digits after the decimal separator".to_string())
+        }
+        Some(fraction) if integer.is_empty() => Ok(format!("0.{}", fraction)),
+        Some(fraction) => Ok(format!("{}.{}", integer, fraction)),
+        None if integer.is_empty() => Err(format!("Invalid numeric value: '{}'", text)),
+        None => Ok(integer),
     }
 }
 
@@ -147,7 +578,7 @@ impl<C: Currency> FromStr for Amount<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{EUR, GBP, JPY, USD};
+    use crate::{AUD, BTC, CAD, CNY, EUR, GBP, INR, JPY, USD};
 
     // ========================================================================
     // Parsing Tests - Numeric Only
@@ -209,11 +640,14 @@ mod tests {
     fn test_parse_symbol_mismatch() {
         // USD parser with EUR symbol should fail
         let result = Amount::<USD>::parse("€12.34");
-        assert!(result.is_err());
-
-        if let Err(e) = result {
-            assert!(e.to_string().contains("symbol mismatch"));
-        }
+        assert!(matches!(
+            result,
+            Err(MoneyError::CurrencyMismatch {
+                expected: "USD",
+                found: "EUR",
+                ..
+            })
+        ));
     }
 
     // ========================================================================
@@ -236,11 +670,70 @@ mod tests {
     fn test_parse_code_mismatch() {
         // USD parser with EUR code should fail
         let result = Amount::<USD>::parse("12.34 EUR");
-        assert!(result.is_err());
+        assert!(matches!(
+            result,
+            Err(MoneyError::CurrencyMismatch { found: "EUR", .. })
+        ));
+    }
 
-        if let Err(e) = result {
-            assert!(e.to_string().contains("code mismatch"));
+    fn found<C: Currency>(input: &str) -> Option<&'static str> {
+        match Amount::<C>::parse(input) {
+            Err(MoneyError::CurrencyMismatch { found, .. }) => Some(found),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_detects_any_known_currency() {
+        assert_eq!(found::<USD>("R$10"), Some("BRL"));
+        assert_eq!(found::<USD>("10 CHF"), Some("CHF"));
+        assert_eq!(found::<USD>("A$10"), Some("AUD"));
+        assert_eq!(found::<USD>("₹1,00"), Some("INR"));
+        assert_eq!(found::<USD>("10 zł"), Some("PLN"));
+        assert_eq!(found::<USD>("USDC 10"), Some("USDC"));
+        assert_eq!(found::<EUR>("$10"), Some("USD"));
+        assert_eq!(found::<EUR>("XBT 0.5"), Some("BTC"));
+        assert_eq!(found::<JPY>("RMB 10"), Some("CNY"));
+    }
+
+    #[test]
+    fn test_parse_aliases_and_shared_symbols() {
+        assert_eq!(Amount::<CNY>::parse("RMB 10").unwrap().to_minor(), 1000);
+        assert_eq!(Amount::<CNY>::parse("10 RMB").unwrap().to_minor(), 1000);
+        assert_eq!(
+            Amount::<BTC>::parse("0.5 XBT").unwrap(),
+            Amount::<BTC>::parse("0.5").unwrap()
+        );
+        // "$" is only AUD's narrow symbol, so strict parsing reads it as USD
+        assert_eq!(found::<AUD>("$10"), Some("USD"));
+        assert_eq!(found::<CAD>("10 $"), Some("USD"));
+        assert_eq!(Amount::<AUD>::parse_native("$10").unwrap().to_minor(), 1000);
+        // "¥" is shared by CNY and JPY
+        assert_eq!(Amount::<CNY>::parse("¥10").unwrap().to_minor(), 1000);
+        // Not followed by a number, so not a currency
+        assert!(matches!(
+            Amount::<USD>::parse("Rubbish"),
+            Err(MoneyError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_detects_registered_currency() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        struct Miles;
+
+        impl Currency for Miles {
+            const DECIMALS: u8 = 0;
+            const CODE: &'static str = "MLS";
+            const SYMBOL: &'static str = "✈";
+            const ALIASES: &'static [&'static str] = &["MILES"];
         }
+
+        assert_eq!(found::<USD>("500 MILES"), None);
+        crate::register_currency::<Miles>();
+        assert_eq!(found::<USD>("500 MILES"), Some("MLS"));
+        assert_eq!(found::<USD>("✈500"), Some("MLS"));
+        assert_eq!(Amount::<Miles>::parse("500 MILES").unwrap().to_minor(), 500);
     }
 
     // ========================================================================
@@ -490,4 +983,236 @@ mod tests {
             );
         }
     }
+
+    // ========================================================================
+    // Locale-Aware Parsing Tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_applies_current_policy() {
+        let policy = AmountPolicy::new()
+            .with_currency_scale()
+            .without_negatives()
+            .with_max_input_len(12)
+            .with_formats(&[InputFormat::Plain, InputFormat::Code]);
+        policy.scope(|| {
+            assert_eq!(Amount::<USD>::parse("12.340 USD").unwrap().to_minor(), 1234);
+            assert!(matches!(
+                "12.345".parse::<Amount<USD>>(),
+                Err(MoneyError::PrecisionError { actual: 3, .. })
+            ));
+            let reason = |input: &str| match Amount::<USD>::parse(input) {
+                Err(MoneyError::ParseError { reason, .. }) => reason,
+                other => panic!("unexpected result: {:?}", other),
+            };
+            assert!(reason("-12.34").starts_with("allow_negative"));
+            assert!(reason("$12.34").starts_with("formats"));
+            assert!(reason("1234567890.12").starts_with("max_input_len"));
+        });
+        assert!(Amount::<USD>::parse("$-12.345").is_ok());
+    }
+
+    #[test]
+    fn test_parse_locale_applies_current_policy() {
+        let de = Locale::parse("de-DE").unwrap();
+        let symbols_only = AmountPolicy::new()
+            .with_formats(&[InputFormat::Symbol])
+            .with_max_magnitude(Decimal::from(1_000));
+        symbols_only.scope(|| {
+            assert!(Amount::<EUR>::parse_locale("999,99 €", &de).is_ok());
+            assert!(Amount::<EUR>::parse_locale("999,99", &de).is_err());
+            assert!(Amount::<EUR>::parse_locale("999,99 EUR", &de).is_err());
+            let err = Amount::<EUR>::parse_locale("1.000,01 €", &de).unwrap_err();
+            assert!(err.to_string().contains("max_magnitude"));
+        });
+    }
+
+    #[test]
+    fn test_parse_locale_german() {
+        let de = Locale::parse("de-DE").unwrap();
+        let expected = Amount::<EUR>::from_minor(123_456);
+        for input in [
+            "1.234,56 €",
+            "1.234,56\u{a0}€",
+            "1234,56 €",
+            "1.234,56 EUR",
+            "EUR 1.234,56",
+            "1.234,56",
+        ] {
+            assert_eq!(
+                Amount::<EUR>::parse_locale(input, &de).unwrap(),
+                expected,
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_locale_negatives() {
+        let en = Locale::parse("en-US").unwrap();
+        let expected = Amount::<USD>::from_minor(-123_456);
+        for input in [
+            "-$1,234.56",
+            "$-1,234.56",
+            "($1,234.56)",
+            "( $1,234.56 )",
+            "1,234.56-",
+            "$1,234.56-",
+            "1,234.56 USD-",
+            "\u{2212}$1,234.56",
+        ] {
+            assert_eq!(
+                Amount::<USD>::parse_locale(input, &en).unwrap(),
+                expected,
+                "{:?}",
+                input
+            );
+        }
+        assert_eq!(
+            Amount::<USD>::parse_locale("+$1.50", &en).unwrap(),
+            Amount::<USD>::from_minor(150)
+        );
+    }
+
+    #[test]
+    fn test_parse_locale_spaces() {
+        let fr = Locale::parse("fr-FR").unwrap();
+        let expected = Amount::<EUR>::from_minor(123_456_789);
+        for input in [
+            "1\u{202f}234\u{202f}567,89\u{a0}€",
+            "1\u{a0}234\u{a0}567,89 €",
+            "1 234 567,89 €",
+            " 1 234 567,89 EUR ",
+        ] {
+            assert_eq!(
+                Amount::<EUR>::parse_locale(input, &fr).unwrap(),
+                expected,
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_locale_digits() {
+        let ar = Locale::parse("ar-EG").unwrap();
+        let amount = Amount::<EUR>::parse_locale("١٬٢٣٤٫٥٦\u{a0}€", &ar).unwrap();
+        assert_eq!(amount.to_minor(), 123_456);
+    }
+
+    #[test]
+    fn test_parse_locale_rejects_ambiguous_input() {
+        let en = Locale::parse("en-US").unwrap();
+        for input in [
+            "1,23",
+            "12,34,567.89",
+            "1,234.56.78",
+            "1.234,56",
+            ",123",
+            "1,234.",
+            "--1",
+            "-(1.00)",
+            "(1.00",
+            "1 234.56",
+            "$",
+            "",
+        ] {
+            assert!(
+                Amount::<USD>::parse_locale(input, &en).is_err(),
+                "{:?}",
+                input
+            );
+        }
+
+        let de = Locale::parse("de-DE").unwrap();
+        // A lone dot is a grouping separator in German
+        assert!(Amount::<EUR>::parse_locale("1.5", &de).is_err());
+        assert!(Amount::<EUR>::parse_locale(&"1".repeat(101), &de).is_err());
+    }
+
+    #[test]
+    fn test_parse_locale_currency_mismatch() {
+        let de = Locale::parse("de-DE").unwrap();
+        let err = Amount::<EUR>::parse_locale("1.234,56 $", &de).unwrap_err();
+        assert!(matches!(
+            err,
+            MoneyError::CurrencyMismatch { found: "USD", .. }
+        ));
+
+        let err = Amount::<EUR>::parse_locale("USD 1.234,56", &de).unwrap_err();
+        assert!(matches!(
+            err,
+            MoneyError::CurrencyMismatch { found: "USD", .. }
+        ));
+
+        let err = Amount::<EUR>::parse_locale("-1.234,56 CHF", &de).unwrap_err();
+        assert!(matches!(
+            err,
+            MoneyError::CurrencyMismatch { found: "CHF", .. }
+        ));
+        assert_eq!(err.currency(), Some("EUR"));
+    }
+
+    #[test]
+    fn test_parse_locale_round_trips_format_in() {
+        for tag in Locale::builtin_tags() {
+            let locale = Locale::parse(tag).unwrap();
+            for minor in [123_456_789, -123_456_789, 5, -5, 0] {
+                let amount = Amount::<USD>::from_minor(minor);
+                let formatted = amount.format_in(&locale);
+                assert_eq!(
+                    Amount::<USD>::parse_locale(&formatted, &locale).unwrap(),
+                    amount,
+                    "{} {:?}",
+                    tag,
+                    formatted
+                );
+            }
+
+            let inr = Amount::<INR>::from_minor(-123_456_789);
+            let formatted = inr.format_in(&locale);
+            assert_eq!(
+                Amount::<INR>::parse_locale(&formatted, &locale).unwrap(),
+                inr,
+                "{} {:?}",
+                tag,
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_native() {
+        assert_eq!(
+            Amount::<EUR>::parse_native("1.234,56 €")
+                .unwrap()
+                .to_minor(),
+            123_456
+        );
+        assert_eq!(
+            Amount::<INR>::parse_native("₹12,34,567.89")
+                .unwrap()
+                .to_minor(),
+            123_456_789
+        );
+        assert_eq!(
+            Amount::<AUD>::parse_native("A$1,000").unwrap(),
+            Amount::<AUD>::parse_native("$1,000").unwrap()
+        );
+        assert_eq!(
+            Amount::<USD>::parse_native("(1,000.00 USD)")
+                .unwrap()
+                .to_minor(),
+            -100_000
+        );
+        assert!(Amount::<EUR>::parse_native("1,234.56 €").is_err());
+    }
+
+    #[test]
+    fn test_parse_native_round_trips_display() {
+        let amount = Amount::<EUR>::from_minor(-123_456_789);
+        let formatted = amount.format_with(&crate::MoneyFormatter::new());
+        assert_eq!(Amount::<EUR>::parse_native(&formatted).unwrap(), amount);
+    }
 }
@@ -0,0 +1,433 @@
+//! Validation policy for amounts read from untrusted input.
+//!
+//! An [`AmountPolicy`] limits what [`Amount::parse`], the locale-aware
+//! parsers, `FromStr` and serde deserialization accept. Like
+//! [`MoneyContext`](crate::MoneyContext) it is scoped to the current thread,
+//! so a request handler can install a strict policy around the code that
+//! reads its payload.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::{Amount, AmountPolicy, InputFormat, MoneyError, USD};
+//!
+//! let payments = AmountPolicy::new()
+//!     .with_currency_scale()
+//!     .without_negatives()
+//!     .with_formats(&[InputFormat::Plain]);
+//!
+//! payments.scope(|| {
+//!     assert!(Amount::<USD>::parse("12.34").is_ok());
+//!     assert!(matches!(
+//!         Amount::<USD>::parse("12.345678"),
+//!         Err(MoneyError::PrecisionError { .. })
+//!     ));
+//!     assert!(Amount::<USD>::parse("-12.34").is_err());
+//!     assert!(Amount::<USD>::parse("$12.34").is_err());
+//! });
+//!
+//! // Outside the scope the permissive default applies again
+//! assert!(Amount::<USD>::parse("12.345678").is_ok());
+//! ```
+
+use super::type_def::Amount;
+use crate::{Currency, CurrencyInfo, MoneyError, MoneyResult};
+use std::cell::RefCell;
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// Longest input accepted by the default policy, in bytes.
+const DEFAULT_MAX_INPUT_LEN: usize = 100;
+
+/// How an amount is written, as restricted by
+/// [`AmountPolicy::with_formats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputFormat {
+    /// A number without currency: `12.34`
+    Plain,
+    /// A number with the currency symbol: `$12.34`
+    Symbol,
+    /// A number with the currency code or an alias: `12.34 USD`
+    Code,
+}
+
+/// Limits on the amounts accepted from text or serialized data.
+///
+/// The default policy accepts any value up to 100 characters long, in any
+/// format, which matches the crate's behaviour without a policy. Violations
+/// are reported as [`MoneyError::PrecisionError`] for excess decimal places
+/// and [`MoneyError::ParseError`] otherwise, naming the policy field that
+/// failed.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use typed_money::{Amount, AmountPolicy, USD};
+///
+/// let policy = AmountPolicy::new()
+///     .with_min_magnitude(Decimal::new(50, 2))
+///     .with_max_magnitude(Decimal::from(10_000));
+///
+/// assert!(policy.check(&Amount::<USD>::from_minor(1_999)).is_ok());
+///
+/// let err = policy.check(&Amount::<USD>::from_major(20_000)).unwrap_err();
+/// assert!(err.to_string().contains("max_magnitude"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmountPolicy {
+    max_input_len: usize,
+    max_scale: Option<u32>,
+    currency_scale: bool,
+    min_magnitude: Option<Decimal>,
+    max_magnitude: Option<Decimal>,
+    allow_negative: bool,
+    formats: Vec<InputFormat>,
+}
+
+thread_local! {
+    static CURRENT_POLICY: RefCell<AmountPolicy> = RefCell::new(AmountPolicy::new());
+}
+
+impl AmountPolicy {
+    /// Creates the default, permissive policy.
+    pub fn new() -> Self {
+        Self {
+            max_input_len: DEFAULT_MAX_INPUT_LEN,
+            max_scale: None,
+            currency_scale: false,
+            min_magnitude: None,
+            max_magnitude: None,
+            allow_negative: true,
+            formats: vec![InputFormat::Plain, InputFormat::Symbol, InputFormat::Code],
+        }
+    }
+
+    /// Returns the policy with inputs longer than `len` bytes rejected.
+    pub fn with_max_input_len(mut self, len: usize) -> Self {
+        self.max_input_len = len;
+        self
+    }
+
+    /// Returns the policy with values of more than `scale` significant
+    /// decimal places rejected.
+    pub fn with_max_scale(mut self, scale: u32) -> Self {
+        self.max_scale = Some(scale);
+        self
+    }
+
+    /// Returns the policy with values of more significant decimal places
+    /// than the currency's `DECIMALS` rejected, so `12.345` is refused for
+    /// USD but `12.340` is not.
+    pub fn with_currency_scale(mut self) -> Self {
+        self.currency_scale = true;
+        self
+    }
+
+    /// Returns the policy with values smaller than `magnitude` in absolute
+    /// terms rejected.
+    pub fn with_min_magnitude(mut self, magnitude: Decimal) -> Self {
+        self.min_magnitude = Some(magnitude);
+        self
+    }
+
+    /// Returns the policy with values larger than `magnitude` in absolute
+    /// terms rejected.
+    pub fn with_max_magnitude(mut self, magnitude: Decimal) -> Self {
+        self.max_magnitude = Some(magnitude);
+        self
+    }
+
+    /// Returns the policy with negative values rejected.
+    pub fn without_negatives(mut self) -> Self {
+        self.allow_negative = false;
+        self
+    }
+
+    /// Returns the policy with only the given input formats accepted.
+    ///
+    /// An input with both symbol and code, such as `$12.34 USD`, needs both
+    /// formats. Serialized amounts carry their code in a separate field and
+    /// are not restricted by format.
+    pub fn with_formats(mut self, formats: &[InputFormat]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    /// Returns the longest accepted input, in bytes.
+    pub const fn max_input_len(&self) -> usize {
+        self.max_input_len
+    }
+
+    /// Returns the most significant decimal places accepted for `C`, if
+    /// limited.
+    pub fn max_scale_for<C: Currency>(&self) -> Option<u32> {
+        self.max_scale_with(C::DECIMALS)
+    }
+
+    /// Returns the most significant decimal places accepted for a currency
+    /// of `decimals` places, if limited.
+    fn max_scale_with(&self, decimals: u8) -> Option<u32> {
+        let currency = self.currency_scale.then_some(u32::from(decimals));
+        match (self.max_scale, currency) {
+            (Some(max), Some(currency)) => Some(max.min(currency)),
+            (max, currency) => max.or(currency),
+        }
+    }
+
+    /// Returns whether negative values are accepted.
+    pub const fn allows_negative(&self) -> bool {
+        self.allow_negative
+    }
+
+    /// Returns whether `format` is accepted.
+    pub fn allows_format(&self, format: InputFormat) -> bool {
+        self.formats.contains(&format)
+    }
+
+    /// Checks `amount` against the scale, magnitude and sign limits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::PrecisionError`] if the amount has too many
+    /// significant decimal places, and [`MoneyError::ParseError`] if it is
+    /// negative or out of range.
+    pub fn check<C: Currency>(&self, amount: &Amount<C>) -> MoneyResult<()> {
+        self.check_value(&amount.value.to_string(), amount)
+    }
+
+    /// Checks a parsed amount, reporting `input` in errors.
+    pub(crate) fn check_value<C: Currency>(
+        &self,
+        input: &str,
+        amount: &Amount<C>,
+    ) -> MoneyResult<()> {
+        self.check_in(input, &amount.value, CurrencyInfo::of::<C>())
+    }
+
+    /// Checks a parsed value in `currency`, reporting `input` in errors.
+    pub(crate) fn check_in(
+        &self,
+        input: &str,
+        value: &Decimal,
+        currency: CurrencyInfo,
+    ) -> MoneyResult<()> {
+        let error = |field: &str, reason: String| MoneyError::ParseError {
+            input: input.to_string(),
+            expected_currency: Some(currency.code()),
+            reason: format!("{}: {}", field, reason),
+        };
+
+        if let Some(max) = self.max_scale_with(currency.decimals()) {
+            let actual = significant_scale(value);
+            if actual > max {
+                let field = if self.max_scale == Some(max) {
+                    "max_scale"
+                } else {
+                    "currency_scale"
+                };
+                return Err(MoneyError::PrecisionError {
+                    currency: currency.code(),
+                    expected: u8::try_from(max).unwrap_or(u8::MAX),
+                    actual,
+                    suggestion: format!(
+                        "{}: at most {} decimal places are accepted; round the amount first",
+                        field, max
+                    ),
+                });
+            }
+        }
+
+        let zero = Decimal::from(0);
+        if !self.allow_negative && *value < zero {
+            return Err(error(
+                "allow_negative",
+                "negative amounts are not accepted".to_string(),
+            ));
+        }
+        let magnitude = value.abs();
+        if let Some(min) = &self.min_magnitude {
+            if magnitude < *min {
+                return Err(error(
+                    "min_magnitude",
+                    format!("{} is below {}", magnitude, min),
+                ));
+            }
+        }
+        if let Some(max) = &self.max_magnitude {
+            if magnitude > *max {
+                return Err(error(
+                    "max_magnitude",
+                    format!("{} is above {}", magnitude, max),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the policy of the current thread.
+    pub fn current() -> Self {
+        CURRENT_POLICY.with(|current| current.borrow().clone())
+    }
+
+    /// Runs `f` with the policy of the current thread, without cloning it.
+    pub(crate) fn with_current<R>(f: impl FnOnce(&AmountPolicy) -> R) -> R {
+        CURRENT_POLICY.with(|current| f(&current.borrow()))
+    }
+
+    /// Replaces the policy of the current thread, returning the previous one.
+    ///
+    /// Prefer [`scope`](Self::scope) for temporary overrides; it restores the
+    /// previous policy even if the closure panics.
+    pub fn set_current(policy: Self) -> Self {
+        CURRENT_POLICY.with(|current| current.replace(policy))
+    }
+
+    /// Restores the default policy on the current thread, returning the
+    /// previous one.
+    pub fn reset_current() -> Self {
+        Self::set_current(Self::new())
+    }
+
+    /// Runs `f` with this policy installed on the current thread.
+    ///
+    /// The previous policy is restored when `f` returns or panics.
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<AmountPolicy>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(policy) = self.0.take() {
+                    AmountPolicy::set_current(policy);
+                }
+            }
+        }
+
+        let _restore = Restore(Some(Self::set_current(self)));
+        f()
+    }
+}
+
+impl Default for AmountPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the number of decimal places of `value` without trailing zeros.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn significant_scale(value: &Decimal) -> u32 {
+    value.normalize().scale()
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn significant_scale(value: &Decimal) -> u32 {
+    let (_, scale) = value.normalized().as_bigint_and_exponent();
+    u32::try_from(scale.max(0)).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JPY, USD};
+
+    #[test]
+    fn test_default_policy_accepts_anything() {
+        let policy = AmountPolicy::new();
+        assert_eq!(policy.max_input_len(), 100);
+        assert_eq!(policy.max_scale_for::<USD>(), None);
+        assert!(policy.allows_negative());
+        assert!(policy.check(&(Amount::<USD>::from_major(-1) / 3)).is_ok());
+    }
+
+    #[test]
+    fn test_scale_limits() {
+        let policy = AmountPolicy::new().with_currency_scale();
+        assert_eq!(policy.max_scale_for::<USD>(), Some(2));
+        assert_eq!(policy.max_scale_for::<JPY>(), Some(0));
+        assert_eq!(
+            policy.clone().with_max_scale(1).max_scale_for::<USD>(),
+            Some(1)
+        );
+        assert_eq!(
+            AmountPolicy::new().with_max_scale(4).max_scale_for::<JPY>(),
+            Some(4)
+        );
+
+        // Trailing zeros are not excess precision
+        let padded = Amount::<USD>::from_minor(1_234_000) / 1000;
+        assert!(policy.check(&padded).is_ok());
+
+        let err = policy
+            .check(&(Amount::<USD>::from_minor(12_345) / 1000))
+            .unwrap_err();
+        match err {
+            MoneyError::PrecisionError {
+                currency,
+                expected,
+                actual,
+                suggestion,
+            } => {
+                assert_eq!((currency, expected, actual), ("USD", 2, 5));
+                assert!(suggestion.starts_with("currency_scale"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        // The actual scale is read off the value, however deep it goes
+        let tiny = Amount::<USD>::parse("0.0000000000000000000000000001").unwrap();
+        assert!(matches!(
+            policy.check(&tiny),
+            Err(MoneyError::PrecisionError { actual: 28, .. })
+        ));
+    }
+
+    #[test]
+    fn test_sign_and_magnitude() {
+        let policy = AmountPolicy::new()
+            .without_negatives()
+            .with_min_magnitude(Decimal::from(1))
+            .with_max_magnitude(Decimal::from(100));
+        assert!(policy.check(&Amount::<USD>::from_major(100)).is_ok());
+
+        let reason = |amount: Amount<USD>| match policy.check(&amount) {
+            Err(MoneyError::ParseError { reason, .. }) => reason,
+            other => panic!("unexpected result: {:?}", other),
+        };
+        assert!(reason(Amount::from_major(-5)).starts_with("allow_negative"));
+        assert!(reason(Amount::from_minor(50)).starts_with("min_magnitude"));
+        assert!(reason(Amount::from_minor(10_001)).starts_with("max_magnitude"));
+    }
+
+    #[test]
+    fn test_scope_restores_policy() {
+        let strict = AmountPolicy::new().with_max_input_len(10);
+        strict.clone().scope(|| {
+            assert_eq!(AmountPolicy::current(), strict);
+            AmountPolicy::new().scope(|| {
+                assert_eq!(AmountPolicy::current(), AmountPolicy::new());
+            });
+            assert_eq!(AmountPolicy::current().max_input_len(), 10);
+        });
+        assert_eq!(AmountPolicy::current(), AmountPolicy::new());
+
+        let previous = AmountPolicy::set_current(strict.clone());
+        assert_eq!(previous, AmountPolicy::new());
+        assert_eq!(AmountPolicy::reset_current(), strict);
+    }
+
+    #[test]
+    fn test_scope_restores_after_panic() {
+        let result = std::panic::catch_unwind(|| {
+            AmountPolicy::new()
+                .without_negatives()
+                .scope(|| panic!("inside scope"))
+        });
+        assert!(result.is_err());
+        assert!(AmountPolicy::current().allows_negative());
+    }
+}
@@ -1,7 +1,12 @@
 //! Precision control and detection for Amount.
 
+use super::rounding::round_dp;
 use super::type_def::Amount;
-use crate::{Currency, MoneyError, MoneyResult};
+use crate::{Currency, MoneyError, MoneyResult, RoundingMode};
+use std::marker::PhantomData;
+
+#[cfg(feature = "operation_tracking")]
+use crate::conversion_tracking::{Operation, OperationEvent};
 
 impl<C: Currency> Amount<C> {
     /// Checks if this amount has more decimal places than the currency supports.
@@ -35,12 +40,33 @@ impl<C: Currency> Amount<C> {
 
     #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
     pub fn has_excess_precision(&self) -> bool {
-        use bigdecimal::ToPrimitive;
-
         let (_, scale) = self.value.as_bigint_and_exponent();
         scale > i64::from(C::DECIMALS)
     }
 
+    /// Checks if this amount is exactly representable at the currency's precision.
+    ///
+    /// Unlike [`has_excess_precision`](Self::has_excess_precision), which looks at
+    /// the scale, this ignores trailing zeros: `$8.5000` is exact, `$8.5085` is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD};
+    ///
+    /// let split = Amount::<USD>::from_major(10) / 4; // $2.50
+    /// assert!(split.is_exact_at_currency_precision());
+    ///
+    /// let third = Amount::<USD>::from_major(10) / 3; // $3.333...
+    /// assert!(!third.is_exact_at_currency_precision());
+    ///
+    /// let scaled = Amount::<USD>::from_minor(850) * 10_000 / 10_000; // $8.5000
+    /// assert!(scaled.is_exact_at_currency_precision());
+    /// ```
+    pub fn is_exact_at_currency_precision(&self) -> bool {
+        round_dp(&self.value, u32::from(C::DECIMALS), RoundingMode::Down) == self.value
+    }
+
     /// Returns the number of decimal places in this amount.
     ///
     /// This can be more than the currency's `DECIMALS` if the amount
@@ -105,7 +131,24 @@ impl<C: Currency> Amount<C> {
     /// ```
     pub fn normalize(&self) -> Self {
         use crate::RoundingMode;
-        self.round(RoundingMode::HalfEven)
+
+        let normalized = Self {
+            value: round_dp(&self.value, u32::from(C::DECIMALS), RoundingMode::HalfEven),
+            _currency: PhantomData,
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(
+                Operation::Normalize,
+                C::CODE,
+                &self.value,
+                &normalized.value,
+            )
+            .with_rounding_mode(RoundingMode::HalfEven)
+        });
+
+        normalized
     }
 
     /// Checks if the amount has valid precision for the currency.
@@ -215,6 +258,23 @@ mod tests {
         assert!(divided.has_excess_precision());
     }
 
+    #[test]
+    fn test_is_exact_at_currency_precision() {
+        use rust_decimal::Decimal;
+
+        assert!(Amount::<USD>::from_minor(1234).is_exact_at_currency_precision());
+        assert!(!(Amount::<USD>::from_major(100) / 3).is_exact_at_currency_precision());
+        assert!(!(Amount::<JPY>::from_major(5) / 2).is_exact_at_currency_precision());
+
+        // Trailing zeros beyond the currency precision are still exact
+        let padded = Amount::<USD> {
+            value: Decimal::new(-85_000, 4), // -8.5000
+            _currency: PhantomData,
+        };
+        assert!(padded.has_excess_precision());
+        assert!(padded.is_exact_at_currency_precision());
+    }
+
     #[test]
     fn test_precision_method() {
         let usd = Amount::<USD>::from_minor(1234); // 12.34
@@ -1,9 +1,18 @@
 //! Rounding methods for Amount.
 
-use super::type_def::Amount;
-use crate::{Currency, RoundingMode};
+use super::type_def::{value_ref, Amount};
+use crate::{Currency, MoneyContext, MoneyError, MoneyResult, RoundingMode, Trap};
 use std::marker::PhantomData;
 
+#[cfg(feature = "operation_tracking")]
+use crate::conversion_tracking::{Operation, OperationEvent};
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
 impl<C: Currency> Amount<C> {
     /// Rounds the amount to the currency's decimal precision using the specified rounding mode.
     ///
@@ -35,61 +44,516 @@ impl<C: Currency> Amount<C> {
     ///
     /// See [`RoundingMode`] for detailed documentation on each mode.
     pub fn round(&self, mode: RoundingMode) -> Self {
-        let scale = u32::from(C::DECIMALS);
-
-        #[cfg(feature = "use_rust_decimal")]
-        let rounded_value = {
-            use rust_decimal::prelude::*;
-
-            match mode {
-                RoundingMode::HalfUp => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::MidpointAwayFromZero),
-                RoundingMode::HalfDown => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::MidpointTowardZero),
-                RoundingMode::HalfEven => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::MidpointNearestEven),
-                RoundingMode::Up => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::AwayFromZero),
-                RoundingMode::Down => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::ToZero),
-                RoundingMode::Floor => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::ToNegativeInfinity),
-                RoundingMode::Ceiling => self
-                    .value
-                    .round_dp_with_strategy(scale, RoundingStrategy::ToPositiveInfinity),
-            }
-        };
+        self.round_to_scale(u32::from(C::DECIMALS), mode)
+    }
+
+    /// Rounds the amount to `scale` decimal places using the specified rounding mode.
+    ///
+    /// Unlike [`round`](Self::round), the scale is independent of the currency's
+    /// `DECIMALS`, which is useful for sub-cent prices (fuel at $3.459) or usage
+    /// billing at 6 decimals. Amounts that already have `scale` or fewer decimal
+    /// places are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD, RoundingMode};
+    ///
+    /// let fuel = Amount::<USD>::from_minor(34_589) / 100; // $3.4589 per gallon
+    /// let rounded = fuel.round_to_scale(3, RoundingMode::HalfUp);
+    /// assert_eq!(rounded, Amount::<USD>::from_minor(3_459) / 10); // $3.459
+    /// assert_eq!(rounded.precision(), 3);
+    /// ```
+    pub fn round_to_scale(&self, scale: u32, mode: RoundingMode) -> Self {
+        self.rounded_to(round_dp(&self.value, scale, mode), mode)
+    }
+
+    /// Truncates the amount to `scale` decimal places, discarding the remaining digits.
+    ///
+    /// Equivalent to `round_to_scale(scale, RoundingMode::Down)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD};
+    ///
+    /// let amount = Amount::<USD>::from_minor(-34_589) / 100; // -$3.4589
+    /// assert_eq!(amount.truncate_to_scale(2).to_minor(), -345);
+    /// ```
+    pub fn truncate_to_scale(&self, scale: u32) -> Self {
+        self.round_to_scale(scale, RoundingMode::Down)
+    }
+
+    /// Rounds the amount to `digits` significant digits using the specified rounding mode.
+    ///
+    /// Intended for reporting, where magnitudes matter more than exact values.
+    /// Rounding may affect the integer part (12,345 → 12,000 at two digits).
+    /// A `digits` value of 0 is treated as 1, and zero amounts are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD, RoundingMode};
+    ///
+    /// let revenue = Amount::<USD>::from_major(1_234_567);
+    /// let rounded = revenue.round_to_significant(3, RoundingMode::HalfUp);
+    /// assert_eq!(rounded.to_major_floor(), 1_230_000);
+    ///
+    /// let fee = Amount::<USD>::from_minor(1_234_567) / 1_000_000; // $0.01234567
+    /// let rounded = fee.round_to_significant(2, RoundingMode::Ceiling);
+    /// assert_eq!(rounded, Amount::<USD>::from_minor(13) / 10); // $0.013
+    /// ```
+    pub fn round_to_significant(&self, digits: u32, mode: RoundingMode) -> Self {
+        self.rounded_to(round_significant(&self.value, digits.max(1), mode), mode)
+    }
 
-        #[cfg(feature = "use_bigdecimal")]
-        let rounded_value = {
-            use bigdecimal::RoundingMode as BigDecimalRoundingMode;
-
-            let bigdecimal_mode = match mode {
-                RoundingMode::HalfUp => BigDecimalRoundingMode::HalfUp,
-                RoundingMode::HalfDown => BigDecimalRoundingMode::HalfDown,
-                RoundingMode::HalfEven => BigDecimalRoundingMode::HalfEven,
-                RoundingMode::Up => BigDecimalRoundingMode::Up,
-                RoundingMode::Down => BigDecimalRoundingMode::Down,
-                RoundingMode::Floor => BigDecimalRoundingMode::Floor,
-                RoundingMode::Ceiling => BigDecimalRoundingMode::Ceiling,
-            };
-
-            self.value.with_scale_round(scale.into(), bigdecimal_mode)
+    /// Wraps a rounded value and reports the rounding step to the operation tracker.
+    #[cfg_attr(not(feature = "operation_tracking"), allow(unused_variables))]
+    fn rounded_to(&self, value: Decimal, mode: RoundingMode) -> Self {
+        let rounded = Self {
+            value,
+            _currency: PhantomData,
         };
 
-        Self {
-            value: rounded_value,
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(Operation::Round, C::CODE, &self.value, &rounded.value)
+                .with_rounding_mode(mode)
+        });
+
+        rounded
+    }
+
+    /// Rounds the amount to the nearest multiple of `increment` using the specified rounding mode.
+    ///
+    /// This is used for cash settlement where the smallest coin is larger than the
+    /// minor unit (Swiss 5 Rappen, Canadian nickel rounding, Swedish whole kronor),
+    /// and for pricing rules such as "round to the nearest 0.25".
+    ///
+    /// # Errors
+    ///
+    /// Returns `MoneyError::RoundingError` if `increment` is zero or negative, and
+    /// `MoneyError::Overflow` if the result cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, CHF, USD, RoundingMode};
+    ///
+    /// let total = Amount::<CHF>::from_minor(1_237); // CHF 12.37
+    /// let five_rappen = Amount::<CHF>::from_minor(5);
+    /// let cash = total.round_to_increment(&five_rappen, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(cash.to_minor(), 1_235);
+    ///
+    /// let price = Amount::<USD>::from_minor(1_013); // $10.13
+    /// let quarter = Amount::<USD>::from_minor(25);
+    /// let rounded = price.round_to_increment(&quarter, RoundingMode::Ceiling).unwrap();
+    /// assert_eq!(rounded.to_minor(), 1_025);
+    /// ```
+    pub fn round_to_increment(&self, increment: &Self, mode: RoundingMode) -> MoneyResult<Self> {
+        let value = round_to_multiple::<C>(&self.value, &increment.value, mode)?;
+        let rounded = Self {
+            value,
             _currency: PhantomData,
+        };
+
+        #[cfg(feature = "operation_tracking")]
+        crate::conversion_tracking::track_operation(|| {
+            OperationEvent::new(
+                Operation::RoundToIncrement,
+                C::CODE,
+                &self.value,
+                &rounded.value,
+            )
+            .with_operand(&increment.value)
+            .with_rounding_mode(mode)
+        });
+
+        Ok(rounded)
+    }
+
+    /// Rounds the amount to the currency's cash rounding increment.
+    ///
+    /// The increment comes from [`Currency::CASH_ROUNDING_INCREMENT`] and ties are
+    /// rounded away from zero, as is customary for cash settlement. Currencies
+    /// without cash rounding (increment of one minor unit) behave like
+    /// `round(RoundingMode::HalfUp)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, CAD, CHF, SEK, USD};
+    ///
+    /// // Swiss francs settle in 5 Rappen steps
+    /// assert_eq!(Amount::<CHF>::from_minor(1_237).round_cash().to_minor(), 1_235);
+    /// assert_eq!(Amount::<CHF>::from_minor(1_238).round_cash().to_minor(), 1_240);
+    ///
+    /// // Canadian nickel rounding
+    /// assert_eq!(Amount::<CAD>::from_minor(1_002).round_cash().to_minor(), 1_000);
+    /// assert_eq!(Amount::<CAD>::from_minor(1_003).round_cash().to_minor(), 1_005);
+    ///
+    /// // Swedish cash is settled in whole kronor
+    /// assert_eq!(Amount::<SEK>::from_minor(9_950).round_cash().to_minor(), 10_000);
+    ///
+    /// // USD has no cash rounding beyond the cent
+    /// assert_eq!(Amount::<USD>::from_minor(1_237).round_cash().to_minor(), 1_237);
+    /// ```
+    pub fn round_cash(&self) -> Self {
+        self.round_to_increment(&Self::cash_increment(), RoundingMode::HalfUp)
+            .unwrap_or_else(|_| self.round(RoundingMode::HalfUp))
+    }
+
+    /// Returns the currency's cash rounding increment as an amount.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, CHF, USD};
+    ///
+    /// assert_eq!(Amount::<CHF>::cash_increment().to_minor(), 5);
+    /// assert_eq!(Amount::<USD>::cash_increment().to_minor(), 1);
+    /// ```
+    pub fn cash_increment() -> Self {
+        Self::from_minor(i64::from(C::CASH_ROUNDING_INCREMENT.max(1)))
+    }
+
+    /// Rounds the amount using the current [`MoneyContext`].
+    ///
+    /// The precision is the context's override or `C::DECIMALS`, and the rounding
+    /// mode is the context's override or `C::DEFAULT_ROUNDING`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyContext, RoundingMode, EUR, USD};
+    ///
+    /// let usd = Amount::<USD>::from_minor(125) / 10; // $0.125
+    /// let eur = Amount::<EUR>::from_minor(125) / 10; // €0.125
+    ///
+    /// // USD defaults to banker's rounding, EUR to half-up
+    /// assert_eq!(usd.round_default().to_minor(), 12);
+    /// assert_eq!(eur.round_default().to_minor(), 13);
+    ///
+    /// let fee = Amount::<USD>::from_minor(12_345) / 1_000; // $0.12345
+    /// let context = MoneyContext::new()
+    ///     .with_precision(3)
+    ///     .with_rounding(RoundingMode::Ceiling);
+    /// let rounded = context.scope(|| fee.round_default());
+    /// assert_eq!(rounded, Amount::<USD>::from_minor(124) / 10); // $0.124
+    /// ```
+    pub fn round_default(&self) -> Self {
+        let context = MoneyContext::current();
+        self.round_to_scale(context.precision_for::<C>(), context.rounding_for::<C>())
+    }
+}
+
+/// Applies the current [`MoneyContext`] to the result of a checked division or
+/// conversion, reporting the first trapped condition as an error.
+pub(super) fn apply_context<C: Currency>(
+    operation: &str,
+    value: Decimal,
+    exact: bool,
+) -> MoneyResult<Decimal> {
+    match resolve_context::<C>(operation, value, exact) {
+        (value, None) => Ok(value),
+        (_, Some(error)) => Err(error),
+    }
+}
+
+/// Applies the current [`MoneyContext`] to the result of the `/` operator or
+/// `convert`, which flag conditions but never trap them.
+pub(super) fn round_to_context<C: Currency>(
+    operation: &str,
+    value: Decimal,
+    exact: bool,
+) -> Decimal {
+    resolve_context::<C>(operation, value, exact).0
+}
+
+/// Rounds `value` to the context precision, when one is set, and flags the
+/// `Inexact`, `ExcessPrecision` and `Overflow` conditions it raises.
+///
+/// `exact` tells whether the backend computed the value without rounding.
+/// Returns the rounded value and the error for the first raised condition the
+/// context traps.
+fn resolve_context<C: Currency>(
+    operation: &str,
+    value: Decimal,
+    exact: bool,
+) -> (Decimal, Option<MoneyError>) {
+    let context = MoneyContext::current();
+    let mut trapped = None;
+
+    if !exact && MoneyContext::signal(Trap::Inexact) {
+        trapped = Some(MoneyError::RoundingError {
+            currency: C::CODE,
+            reason: format!(
+                "Inexact result: {} was rounded at the decimal backend's precision limit",
+                value
+            ),
+        });
+    }
+
+    let value = match context.precision() {
+        Some(scale) => {
+            let rounded = round_dp(&value, scale, context.rounding_for::<C>());
+            if rounded != value && MoneyContext::signal(Trap::Inexact) {
+                trapped.get_or_insert_with(|| MoneyError::RoundingError {
+                    currency: C::CODE,
+                    reason: format!(
+                        "Inexact result: {} does not fit in {} decimal places",
+                        value, scale
+                    ),
+                });
+            }
+            rounded
         }
+        None => value,
+    };
+
+    let amount = Amount::<C> {
+        value,
+        _currency: PhantomData,
+    };
+    if !amount.is_exact_at_currency_precision() && MoneyContext::signal(Trap::ExcessPrecision) {
+        trapped = trapped.or_else(|| amount.check_precision().err());
+    }
+    if amount.checked_to_minor().is_none() && MoneyContext::signal(Trap::Overflow) {
+        trapped.get_or_insert_with(|| MoneyError::Overflow {
+            operation: operation.to_string(),
+            currency: C::CODE,
+        });
+    }
+    (amount.value, trapped)
+}
+
+/// Rounds `value` to the nearest multiple of a positive `increment`.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn round_to_multiple<C: Currency>(
+    value: &Decimal,
+    increment: &Decimal,
+    mode: RoundingMode,
+) -> MoneyResult<Decimal> {
+    if *increment <= Decimal::ZERO {
+        return Err(invalid_increment::<C>(increment));
+    }
+
+    value
+        .checked_div(*increment)
+        .map(|steps| round_dp(&steps, 0, mode))
+        .and_then(|steps| steps.checked_mul(*increment))
+        .ok_or(MoneyError::Overflow {
+            operation: "round_to_increment".to_string(),
+            currency: C::CODE,
+        })
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn round_to_multiple<C: Currency>(
+    value: &Decimal,
+    increment: &Decimal,
+    mode: RoundingMode,
+) -> MoneyResult<Decimal> {
+    use bigdecimal::Zero;
+
+    if *increment <= Decimal::zero() {
+        return Err(invalid_increment::<C>(increment));
+    }
+
+    let steps = round_dp(&(value / increment), 0, mode);
+    Ok(steps * increment)
+}
+
+fn invalid_increment<C: Currency>(increment: &Decimal) -> MoneyError {
+    MoneyError::RoundingError {
+        currency: C::CODE,
+        reason: format!("Rounding increment must be positive, got {}", increment),
     }
 }
 
+/// Rounds a decimal value to `digits` significant digits.
+///
+/// When the rounding position falls left of the decimal point, the value is
+/// shifted down by a power of ten, rounded to an integer and shifted back, so
+/// both backends share the same `round_dp` semantics.
+fn round_significant(value: &Decimal, digits: u32, mode: RoundingMode) -> Decimal {
+    let (digit_count, scale) = digits_and_scale(value);
+    if digit_count == 0 {
+        return round_dp(value, scale.max(0) as u32, mode);
+    }
+
+    let target_scale = scale + i64::from(digits) - digit_count;
+    if target_scale >= 0 {
+        round_dp(value, target_scale as u32, mode)
+    } else {
+        let factor = pow10((-target_scale) as u32);
+        let steps = round_dp(&(value_ref(value) / value_ref(&factor)), 0, mode);
+        value_ref(&steps) * value_ref(&factor)
+    }
+}
+
+/// Returns the number of digits in the unscaled value (0 for zero) and the scale.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn digits_and_scale(value: &Decimal) -> (i64, i64) {
+    let mantissa = value.mantissa().unsigned_abs();
+    let digits = mantissa
+        .checked_ilog10()
+        .map_or(0, |log| i64::from(log) + 1);
+    (digits, i64::from(value.scale()))
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn digits_and_scale(value: &Decimal) -> (i64, i64) {
+    use bigdecimal::Zero;
+
+    let (unscaled, scale) = value.as_bigint_and_exponent();
+    if unscaled.is_zero() {
+        return (0, scale);
+    }
+    let digits = unscaled.magnitude().to_string().len() as i64;
+    (digits, scale)
+}
+
+/// Returns 10 raised to `exponent`.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn pow10(exponent: u32) -> Decimal {
+    Decimal::from_i128_with_scale(10_i128.pow(exponent), 0)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn pow10(exponent: u32) -> Decimal {
+    use bigdecimal::num_bigint::BigInt;
+
+    Decimal::from(BigInt::from(10).pow(exponent))
+}
+
+/// Rounds a decimal value to `scale` decimal places using the given mode.
+///
+/// This is the single place where `RoundingMode` is interpreted. Modes the
+/// backends support natively are delegated to them; `HalfOdd`, `Round05Up` and
+/// `Stochastic` are derived from native directional roundings so that both
+/// backends produce identical results.
+pub(crate) fn round_dp(value: &Decimal, scale: u32, mode: RoundingMode) -> Decimal {
+    match mode {
+        RoundingMode::HalfUp | RoundingMode::HalfAwayFromZero => {
+            round_native(value, scale, Native::HalfAwayFromZero)
+        }
+        RoundingMode::HalfDown | RoundingMode::HalfTowardZero => {
+            round_native(value, scale, Native::HalfTowardZero)
+        }
+        RoundingMode::HalfEven => round_native(value, scale, Native::HalfEven),
+        RoundingMode::Up => round_native(value, scale, Native::AwayFromZero),
+        RoundingMode::Down => round_native(value, scale, Native::TowardZero),
+        RoundingMode::Floor => round_native(value, scale, Native::Floor),
+        RoundingMode::Ceiling => round_native(value, scale, Native::Ceiling),
+        RoundingMode::HalfOdd => {
+            let away = round_native(value, scale, Native::HalfAwayFromZero);
+            let toward = round_native(value, scale, Native::HalfTowardZero);
+            if away == toward {
+                return away;
+            }
+            // A tie: exactly one neighbour is even, pick the other one
+            let even = round_native(value, scale, Native::HalfEven);
+            if even == away {
+                toward
+            } else {
+                away
+            }
+        }
+        RoundingMode::Round05Up => {
+            let toward = round_native(value, scale, Native::TowardZero);
+            if &toward == value {
+                return toward;
+            }
+            let units = round_native(&(value_ref(&toward) * pow10(scale)), 0, Native::TowardZero);
+            let last_digit = value_ref(&units) % Decimal::from(10);
+            if last_digit == Decimal::from(0)
+                || last_digit == Decimal::from(5)
+                || last_digit == Decimal::from(-5)
+            {
+                round_native(value, scale, Native::AwayFromZero)
+            } else {
+                toward
+            }
+        }
+        RoundingMode::Stochastic { seed } => {
+            let toward = round_native(value, scale, Native::TowardZero);
+            if &toward == value {
+                return toward;
+            }
+            let units: i128 =
+                round_native(&(value_ref(&toward) * pow10(scale)), 0, Native::TowardZero)
+                    .to_string()
+                    .parse()
+                    .unwrap_or(0);
+            // Probability of rounding away from zero, in units of 2^-53
+            let fraction = (value_ref(value) - value_ref(&toward)).abs() * pow10(scale);
+            let threshold: u64 = round_native(
+                &(fraction * Decimal::from(1_u64 << 53)),
+                0,
+                Native::TowardZero,
+            )
+            .to_string()
+            .parse()
+            .unwrap_or(0);
+            if crate::rounding::stochastic_draw(seed, units, threshold) >> 11 < threshold {
+                round_native(value, scale, Native::AwayFromZero)
+            } else {
+                toward
+            }
+        }
+    }
+}
+
+/// A rounding the decimal backends implement directly.
+#[derive(Debug, Clone, Copy)]
+enum Native {
+    HalfAwayFromZero,
+    HalfTowardZero,
+    HalfEven,
+    AwayFromZero,
+    TowardZero,
+    Floor,
+    Ceiling,
+}
+
+/// Rounds using a strategy the backend implements directly.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn round_native(value: &Decimal, scale: u32, native: Native) -> Decimal {
+    use rust_decimal::RoundingStrategy;
+
+    let strategy = match native {
+        Native::HalfAwayFromZero => RoundingStrategy::MidpointAwayFromZero,
+        Native::HalfTowardZero => RoundingStrategy::MidpointTowardZero,
+        Native::HalfEven => RoundingStrategy::MidpointNearestEven,
+        Native::AwayFromZero => RoundingStrategy::AwayFromZero,
+        Native::TowardZero => RoundingStrategy::ToZero,
+        Native::Floor => RoundingStrategy::ToNegativeInfinity,
+        Native::Ceiling => RoundingStrategy::ToPositiveInfinity,
+    };
+
+    value.round_dp_with_strategy(scale, strategy)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn round_native(value: &Decimal, scale: u32, native: Native) -> Decimal {
+    use bigdecimal::RoundingMode as BigDecimalRoundingMode;
+
+    let bigdecimal_mode = match native {
+        Native::HalfAwayFromZero => BigDecimalRoundingMode::HalfUp,
+        Native::HalfTowardZero => BigDecimalRoundingMode::HalfDown,
+        Native::HalfEven => BigDecimalRoundingMode::HalfEven,
+        Native::AwayFromZero => BigDecimalRoundingMode::Up,
+        Native::TowardZero => BigDecimalRoundingMode::Down,
+        Native::Floor => BigDecimalRoundingMode::Floor,
+        Native::Ceiling => BigDecimalRoundingMode::Ceiling,
+    };
+
+    // Never extend the scale, matching rust_decimal's `round_dp_with_strategy`
+    let scale = i64::from(scale).min(value.fractional_digit_count());
+    value.with_scale_round(scale, bigdecimal_mode)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -502,4 +966,396 @@ mod tests {
             assert!((12..=13).contains(&major), "Mode {:?} gave {}", mode, major);
         }
     }
+
+    // ========================================================================
+    // Increment and Cash Rounding Tests
+    // ========================================================================
+
+    #[test]
+    fn test_round_to_increment_modes() {
+        use crate::CHF;
+        let step = Amount::<CHF>::from_minor(5);
+        let amount = Amount::<CHF>::from_minor(1_237); // 12.37
+
+        let cases = [
+            (RoundingMode::HalfUp, 1_235),
+            (RoundingMode::Up, 1_240),
+            (RoundingMode::Down, 1_235),
+            (RoundingMode::Floor, 1_235),
+            (RoundingMode::Ceiling, 1_240),
+        ];
+        for (mode, expected) in cases {
+            let rounded = amount.round_to_increment(&step, mode).unwrap();
+            assert_eq!(rounded.to_minor(), expected, "mode {:?}", mode);
+        }
+    }
+
+    #[test]
+    fn test_round_to_increment_ties() {
+        use crate::CHF;
+        let step = Amount::<CHF>::from_minor(5);
+        // 12.375 sits exactly between 12.35 and 12.40 (steps 247 and 248)
+        let amount = amount::<CHF>("12.375");
+
+        let half_up = amount
+            .round_to_increment(&step, RoundingMode::HalfUp)
+            .unwrap();
+        let half_down = amount
+            .round_to_increment(&step, RoundingMode::HalfDown)
+            .unwrap();
+        let half_even = amount
+            .round_to_increment(&step, RoundingMode::HalfEven)
+            .unwrap();
+        assert_eq!(half_up.to_minor(), 1_240);
+        assert_eq!(half_down.to_minor(), 1_235);
+        assert_eq!(half_even.to_minor(), 1_240);
+    }
+
+    #[test]
+    fn test_round_to_increment_negative_amount() {
+        let step = Amount::<USD>::from_minor(25);
+        let amount = Amount::<USD>::from_minor(-1_013);
+
+        let floor = amount
+            .round_to_increment(&step, RoundingMode::Floor)
+            .unwrap();
+        let ceiling = amount
+            .round_to_increment(&step, RoundingMode::Ceiling)
+            .unwrap();
+        let half_up = amount
+            .round_to_increment(&step, RoundingMode::HalfUp)
+            .unwrap();
+        assert_eq!(floor.to_minor(), -1_025);
+        assert_eq!(ceiling.to_minor(), -1_000);
+        assert_eq!(half_up.to_minor(), -1_025);
+    }
+
+    #[test]
+    fn test_round_to_increment_rejects_non_positive_increment() {
+        let amount = Amount::<USD>::from_minor(1_013);
+
+        for step in [Amount::<USD>::from_minor(0), Amount::<USD>::from_minor(-5)] {
+            let err = amount
+                .round_to_increment(&step, RoundingMode::HalfUp)
+                .unwrap_err();
+            assert!(matches!(
+                err,
+                MoneyError::RoundingError {
+                    currency: "USD",
+                    ..
+                }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_round_cash_builtin_increments() {
+        use crate::{CAD, CHF, HUF, NZD, SEK};
+
+        assert_eq!(
+            Amount::<CHF>::from_minor(1_237).round_cash().to_minor(),
+            1_235
+        );
+        assert_eq!(
+            Amount::<CHF>::from_minor(1_238).round_cash().to_minor(),
+            1_240
+        );
+        assert_eq!(
+            Amount::<CAD>::from_minor(-1_003).round_cash().to_minor(),
+            -1_005
+        );
+        assert_eq!(
+            Amount::<NZD>::from_minor(1_215).round_cash().to_minor(),
+            1_220
+        );
+        assert_eq!(
+            Amount::<SEK>::from_minor(1_249).round_cash().to_minor(),
+            1_200
+        );
+        assert_eq!(
+            Amount::<HUF>::from_minor(1_002).round_cash().to_minor(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_round_cash_defaults_to_minor_unit() {
+        let amount = usd("12.345");
+
+        assert_eq!(Amount::<USD>::cash_increment().to_minor(), 1);
+        assert_eq!(amount.round_cash(), amount.round(RoundingMode::HalfUp));
+        assert_eq!(
+            Amount::<JPY>::from_minor(1_237).round_cash().to_minor(),
+            1_237
+        );
+    }
+
+    // ========================================================================
+    // Scale and Significant Digit Tests
+    // ========================================================================
+
+    fn amount<C: Currency>(value: &str) -> Amount<C> {
+        Amount {
+            value: value.parse().unwrap(),
+            _currency: PhantomData,
+        }
+    }
+
+    fn usd(value: &str) -> Amount<USD> {
+        amount(value)
+    }
+
+    #[test]
+    fn test_round_to_scale_sub_cent() {
+        let fuel = usd("3.4589");
+        assert_eq!(fuel.round_to_scale(3, RoundingMode::HalfUp), usd("3.459"));
+        assert_eq!(fuel.round_to_scale(3, RoundingMode::Floor), usd("3.458"));
+        assert_eq!(fuel.round_to_scale(3, RoundingMode::HalfUp).precision(), 3);
+    }
+
+    #[test]
+    fn test_round_to_scale_all_modes_on_tie() {
+        let amount = usd("-1.234565");
+        let cases = [
+            (RoundingMode::HalfUp, "-1.23457"),
+            (RoundingMode::HalfDown, "-1.23456"),
+            (RoundingMode::HalfEven, "-1.23456"),
+            (RoundingMode::Up, "-1.23457"),
+            (RoundingMode::Down, "-1.23456"),
+            (RoundingMode::Floor, "-1.23457"),
+            (RoundingMode::Ceiling, "-1.23456"),
+        ];
+        for (mode, expected) in cases {
+            assert_eq!(
+                amount.round_to_scale(5, mode),
+                usd(expected),
+                "mode {:?}",
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_to_scale_beyond_current_scale_is_unchanged() {
+        let amount = usd("12.34");
+        assert_eq!(amount.round_to_scale(6, RoundingMode::Up), amount);
+        assert_eq!(amount.round_to_scale(6, RoundingMode::Up).precision(), 2);
+    }
+
+    #[test]
+    fn test_truncate_to_scale() {
+        assert_eq!(usd("3.4589").truncate_to_scale(2), usd("3.45"));
+        assert_eq!(usd("-3.4589").truncate_to_scale(2), usd("-3.45"));
+        assert_eq!(usd("3.4589").truncate_to_scale(0), usd("3"));
+    }
+
+    #[test]
+    fn test_round_to_significant_integer_part() {
+        let amount = usd("1234567");
+        assert_eq!(
+            amount.round_to_significant(3, RoundingMode::HalfUp),
+            usd("1230000")
+        );
+        assert_eq!(
+            amount.round_to_significant(1, RoundingMode::Ceiling),
+            usd("2000000")
+        );
+        assert_eq!(
+            usd("-1250").round_to_significant(2, RoundingMode::HalfEven),
+            usd("-1200")
+        );
+    }
+
+    #[test]
+    fn test_round_to_significant_fractional() {
+        let amount = usd("0.01234567");
+        assert_eq!(
+            amount.round_to_significant(2, RoundingMode::HalfUp),
+            usd("0.012")
+        );
+        assert_eq!(
+            amount.round_to_significant(4, RoundingMode::Down),
+            usd("0.01234")
+        );
+    }
+
+    #[test]
+    fn test_round_to_significant_ignores_trailing_zeros() {
+        let amount = usd("1.23000");
+        assert_eq!(
+            amount.round_to_significant(2, RoundingMode::HalfUp),
+            usd("1.2")
+        );
+    }
+
+    #[test]
+    fn test_round_to_significant_edge_cases() {
+        assert_eq!(
+            usd("0.00").round_to_significant(3, RoundingMode::HalfUp),
+            usd("0.00")
+        );
+        // Zero digits is treated as one
+        assert_eq!(
+            usd("45.67").round_to_significant(0, RoundingMode::HalfUp),
+            usd("50")
+        );
+        // More digits than present leaves the amount unchanged
+        assert_eq!(
+            usd("45.67").round_to_significant(10, RoundingMode::HalfUp),
+            usd("45.67")
+        );
+    }
+
+    // ========================================================================
+    // Additional Mode Tests
+    // ========================================================================
+
+    #[test]
+    fn test_half_odd() {
+        let cases = [
+            ("12.345", "12.35"), // tie, odd
+            ("12.355", "12.35"), // tie, odd
+            ("12.356", "12.36"), // not a tie
+            ("-12.345", "-12.35"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                usd(value).round(RoundingMode::HalfOdd),
+                usd(expected),
+                "value {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_explicit_half_modes_match_aliases() {
+        for value in ["12.345", "-12.345", "12.346", "-12.344"] {
+            let amount = usd(value);
+            assert_eq!(
+                amount.round(RoundingMode::HalfAwayFromZero),
+                amount.round(RoundingMode::HalfUp)
+            );
+            assert_eq!(
+                amount.round(RoundingMode::HalfTowardZero),
+                amount.round(RoundingMode::HalfDown)
+            );
+        }
+        assert_eq!(
+            usd("-12.345").round(RoundingMode::HalfAwayFromZero),
+            usd("-12.35")
+        );
+        assert_eq!(
+            usd("-12.345").round(RoundingMode::HalfTowardZero),
+            usd("-12.34")
+        );
+    }
+
+    #[test]
+    fn test_round_05_up() {
+        let cases = [
+            ("12.341", "12.34"), // last kept digit 4: truncate
+            ("12.301", "12.31"), // last kept digit 0: away from zero
+            ("12.351", "12.36"), // last kept digit 5: away from zero
+            ("12.350", "12.35"), // exact: unchanged
+            ("12.309", "12.31"),
+            ("-12.351", "-12.36"),
+            ("-12.341", "-12.34"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(
+                usd(value).round(RoundingMode::Round05Up),
+                usd(expected),
+                "value {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_stochastic_picks_a_neighbour_and_keeps_exact_values() {
+        for seed in 0..100 {
+            let mode = RoundingMode::Stochastic { seed };
+            let cents = usd("12.343").round(mode).to_minor();
+            assert!(cents == 1_234 || cents == 1_235);
+            let negative = usd("-12.343").round(mode).to_minor();
+            assert!(negative == -1_234 || negative == -1_235);
+            assert_eq!(usd("12.340").round(mode), usd("12.34"));
+        }
+    }
+
+    #[test]
+    fn test_stochastic_is_unbiased_on_aggregate() {
+        let fee = usd("0.003"); // 30% of a cent
+
+        let total: i64 = (0..10_000)
+            .map(|seed| fee.round(RoundingMode::Stochastic { seed }).to_minor())
+            .sum();
+        // Expected 3,000 cents; allow a wide margin for randomness
+        assert!((2_800..=3_200).contains(&total), "total {}", total);
+    }
+
+    #[test]
+    fn test_stochastic_depends_only_on_seed_and_value() {
+        let mode = RoundingMode::Stochastic { seed: 5 };
+        let fee = usd("0.005");
+
+        let first = fee.round(mode);
+        for value in ["0.001", "0.002", "0.009", "1.005"] {
+            let _ = usd(value).round(mode);
+        }
+        assert_eq!(fee.round(mode), first);
+        assert_eq!(usd("0.00500").round(mode), first);
+        let other_thread = std::thread::spawn(move || fee.round(mode)).join().unwrap();
+        assert_eq!(other_thread, first);
+    }
+
+    #[test]
+    fn test_new_modes_with_increment_and_scale() {
+        let step = Amount::<USD>::from_minor(5);
+        assert_eq!(
+            usd("12.25")
+                .round_to_increment(&step, RoundingMode::HalfOdd)
+                .unwrap(),
+            usd("12.25")
+        );
+        assert_eq!(
+            usd("12.251").round_to_scale(2, RoundingMode::Round05Up),
+            usd("12.26")
+        );
+    }
+
+    // ========================================================================
+    // Default Rounding Tests
+    // ========================================================================
+
+    #[test]
+    fn test_round_default_uses_currency_default() {
+        use crate::{CHF, EUR};
+
+        let eur = amount::<EUR>("0.125");
+        let chf = amount::<CHF>("0.125");
+
+        assert_eq!(usd("0.125").round_default(), usd("0.12"));
+        assert_eq!(eur.round_default().to_minor(), 13);
+        assert_eq!(chf.round_default().to_minor(), 13);
+    }
+
+    #[test]
+    fn test_round_default_uses_context_overrides() {
+        use crate::MoneyContext;
+
+        let amount = usd("1.23456");
+        let context = MoneyContext::new()
+            .with_precision(3)
+            .with_rounding(RoundingMode::Down);
+
+        assert_eq!(context.scope(|| amount.round_default()), usd("1.234"));
+        assert_eq!(
+            MoneyContext::new()
+                .with_rounding(RoundingMode::Up)
+                .scope(|| amount.round_default()),
+            usd("1.24")
+        );
+    }
 }
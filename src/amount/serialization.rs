@@ -7,7 +7,7 @@
 use super::type_def::Amount;
 
 #[cfg(feature = "serde_support")]
-use crate::Currency;
+use crate::{AmountPolicy, Currency};
 
 #[cfg(feature = "serde_support")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -65,13 +65,26 @@ impl<'de, C: Currency> Deserialize<'de> for Amount<C> {
             )));
         }
 
-        // Parse the decimal value
-        let decimal_value = Decimal::from_str(&amount_serde.value)
-            .map_err(|_| serde::de::Error::custom("Invalid decimal value"))?;
-
-        Ok(Self {
-            value: decimal_value,
-            _currency: PhantomData,
+        AmountPolicy::with_current(|policy| {
+            if amount_serde.value.len() > policy.max_input_len() {
+                return Err(serde::de::Error::custom(format!(
+                    "max_input_len: Value too long (max {} characters)",
+                    policy.max_input_len()
+                )));
+            }
+
+            // Parse the decimal value
+            let decimal_value = Decimal::from_str(&amount_serde.value)
+                .map_err(|_| serde::de::Error::custom("Invalid decimal value"))?;
+
+            let amount = Self {
+                value: decimal_value,
+                _currency: PhantomData,
+            };
+            policy
+                .check_value(&amount_serde.value, &amount)
+                .map_err(serde::de::Error::custom)?;
+            Ok(amount)
         })
     }
 }
@@ -136,6 +149,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_deserialize_applies_policy() {
+        let policy = AmountPolicy::new()
+            .with_currency_scale()
+            .without_negatives()
+            .with_max_input_len(8);
+        policy.scope(|| {
+            let parse = |value: &str| {
+                let json = format!(r#"{{"value":"{}","currency":"USD"}}"#, value);
+                serde_json::from_str::<Amount<USD>>(&json).map_err(|e| e.to_string())
+            };
+            assert!(parse("12.50").is_ok());
+            assert!(parse("12.505")
+                .unwrap_err()
+                .contains("expected 2 decimal places, found 3"));
+            assert!(parse("-12.50").unwrap_err().contains("allow_negative"));
+            assert!(parse("123456.78").unwrap_err().contains("max_input_len"));
+        });
+    }
+
     #[test]
     fn test_deserialize_invalid_value() {
         let json = r#"{"value":"not a number","currency":"USD"}"#;
@@ -63,6 +63,33 @@ pub struct Amount<C: Currency> {
     pub(super) _currency: PhantomData<C>,
 }
 
+/// Returns an operand for arithmetic that leaves `value` usable: a copy on
+/// rust_decimal, where `Decimal` is `Copy`, and the borrow on bigdecimal.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+#[inline]
+pub(crate) fn value_ref(value: &Decimal) -> Decimal {
+    *value
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+#[inline]
+pub(crate) fn value_ref(value: &Decimal) -> &Decimal {
+    value
+}
+
+/// Returns an owned copy of `value`, cloning only on bigdecimal.
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+#[inline]
+pub(crate) fn cloned_value(value: &Decimal) -> Decimal {
+    *value
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+#[inline]
+pub(crate) fn cloned_value(value: &Decimal) -> Decimal {
+    value.clone()
+}
+
 impl<C: Currency> Amount<C> {
     /// Returns the raw `Decimal` value.
     ///
@@ -0,0 +1,391 @@
+//! Thread-local monetary context.
+//!
+//! A [`MoneyContext`] holds settings that would otherwise be repeated at every
+//! call site: a precision override, a rounding mode override, and traps that turn
+//! silent precision loss into errors. It is modelled on the context of Python's
+//! `decimal` module and is scoped to the current thread.
+//!
+//! Like Python's context, it also records flags: every condition raised by an
+//! operation is flagged on the current context, whether or not it is trapped, and
+//! stays flagged until [`MoneyContext::clear_flags`] is called.
+//!
+//! # What Consults the Context
+//!
+//! - [`Amount::round_default`](crate::Amount::round_default) rounds to the context
+//!   precision (or `C::DECIMALS`) with the context rounding mode (or
+//!   `C::DEFAULT_ROUNDING`).
+//! - Division and conversion round their result to the context precision, when one
+//!   is set, and flag `Inexact` and `ExcessPrecision`.
+//!   [`checked_div`](crate::Amount::checked_div) and
+//!   [`try_convert`](crate::Amount::try_convert) report trapped conditions as
+//!   errors; the `/` operator and `convert` never trap.
+//! - Division and conversion raise `Inexact` when the backend had to round the
+//!   result, e.g. `10 / 3` truncated at 28 digits on `rust_decimal`.
+//! - Division and conversion raise `Overflow` when the result does not fit in
+//!   `i64` minor units, and conversion also when it exceeds the backend's range.
+//!   A trapped overflow is reported as
+//!   [`MoneyError::Overflow`](crate::MoneyError::Overflow). The backend has no
+//!   result to fall back on, so `try_convert` reports that case even untrapped,
+//!   and `convert` panics on it as the multiplication operators do.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::{Amount, MoneyContext, RoundingMode, Trap, USD};
+//!
+//! let billing = MoneyContext::new()
+//!     .with_precision(4)
+//!     .with_rounding(RoundingMode::HalfUp);
+//!
+//! let per_call = billing.scope(|| Amount::<USD>::from_major(1) / 3);
+//! assert_eq!(per_call, Amount::<USD>::from_minor(3_333) / 100); // $0.3333
+//!
+//! // Outside the scope the default context applies again
+//! let strict = MoneyContext::new().with_trap(Trap::ExcessPrecision);
+//! let result = strict.scope(|| Amount::<USD>::from_major(1).checked_div(3));
+//! assert!(result.is_err());
+//! ```
+
+use crate::{Currency, RoundingMode};
+use std::cell::Cell;
+
+/// Conditions that a [`MoneyContext`] can flag or turn into errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trap {
+    /// A result had to be rounded, by the backend or to the context precision,
+    /// losing information
+    Inexact,
+    /// A result has more decimal places than the currency supports
+    ExcessPrecision,
+    /// A result does not fit in `i64` minor units or in the backend's range
+    Overflow,
+}
+
+/// Precision, rounding and trap settings for monetary operations on the current thread.
+///
+/// The default context has no precision or rounding override and traps
+/// nothing, which matches the behaviour of the crate without a context.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{Amount, Currency, MoneyContext, RoundingMode, EUR, USD};
+///
+/// let context = MoneyContext::new().with_rounding(RoundingMode::Floor);
+/// assert_eq!(context.rounding_for::<USD>(), RoundingMode::Floor);
+///
+/// // Without an override, each currency's default rounding applies
+/// assert_eq!(MoneyContext::new().rounding_for::<EUR>(), EUR::DEFAULT_ROUNDING);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoneyContext {
+    precision: Option<u32>,
+    rounding: Option<RoundingMode>,
+    trap_inexact: bool,
+    trap_excess_precision: bool,
+    trap_overflow: bool,
+    flag_inexact: bool,
+    flag_excess_precision: bool,
+    flag_overflow: bool,
+}
+
+thread_local! {
+    static CURRENT_CONTEXT: Cell<MoneyContext> = const { Cell::new(MoneyContext::new()) };
+}
+
+impl MoneyContext {
+    /// Creates the default context: no overrides and no traps.
+    pub const fn new() -> Self {
+        Self {
+            precision: None,
+            rounding: None,
+            trap_inexact: false,
+            trap_excess_precision: false,
+            trap_overflow: false,
+            flag_inexact: false,
+            flag_excess_precision: false,
+            flag_overflow: false,
+        }
+    }
+
+    /// Returns the context with results of division and conversion rounded to
+    /// `scale` decimal places.
+    pub const fn with_precision(mut self, scale: u32) -> Self {
+        self.precision = Some(scale);
+        self
+    }
+
+    /// Returns the context with `mode` used instead of each currency's
+    /// `DEFAULT_ROUNDING`.
+    pub const fn with_rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = Some(mode);
+        self
+    }
+
+    /// Returns the context with `trap` enabled.
+    pub const fn with_trap(self, trap: Trap) -> Self {
+        self.set_trap(trap, true)
+    }
+
+    /// Returns the context with `trap` disabled.
+    pub const fn without_trap(self, trap: Trap) -> Self {
+        self.set_trap(trap, false)
+    }
+
+    const fn set_trap(mut self, trap: Trap, enabled: bool) -> Self {
+        match trap {
+            Trap::Inexact => self.trap_inexact = enabled,
+            Trap::ExcessPrecision => self.trap_excess_precision = enabled,
+            Trap::Overflow => self.trap_overflow = enabled,
+        }
+        self
+    }
+
+    /// Returns the precision override, if any.
+    pub const fn precision(&self) -> Option<u32> {
+        self.precision
+    }
+
+    /// Returns the rounding mode override, if any.
+    pub const fn rounding(&self) -> Option<RoundingMode> {
+        self.rounding
+    }
+
+    /// Returns whether `trap` is enabled.
+    pub const fn is_trapped(&self, trap: Trap) -> bool {
+        match trap {
+            Trap::Inexact => self.trap_inexact,
+            Trap::ExcessPrecision => self.trap_excess_precision,
+            Trap::Overflow => self.trap_overflow,
+        }
+    }
+
+    /// Returns whether `condition` has been raised since the flags were last cleared.
+    pub const fn is_flagged(&self, condition: Trap) -> bool {
+        match condition {
+            Trap::Inexact => self.flag_inexact,
+            Trap::ExcessPrecision => self.flag_excess_precision,
+            Trap::Overflow => self.flag_overflow,
+        }
+    }
+
+    /// Clears all flags of the current thread's context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyContext, Trap, USD};
+    ///
+    /// MoneyContext::clear_flags();
+    /// let _ = Amount::<USD>::from_major(10) / 4;
+    /// assert!(!MoneyContext::current().is_flagged(Trap::Inexact));
+    ///
+    /// let _ = Amount::<USD>::from_major(10) / 3; // truncated by the backend
+    /// assert!(MoneyContext::current().is_flagged(Trap::Inexact));
+    ///
+    /// MoneyContext::clear_flags();
+    /// assert!(!MoneyContext::current().is_flagged(Trap::Inexact));
+    /// ```
+    pub fn clear_flags() {
+        CURRENT_CONTEXT.with(|current| {
+            let mut context = current.get();
+            context.flag_inexact = false;
+            context.flag_excess_precision = false;
+            context.flag_overflow = false;
+            current.set(context);
+        });
+    }
+
+    /// Flags `condition` on the current thread's context and returns whether it
+    /// is trapped.
+    pub(crate) fn signal(condition: Trap) -> bool {
+        CURRENT_CONTEXT.with(|current| {
+            let mut context = current.get();
+            match condition {
+                Trap::Inexact => context.flag_inexact = true,
+                Trap::ExcessPrecision => context.flag_excess_precision = true,
+                Trap::Overflow => context.flag_overflow = true,
+            }
+            current.set(context);
+            context.is_trapped(condition)
+        })
+    }
+
+    /// Returns the precision to use for currency `C`: the override or `C::DECIMALS`.
+    pub fn precision_for<C: Currency>(&self) -> u32 {
+        self.precision.unwrap_or(u32::from(C::DECIMALS))
+    }
+
+    /// Returns the rounding mode to use for currency `C`: the override or
+    /// `C::DEFAULT_ROUNDING`.
+    pub fn rounding_for<C: Currency>(&self) -> RoundingMode {
+        self.rounding.unwrap_or(C::DEFAULT_ROUNDING)
+    }
+
+    /// Returns the context of the current thread.
+    pub fn current() -> Self {
+        CURRENT_CONTEXT.with(Cell::get)
+    }
+
+    /// Replaces the context of the current thread, returning the previous one.
+    ///
+    /// Flags are part of the context, so they are replaced as well.
+    ///
+    /// Prefer [`scope`](Self::scope) for temporary overrides; it restores the
+    /// previous context even if the closure panics.
+    pub fn set_current(context: Self) -> Self {
+        CURRENT_CONTEXT.with(|current| current.replace(context))
+    }
+
+    /// Restores the default context on the current thread, returning the previous one.
+    pub fn reset_current() -> Self {
+        Self::set_current(Self::new())
+    }
+
+    /// Runs `f` with this context installed on the current thread.
+    ///
+    /// The previous context, including its flags, is restored when `f` returns or
+    /// panics. Inspect flags raised inside the scope from within `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{MoneyContext, RoundingMode};
+    ///
+    /// let context = MoneyContext::new().with_rounding(RoundingMode::Ceiling);
+    /// context.scope(|| {
+    ///     assert_eq!(MoneyContext::current().rounding(), Some(RoundingMode::Ceiling));
+    /// });
+    /// assert_eq!(MoneyContext::current().rounding(), None);
+    /// ```
+    pub fn scope<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(MoneyContext);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                MoneyContext::set_current(self.0);
+            }
+        }
+
+        let _restore = Restore(Self::set_current(self));
+        f()
+    }
+}
+
+impl Default for MoneyContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CHF, EUR, JPY, USD};
+
+    #[test]
+    fn test_default_context() {
+        let context = MoneyContext::default();
+        assert_eq!(context, MoneyContext::new());
+        assert_eq!(context.precision(), None);
+        assert_eq!(context.rounding(), None);
+        assert!(!context.is_trapped(Trap::Inexact));
+        assert!(!context.is_trapped(Trap::ExcessPrecision));
+        assert!(!context.is_trapped(Trap::Overflow));
+    }
+
+    #[test]
+    fn test_builders() {
+        let context = MoneyContext::new()
+            .with_precision(6)
+            .with_rounding(RoundingMode::Floor)
+            .with_trap(Trap::Inexact)
+            .with_trap(Trap::ExcessPrecision)
+            .without_trap(Trap::ExcessPrecision);
+
+        assert_eq!(context.precision(), Some(6));
+        assert_eq!(context.rounding(), Some(RoundingMode::Floor));
+        assert!(context.is_trapped(Trap::Inexact));
+        assert!(!context.is_trapped(Trap::ExcessPrecision));
+    }
+
+    #[test]
+    fn test_currency_fallbacks() {
+        let context = MoneyContext::new();
+        assert_eq!(context.precision_for::<USD>(), 2);
+        assert_eq!(context.precision_for::<JPY>(), 0);
+        assert_eq!(context.rounding_for::<USD>(), RoundingMode::HalfEven);
+        assert_eq!(context.rounding_for::<EUR>(), RoundingMode::HalfUp);
+        assert_eq!(context.rounding_for::<CHF>(), RoundingMode::HalfUp);
+
+        let context = context.with_precision(4).with_rounding(RoundingMode::Down);
+        assert_eq!(context.precision_for::<JPY>(), 4);
+        assert_eq!(context.rounding_for::<EUR>(), RoundingMode::Down);
+    }
+
+    #[test]
+    fn test_set_and_reset_current() {
+        let custom = MoneyContext::new().with_precision(3);
+        let previous = MoneyContext::set_current(custom);
+        assert_eq!(previous, MoneyContext::new());
+        assert_eq!(MoneyContext::current(), custom);
+
+        assert_eq!(MoneyContext::reset_current(), custom);
+        assert_eq!(MoneyContext::current(), MoneyContext::new());
+    }
+
+    #[test]
+    fn test_scope_nests_and_restores() {
+        let outer = MoneyContext::new().with_precision(4);
+        let inner = MoneyContext::new().with_precision(6);
+
+        outer.scope(|| {
+            inner.scope(|| assert_eq!(MoneyContext::current().precision(), Some(6)));
+            assert_eq!(MoneyContext::current().precision(), Some(4));
+        });
+        assert_eq!(MoneyContext::current(), MoneyContext::new());
+    }
+
+    #[test]
+    fn test_scope_restores_after_panic() {
+        let context = MoneyContext::new().with_precision(4);
+        let result = std::panic::catch_unwind(|| context.scope(|| panic!("boom")));
+
+        assert!(result.is_err());
+        assert_eq!(MoneyContext::current(), MoneyContext::new());
+    }
+
+    #[test]
+    fn test_context_is_thread_local() {
+        MoneyContext::new().with_precision(5).scope(|| {
+            let other = std::thread::spawn(MoneyContext::current).join().unwrap();
+            assert_eq!(other, MoneyContext::new());
+        });
+    }
+
+    #[test]
+    fn test_signal_sets_flag_and_reports_trap() {
+        MoneyContext::new().scope(|| {
+            assert!(!MoneyContext::current().is_flagged(Trap::ExcessPrecision));
+            assert!(!MoneyContext::signal(Trap::ExcessPrecision));
+
+            let current = MoneyContext::current();
+            assert!(current.is_flagged(Trap::ExcessPrecision));
+            assert!(!current.is_flagged(Trap::Inexact));
+
+            MoneyContext::new()
+                .with_trap(Trap::Inexact)
+                .scope(|| assert!(MoneyContext::signal(Trap::Inexact)));
+
+            MoneyContext::clear_flags();
+            assert!(!MoneyContext::current().is_flagged(Trap::ExcessPrecision));
+        });
+    }
+
+    #[test]
+    fn test_scope_discards_inner_flags() {
+        MoneyContext::clear_flags();
+        MoneyContext::new().scope(|| MoneyContext::signal(Trap::Inexact));
+        assert!(!MoneyContext::current().is_flagged(Trap::Inexact));
+    }
+}
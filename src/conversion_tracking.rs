@@ -2,9 +2,73 @@
 //!
 //! This module provides optional tracking capabilities for monitoring and logging
 //! currency conversions when the `conversion_tracking` feature is enabled.
+//!
+//! There are two tracker traits:
+//!
+//! - [`ConversionTracker`] receives fully typed [`ConversionEvent`]s and is used
+//!   with [`Amount::convert_with_tracking`](crate::Amount::convert_with_tracking).
+//! - [`DynConversionTracker`] is object-safe and receives a type-erased
+//!   [`DynConversionEvent`]. It can be boxed, stored in application state and
+//!   swapped at runtime. Closures taking `&DynConversionEvent` implement it
+//!   automatically, and every `DynConversionTracker` is also a `ConversionTracker`.
+//!
+//! A process-wide default tracker can be installed with [`set_default_tracker`].
+//! Plain [`Amount::convert`](crate::Amount::convert) reports every conversion to it.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::conversion_tracking::{self, DynConversionEvent};
+//! use typed_money::{Amount, Rate, USD, EUR};
+//!
+//! conversion_tracking::set_default_tracker(|event: &DynConversionEvent| {
+//!     println!("{} -> {}", event.from_currency_code, event.to_currency_code);
+//! });
+//!
+//! let eur = Amount::<USD>::from_major(100).convert(&Rate::<USD, EUR>::new(0.85));
+//! assert_eq!(eur.to_major_floor(), 85);
+//!
+//! conversion_tracking::clear_default_tracker();
+//! ```
+//!
+//! # Operation Tracing
+//!
+//! With the `operation_tracking` feature, the same subsystem also records
+//! arithmetic, rounding, normalization and conversion steps as
+//! [`OperationEvent`]s. This produces a full calculation trail for auditing, not
+//! just the FX conversions. When the feature is disabled the hooks are compiled
+//! out entirely.
+//!
+//! ```
+//! # #[cfg(feature = "operation_tracking")]
+//! # {
+//! use typed_money::conversion_tracking::OperationCollector;
+//! use typed_money::{Amount, RoundingMode, USD};
+//!
+//! let (total, trail) = OperationCollector::capture(|| {
+//!     let subtotal = Amount::<USD>::from_minor(1999) * 3;
+//!     (subtotal / 7).round(RoundingMode::HalfEven)
+//! });
+//!
+//! assert_eq!(total.to_minor(), 857);
+//! assert_eq!(trail.len(), 3);
+//! println!("{}", trail);
+//! # }
+//! ```
 
-use crate::Currency;
+use crate::amount::cloned_value;
+use crate::{Amount, Currency, Rate};
 use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+
+#[cfg(feature = "operation_tracking")]
+use crate::RoundingMode;
+#[cfg(feature = "operation_tracking")]
+use std::cell::RefCell;
+#[cfg(feature = "operation_tracking")]
+use std::fmt;
+#[cfg(feature = "operation_tracking")]
+use std::rc::Rc;
 
 #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
 use rust_decimal::Decimal;
@@ -62,6 +126,149 @@ impl<From: Currency, To: Currency> ConversionEvent<From, To> {
             _phantom: PhantomData,
         }
     }
+
+    /// Returns a type-erased copy of this event.
+    ///
+    /// The currency types are only kept as their codes, so the result can be
+    /// passed to a [`DynConversionTracker`].
+    pub fn erase(&self) -> DynConversionEvent {
+        DynConversionEvent {
+            from_amount: cloned_value(&self.from_amount),
+            to_amount: cloned_value(&self.to_amount),
+            rate: cloned_value(&self.rate),
+            timestamp_unix_secs: self.timestamp_unix_secs,
+            rate_source: self.rate_source,
+            from_currency_code: self.from_currency_code,
+            to_currency_code: self.to_currency_code,
+        }
+    }
+}
+
+/// A type-erased record of a currency conversion event.
+///
+/// This carries the same information as [`ConversionEvent`], but identifies the
+/// currencies by their codes instead of type parameters. It is the event type
+/// received by [`DynConversionTracker`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynConversionEvent {
+    /// The source amount value
+    pub from_amount: Decimal,
+    /// The target amount value after conversion
+    pub to_amount: Decimal,
+    /// The exchange rate used
+    pub rate: Decimal,
+    /// Optional timestamp when the conversion occurred (UNIX seconds)
+    pub timestamp_unix_secs: Option<u64>,
+    /// Optional source of the exchange rate
+    pub rate_source: Option<&'static str>,
+    /// Source currency code
+    pub from_currency_code: &'static str,
+    /// Target currency code
+    pub to_currency_code: &'static str,
+}
+
+impl<From: Currency, To: Currency> std::convert::From<&ConversionEvent<From, To>>
+    for DynConversionEvent
+{
+    fn from(event: &ConversionEvent<From, To>) -> Self {
+        event.erase()
+    }
+}
+
+/// Serialized form of a conversion event, with amounts and rate as strings.
+#[cfg(feature = "serde_support")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ConversionEventSerde {
+    from_amount: String,
+    to_amount: String,
+    rate: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp_unix_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rate_source: Option<String>,
+    from_currency_code: String,
+    to_currency_code: String,
+}
+
+#[cfg(feature = "serde_support")]
+impl ConversionEventSerde {
+    fn of(event: &DynConversionEvent) -> Self {
+        Self {
+            from_amount: event.from_amount.to_string(),
+            to_amount: event.to_amount.to_string(),
+            rate: event.rate.to_string(),
+            timestamp_unix_secs: event.timestamp_unix_secs,
+            rate_source: event.rate_source.map(str::to_string),
+            from_currency_code: event.from_currency_code.to_string(),
+            to_currency_code: event.to_currency_code.to_string(),
+        }
+    }
+
+    fn into_event<E: serde::de::Error>(self) -> Result<DynConversionEvent, E> {
+        use std::str::FromStr;
+
+        let decimal = |text: &str| {
+            Decimal::from_str(text)
+                .map_err(|_| E::custom(format!("Invalid decimal value: '{}'", text)))
+        };
+        Ok(DynConversionEvent {
+            from_amount: decimal(&self.from_amount)?,
+            to_amount: decimal(&self.to_amount)?,
+            rate: decimal(&self.rate)?,
+            timestamp_unix_secs: self.timestamp_unix_secs,
+            rate_source: self.rate_source.as_deref().map(crate::serde::rate_source),
+            from_currency_code: crate::serde::currency_code(&self.from_currency_code)?,
+            to_currency_code: crate::serde::currency_code(&self.to_currency_code)?,
+        })
+    }
+}
+
+#[cfg(feature = "serde_support")]
+impl serde::Serialize for DynConversionEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&ConversionEventSerde::of(self), serializer)
+    }
+}
+
+#[cfg(feature = "serde_support")]
+impl<'de> serde::Deserialize<'de> for DynConversionEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <ConversionEventSerde as serde::Deserialize>::deserialize(deserializer)?.into_event()
+    }
+}
+
+/// Serializes the same way as [`DynConversionEvent`].
+#[cfg(feature = "serde_support")]
+impl<From: Currency, To: Currency> serde::Serialize for ConversionEvent<From, To> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&ConversionEventSerde::of(&self.erase()), serializer)
+    }
+}
+
+/// Rejects events whose currency codes are not `From` and `To`.
+#[cfg(feature = "serde_support")]
+impl<'de, From: Currency, To: Currency> serde::Deserialize<'de> for ConversionEvent<From, To> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let event = DynConversionEvent::deserialize(deserializer)?;
+        if event.from_currency_code != From::CODE || event.to_currency_code != To::CODE {
+            return Err(D::Error::custom(format!(
+                "Currency pair mismatch: expected {}/{}, found {}/{}",
+                From::CODE,
+                To::CODE,
+                event.from_currency_code,
+                event.to_currency_code
+            )));
+        }
+        Ok(Self::new(
+            event.from_amount,
+            event.to_amount,
+            event.rate,
+            event.timestamp_unix_secs,
+            event.rate_source,
+        ))
+    }
 }
 
 /// Trait for implementing custom conversion tracking/logging.
@@ -91,18 +298,469 @@ pub trait ConversionTracker {
     fn track<From: Currency, To: Currency>(&self, event: &ConversionEvent<From, To>);
 }
 
+/// Object-safe trait for conversion tracking.
+///
+/// Unlike [`ConversionTracker`], this trait is not generic over the currency
+/// types, so it can be used as `Box<dyn DynConversionTracker>` or
+/// `Arc<dyn DynConversionTracker + Send + Sync>` and swapped at runtime.
+///
+/// Every `DynConversionTracker` also implements [`ConversionTracker`], and any
+/// closure `Fn(&DynConversionEvent)` implements `DynConversionTracker`.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::conversion_tracking::{DynConversionEvent, DynConversionTracker};
+/// use typed_money::{Amount, Rate, USD, EUR};
+///
+/// struct StderrLogger;
+///
+/// impl DynConversionTracker for StderrLogger {
+///     fn track_dyn(&self, event: &DynConversionEvent) {
+///         eprintln!("{} {} -> {} {}",
+///             event.from_amount, event.from_currency_code,
+///             event.to_amount, event.to_currency_code);
+///     }
+/// }
+///
+/// let trackers: Vec<Box<dyn DynConversionTracker>> = vec![
+///     Box::new(StderrLogger),
+///     Box::new(|event: &DynConversionEvent| assert_eq!(event.to_currency_code, "EUR")),
+/// ];
+///
+/// let usd = Amount::<USD>::from_major(100);
+/// let rate = Rate::<USD, EUR>::new(0.85);
+/// for tracker in &trackers {
+///     let _ = usd.convert_with_tracking(&rate, tracker.as_ref());
+/// }
+/// ```
+pub trait DynConversionTracker {
+    /// Called when a conversion occurs.
+    fn track_dyn(&self, event: &DynConversionEvent);
+}
+
+impl<F: Fn(&DynConversionEvent)> DynConversionTracker for F {
+    fn track_dyn(&self, event: &DynConversionEvent) {
+        self(event)
+    }
+}
+
+impl<T: DynConversionTracker + ?Sized> ConversionTracker for T {
+    fn track<From: Currency, To: Currency>(&self, event: &ConversionEvent<From, To>) {
+        self.track_dyn(&event.erase());
+    }
+}
+
 /// A no-op tracker that does nothing.
 ///
 /// This is useful as a default or for disabling tracking.
 #[derive(Debug, Clone, Copy)]
 pub struct NoOpTracker;
 
-impl ConversionTracker for NoOpTracker {
-    fn track<From: Currency, To: Currency>(&self, _event: &ConversionEvent<From, To>) {
+impl DynConversionTracker for NoOpTracker {
+    fn track_dyn(&self, _event: &DynConversionEvent) {
         // Do nothing
     }
 }
 
+// ============================================================================
+// Process-wide default tracker
+// ============================================================================
+
+type SharedTracker = Arc<dyn DynConversionTracker + Send + Sync>;
+
+static DEFAULT_TRACKER: RwLock<Option<SharedTracker>> = RwLock::new(None);
+
+/// Installs a process-wide default tracker, replacing any previous one.
+///
+/// Once installed, every call to [`Amount::convert`](crate::Amount::convert)
+/// reports its conversion to this tracker. Explicitly tracked conversions made
+/// with [`Amount::convert_with_tracking`](crate::Amount::convert_with_tracking)
+/// are only reported to the tracker passed in.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::conversion_tracking::{self, NoOpTracker};
+///
+/// conversion_tracking::set_default_tracker(NoOpTracker);
+/// assert!(conversion_tracking::has_default_tracker());
+///
+/// conversion_tracking::clear_default_tracker();
+/// assert!(!conversion_tracking::has_default_tracker());
+/// ```
+pub fn set_default_tracker<T>(tracker: T)
+where
+    T: DynConversionTracker + Send + Sync + 'static,
+{
+    set_default_tracker_arc(Arc::new(tracker));
+}
+
+/// Installs an already shared tracker as the process-wide default.
+///
+/// This is useful when the application keeps its own handle to the tracker,
+/// for example to read collected events later.
+pub fn set_default_tracker_arc(tracker: Arc<dyn DynConversionTracker + Send + Sync>) {
+    let mut guard = DEFAULT_TRACKER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *guard = Some(tracker);
+}
+
+/// Removes the process-wide default tracker, returning it if one was installed.
+pub fn clear_default_tracker() -> Option<Arc<dyn DynConversionTracker + Send + Sync>> {
+    let mut guard = DEFAULT_TRACKER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    guard.take()
+}
+
+/// Returns whether a process-wide default tracker is installed.
+pub fn has_default_tracker() -> bool {
+    default_tracker().is_some()
+}
+
+fn default_tracker() -> Option<SharedTracker> {
+    DEFAULT_TRACKER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Reports a conversion to the default tracker, if one is installed.
+///
+/// The lock is released before the tracker runs, so trackers may themselves
+/// convert amounts or replace the default tracker.
+pub(crate) fn track_default<From: Currency, To: Currency>(
+    from: &Amount<From>,
+    rate: &Rate<From, To>,
+    to: &Amount<To>,
+) {
+    if let Some(tracker) = default_tracker() {
+        let event = ConversionEvent::<From, To>::new(
+            cloned_value(from.value()),
+            cloned_value(to.value()),
+            cloned_value(rate.value()),
+            rate.timestamp_unix_secs(),
+            rate.source(),
+        );
+
+        tracker.track_dyn(&event.erase());
+    }
+}
+
+// ============================================================================
+// Operation tracing
+// ============================================================================
+
+/// The kind of operation recorded in an [`OperationEvent`].
+#[cfg(feature = "operation_tracking")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Addition of two amounts
+    Add,
+    /// Subtraction of two amounts
+    Subtract,
+    /// Multiplication by a scalar
+    Multiply,
+    /// Division by a scalar
+    Divide,
+    /// Rounding to the currency's precision with an explicit mode
+    Round,
+    /// Rounding to a multiple of an increment (e.g., cash rounding)
+    RoundToIncrement,
+    /// Normalization to the currency's precision (banker's rounding)
+    Normalize,
+    /// Conversion to another currency with an exchange rate
+    Convert,
+}
+
+#[cfg(feature = "operation_tracking")]
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Add => write!(f, "add"),
+            Operation::Subtract => write!(f, "subtract"),
+            Operation::Multiply => write!(f, "multiply"),
+            Operation::Divide => write!(f, "divide"),
+            Operation::Round => write!(f, "round"),
+            Operation::RoundToIncrement => write!(f, "round_to_increment"),
+            Operation::Normalize => write!(f, "normalize"),
+            Operation::Convert => write!(f, "convert"),
+        }
+    }
+}
+
+/// A single step of a monetary calculation.
+///
+/// Events record the input, the optional second operand (the other amount, the
+/// scalar, or the exchange rate), the rounding mode if any, and the output.
+#[cfg(feature = "operation_tracking")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationEvent {
+    /// The operation performed
+    pub operation: Operation,
+    /// The input amount value
+    pub input: Decimal,
+    /// The second operand: other amount, scalar, or exchange rate
+    pub operand: Option<Decimal>,
+    /// The rounding mode, for rounding operations
+    pub rounding_mode: Option<RoundingMode>,
+    /// The resulting amount value
+    pub output: Decimal,
+    /// Currency code of the input
+    pub currency_code: &'static str,
+    /// Currency code of the output (differs from the input only for conversions)
+    pub output_currency_code: &'static str,
+}
+
+#[cfg(feature = "operation_tracking")]
+impl OperationEvent {
+    /// Creates a new operation event for a single-currency operation.
+    pub fn new(
+        operation: Operation,
+        currency_code: &'static str,
+        input: &Decimal,
+        output: &Decimal,
+    ) -> Self {
+        Self {
+            operation,
+            input: cloned_value(input),
+            operand: None,
+            rounding_mode: None,
+            output: cloned_value(output),
+            currency_code,
+            output_currency_code: currency_code,
+        }
+    }
+
+    /// Returns the event with the second operand set.
+    pub fn with_operand(mut self, operand: &Decimal) -> Self {
+        self.operand = Some(cloned_value(operand));
+        self
+    }
+
+    /// Returns the event with the rounding mode set.
+    pub fn with_rounding_mode(mut self, mode: RoundingMode) -> Self {
+        self.rounding_mode = Some(mode);
+        self
+    }
+
+    /// Returns the event with a different output currency code.
+    pub fn with_output_currency(mut self, code: &'static str) -> Self {
+        self.output_currency_code = code;
+        self
+    }
+}
+
+#[cfg(feature = "operation_tracking")]
+impl fmt::Display for OperationEvent {
+    /// Formats the event as one human-readable line of a calculation trail.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let input = format!("{} {}", self.input, self.currency_code);
+        let output = format!("{} {}", self.output, self.output_currency_code);
+
+        match (self.operation, &self.operand, self.rounding_mode) {
+            (Operation::Add, Some(operand), _) => {
+                write!(
+                    f,
+                    "{} + {} {} = {}",
+                    input, operand, self.currency_code, output
+                )
+            }
+            (Operation::Subtract, Some(operand), _) => {
+                write!(
+                    f,
+                    "{} - {} {} = {}",
+                    input, operand, self.currency_code, output
+                )
+            }
+            (Operation::Multiply, Some(operand), _) => {
+                write!(f, "{} × {} = {}", input, operand, output)
+            }
+            (Operation::Divide, Some(operand), _) => {
+                write!(f, "{} ÷ {} = {}", input, operand, output)
+            }
+            (Operation::Convert, Some(rate), _) => {
+                write!(
+                    f,
+                    "{} → {} @ {} = {}",
+                    input, self.output_currency_code, rate, output
+                )
+            }
+            (Operation::RoundToIncrement, Some(increment), Some(mode)) => {
+                write!(
+                    f,
+                    "round({}, step {}, {:?}) = {}",
+                    input, increment, mode, output
+                )
+            }
+            (operation, _, Some(mode)) => {
+                write!(f, "{}({}, {:?}) = {}", operation, input, mode, output)
+            }
+            (operation, _, None) => write!(f, "{}({}) = {}", operation, input, output),
+        }
+    }
+}
+
+/// Object-safe trait for receiving [`OperationEvent`]s.
+///
+/// Any closure `Fn(&OperationEvent)` implements this trait.
+#[cfg(feature = "operation_tracking")]
+pub trait OperationTracker {
+    /// Called after an operation has been performed.
+    fn track_operation(&self, event: &OperationEvent);
+}
+
+#[cfg(feature = "operation_tracking")]
+impl<F: Fn(&OperationEvent)> OperationTracker for F {
+    fn track_operation(&self, event: &OperationEvent) {
+        self(event)
+    }
+}
+
+// Operation trackers are per thread: a calculation trail describes one
+// calculation, and interleaving steps from other threads would make it useless.
+#[cfg(feature = "operation_tracking")]
+thread_local! {
+    static OPERATION_TRACKER: RefCell<Option<Rc<dyn OperationTracker>>> =
+        const { RefCell::new(None) };
+}
+
+/// Installs an operation tracker for the current thread, replacing any previous one.
+///
+/// Returns the previously installed tracker, if any.
+#[cfg(feature = "operation_tracking")]
+pub fn set_operation_tracker<T>(tracker: T) -> Option<Rc<dyn OperationTracker>>
+where
+    T: OperationTracker + 'static,
+{
+    OPERATION_TRACKER.with(|slot| slot.borrow_mut().replace(Rc::new(tracker)))
+}
+
+/// Removes the operation tracker of the current thread, returning it if one was installed.
+#[cfg(feature = "operation_tracking")]
+pub fn clear_operation_tracker() -> Option<Rc<dyn OperationTracker>> {
+    OPERATION_TRACKER.with(|slot| slot.borrow_mut().take())
+}
+
+/// Reports an operation to the current thread's tracker, if one is installed.
+///
+/// The event is only built when a tracker is present, so untracked
+/// calculations do not pay for copying values.
+#[cfg(feature = "operation_tracking")]
+pub(crate) fn track_operation(build: impl FnOnce() -> OperationEvent) {
+    let tracker = OPERATION_TRACKER.with(|slot| slot.borrow().clone());
+    if let Some(tracker) = tracker {
+        tracker.track_operation(&build());
+    }
+}
+
+/// A built-in tracker that collects operations into a calculation trail.
+///
+/// Cloning a collector is cheap and the clones share the same trail, so one
+/// clone can be installed with [`set_operation_tracker`] while another is kept
+/// to read the events.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "operation_tracking")]
+/// # {
+/// use typed_money::conversion_tracking::{self, OperationCollector};
+/// use typed_money::{Amount, USD};
+///
+/// let collector = OperationCollector::new();
+/// conversion_tracking::set_operation_tracker(collector.clone());
+///
+/// let _total = Amount::<USD>::from_major(100) + Amount::<USD>::from_minor(850);
+/// conversion_tracking::clear_operation_tracker();
+///
+/// assert_eq!(collector.render(), "1. 100 USD + 8.50 USD = 108.50 USD");
+/// # }
+/// ```
+#[cfg(feature = "operation_tracking")]
+#[derive(Debug, Clone, Default)]
+pub struct OperationCollector {
+    events: Rc<RefCell<Vec<OperationEvent>>>,
+}
+
+#[cfg(feature = "operation_tracking")]
+impl OperationCollector {
+    /// Creates an empty collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs `f` with a fresh collector installed on the current thread.
+    ///
+    /// Returns the result of `f` together with the collected trail. The
+    /// previously installed tracker, if any, is restored when `f` returns or
+    /// panics.
+    pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Self) {
+        struct Restore(Option<Rc<dyn OperationTracker>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                OPERATION_TRACKER.with(|slot| *slot.borrow_mut() = previous);
+            }
+        }
+
+        let collector = Self::new();
+        let _restore = Restore(OPERATION_TRACKER.with(|slot| {
+            slot.borrow_mut()
+                .replace(Rc::new(collector.clone()) as Rc<dyn OperationTracker>)
+        }));
+        (f(), collector)
+    }
+
+    /// Returns a copy of the collected events, in order.
+    pub fn events(&self) -> Vec<OperationEvent> {
+        self.events.borrow().clone()
+    }
+
+    /// Returns the number of collected events.
+    pub fn len(&self) -> usize {
+        self.events.borrow().len()
+    }
+
+    /// Returns whether no events have been collected.
+    pub fn is_empty(&self) -> bool {
+        self.events.borrow().is_empty()
+    }
+
+    /// Removes all collected events.
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+
+    /// Renders the collected events as a numbered, human-readable trail.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(feature = "operation_tracking")]
+impl OperationTracker for OperationCollector {
+    fn track_operation(&self, event: &OperationEvent) {
+        self.events.borrow_mut().push(event.clone());
+    }
+}
+
+#[cfg(feature = "operation_tracking")]
+impl fmt::Display for OperationCollector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, event) in self.events.borrow().iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}. {}", index + 1, event)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 #[cfg(not(all(feature = "use_rust_decimal", feature = "use_bigdecimal")))]
 mod tests {
@@ -178,4 +836,228 @@ mod tests {
 
         assert_eq!(*tracker.count.borrow(), 2);
     }
+
+    #[test]
+    fn test_erase_keeps_codes_and_values() {
+        let event = ConversionEvent::<USD, EUR>::new(
+            Decimal::from(100),
+            Decimal::new(85, 0),
+            Decimal::new(85, 2),
+            Some(1_700_000_000),
+            Some("ECB"),
+        );
+
+        let erased = DynConversionEvent::from(&event);
+        assert_eq!(erased.from_amount, Decimal::from(100));
+        assert_eq!(erased.to_amount, Decimal::new(85, 0));
+        assert_eq!(erased.rate, Decimal::new(85, 2));
+        assert_eq!(erased.timestamp_unix_secs, Some(1_700_000_000));
+        assert_eq!(erased.rate_source, Some("ECB"));
+        assert_eq!(erased.from_currency_code, "USD");
+        assert_eq!(erased.to_currency_code, "EUR");
+    }
+
+    #[test]
+    fn test_closure_tracker() {
+        let seen = std::cell::RefCell::new(Vec::new());
+        let tracker = |event: &DynConversionEvent| {
+            seen.borrow_mut().push(event.to_currency_code);
+        };
+
+        let event = ConversionEvent::<USD, EUR>::new(
+            Decimal::from(1),
+            Decimal::new(85, 2),
+            Decimal::new(85, 2),
+            None,
+            None,
+        );
+
+        // Closures work both as object-safe and as generic trackers
+        tracker.track_dyn(&event.erase());
+        tracker.track(&event);
+
+        assert_eq!(*seen.borrow(), vec!["EUR", "EUR"]);
+    }
+
+    #[test]
+    fn test_boxed_dyn_tracker() {
+        let count = std::rc::Rc::new(std::cell::Cell::new(0));
+        let counter = count.clone();
+        let mut tracker: Box<dyn DynConversionTracker> = Box::new(NoOpTracker);
+
+        let event = ConversionEvent::<USD, EUR>::new(
+            Decimal::from(1),
+            Decimal::new(85, 2),
+            Decimal::new(85, 2),
+            None,
+            None,
+        );
+
+        tracker.track(&event);
+        assert_eq!(count.get(), 0);
+
+        // Swap the tracker at runtime
+        tracker = Box::new(move |_: &DynConversionEvent| counter.set(counter.get() + 1));
+        tracker.as_ref().track(&event);
+        assert_eq!(count.get(), 1);
+    }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_conversion_event_serde_round_trip() {
+        crate::serde::register_rate_source("ECB");
+        let event = ConversionEvent::<USD, EUR>::new(
+            Decimal::from(100),
+            Decimal::new(85, 0),
+            Decimal::new(85, 2),
+            Some(1_700_000_000),
+            Some("ECB"),
+        );
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(
+            json,
+            r#"{"from_amount":"100","to_amount":"85","rate":"0.85","timestamp_unix_secs":1700000000,"rate_source":"ECB","from_currency_code":"USD","to_currency_code":"EUR"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ConversionEvent<USD, EUR>>(&json).unwrap(),
+            event
+        );
+        assert_eq!(
+            serde_json::from_str::<DynConversionEvent>(&json).unwrap(),
+            event.erase()
+        );
+
+        let swapped = serde_json::from_str::<ConversionEvent<EUR, USD>>(&json).unwrap_err();
+        assert!(swapped
+            .to_string()
+            .contains("expected EUR/USD, found USD/EUR"));
+
+        // Sources this process never registered load as well
+        let archived = json.replace("\"ECB\"", "\"Archived feed\"");
+        let loaded = serde_json::from_str::<DynConversionEvent>(&archived).unwrap();
+        assert_eq!(loaded.rate_source, Some("Archived feed"));
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), archived);
+
+        let unknown = json.replace("\"USD\"", "\"XYZ\"");
+        assert!(serde_json::from_str::<DynConversionEvent>(&unknown)
+            .unwrap_err()
+            .to_string()
+            .contains("Unknown currency code 'XYZ'"));
+    }
+
+    #[cfg(feature = "operation_tracking")]
+    mod operation_tests {
+        use super::*;
+        use crate::{Amount, Rate, RoundingMode, EUR, USD};
+
+        #[test]
+        fn test_capture_records_arithmetic_in_order() {
+            let (_, trail) = OperationCollector::capture(|| {
+                let a = Amount::<USD>::from_major(10);
+                let b = Amount::<USD>::from_major(4);
+                ((a + b) - b) * 3 / 2
+            });
+
+            let operations: Vec<Operation> = trail.events().iter().map(|e| e.operation).collect();
+            assert_eq!(
+                operations,
+                vec![
+                    Operation::Add,
+                    Operation::Subtract,
+                    Operation::Multiply,
+                    Operation::Divide
+                ]
+            );
+        }
+
+        #[test]
+        fn test_round_and_normalize_record_mode() {
+            let (_, trail) = OperationCollector::capture(|| {
+                let third = Amount::<USD>::from_major(10) / 3;
+                let _ = third.round(RoundingMode::Ceiling);
+                third.normalize()
+            });
+
+            let events = trail.events();
+            assert_eq!(events.len(), 3);
+            assert_eq!(events[1].operation, Operation::Round);
+            assert_eq!(events[1].rounding_mode, Some(RoundingMode::Ceiling));
+            assert_eq!(events[1].output, Decimal::new(334, 2));
+            assert_eq!(events[2].operation, Operation::Normalize);
+            assert_eq!(events[2].rounding_mode, Some(RoundingMode::HalfEven));
+        }
+
+        #[test]
+        fn test_convert_records_rate_and_currencies() {
+            let (_, trail) = OperationCollector::capture(|| {
+                Amount::<USD>::from_major(100).convert(&Rate::<USD, EUR>::new(0.85))
+            });
+
+            let events = trail.events();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].currency_code, "USD");
+            assert_eq!(events[0].output_currency_code, "EUR");
+            assert_eq!(events[0].operand, Some(Decimal::new(85, 2)));
+        }
+
+        #[test]
+        fn test_render_trail() {
+            let (_, trail) = OperationCollector::capture(|| {
+                let total = Amount::<USD>::from_minor(1000) * 2;
+                (total / 3).round(RoundingMode::HalfUp)
+            });
+
+            let rendered = trail.render();
+            let lines: Vec<&str> = rendered.lines().collect();
+            assert_eq!(lines.len(), 3);
+            assert_eq!(lines[0], "1. 10.00 USD × 2 = 20.00 USD");
+            assert!(lines[1].starts_with("2. 20.00 USD ÷ 3 = 6.66"));
+            assert!(lines[2].starts_with("3. round(6.66"));
+            assert!(lines[2].ends_with(", HalfUp) = 6.67 USD"));
+        }
+
+        #[test]
+        fn test_capture_restores_previous_tracker() {
+            let outer = OperationCollector::new();
+            set_operation_tracker(outer.clone());
+
+            let (_, inner) = OperationCollector::capture(|| {
+                Amount::<USD>::from_major(1) + Amount::<USD>::from_major(2)
+            });
+            let _ = Amount::<USD>::from_major(3) * 2;
+            clear_operation_tracker();
+
+            assert_eq!(inner.len(), 1);
+            assert_eq!(outer.len(), 1);
+            assert_eq!(outer.events()[0].operation, Operation::Multiply);
+        }
+
+        #[test]
+        fn test_capture_restores_previous_tracker_on_panic() {
+            let outer = OperationCollector::new();
+            set_operation_tracker(outer.clone());
+
+            let result = std::panic::catch_unwind(|| {
+                OperationCollector::capture(|| panic!("inside capture"))
+            });
+            let _ = Amount::<USD>::from_major(3) * 2;
+            clear_operation_tracker();
+
+            assert!(result.is_err());
+            assert_eq!(outer.len(), 1);
+        }
+
+        #[test]
+        fn test_closure_operation_tracker() {
+            let count = Rc::new(std::cell::Cell::new(0));
+            let counter = count.clone();
+            set_operation_tracker(move |_: &OperationEvent| counter.set(counter.get() + 1));
+
+            let _ = Amount::<USD>::from_major(1) + Amount::<USD>::from_major(2);
+            clear_operation_tracker();
+            let _ = Amount::<USD>::from_major(1) + Amount::<USD>::from_major(2);
+
+            assert_eq!(count.get(), 1);
+        }
+    }
 }
@@ -0,0 +1,1131 @@
+//! Reading and writing amounts in CSV files.
+//!
+//! A [`CsvFormat`] says which columns hold the money: a single value column,
+//! or separate debit and credit columns, plus an optional currency column.
+//! [`CsvReader`] streams records from any [`BufRead`] and turns them into
+//! typed [`Amount`]s or [`DynAmount`]s; [`CsvWriter`] writes them back.
+//!
+//! Records follow RFC 4180: fields containing the delimiter, quotes or line
+//! breaks are quoted, and quotes inside them are doubled. A UTF-8 byte order
+//! mark and blank lines are skipped. Records that are not valid UTF-8 are row
+//! errors, and records longer than [`CsvFormat::with_max_record_len`] end the
+//! stream.
+//!
+//! Every row error is a [`MoneyError`] whose message starts with the line it
+//! was found on, e.g. `line 3, column 'amount': Invalid numeric value`. The
+//! reader keeps going after a bad row, so one pass can report them all.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::csv::{CsvFormat, CsvReader};
+//! use typed_money::{Amount, USD};
+//!
+//! let data = "\
+//! date,memo,debit,credit
+//! 2024-01-02,Salary,,\"2,500.00\"
+//! 2024-01-03,Rent,1200.00,
+//! ";
+//! let format = CsvFormat::new().with_debit_credit_columns("debit", "credit");
+//! let mut reader = CsvReader::new(data.as_bytes(), format)?;
+//!
+//! let mut total = Amount::<USD>::from_minor(0);
+//! for row in reader.amounts::<USD>() {
+//!     let row = row?;
+//!     println!("line {}: {} {}", row.line, row.fields[1], row.amount);
+//!     total = total + row.amount;
+//! }
+//! assert_eq!(total, Amount::<USD>::from_minor(130_000));
+//! # Ok::<(), typed_money::MoneyError>(())
+//! ```
+
+use crate::amount::cloned_value;
+use crate::{
+    find_currency, Amount, AmountPolicy, Currency, CurrencyHints, DynAmount, Locale, MoneyError,
+    MoneyResult,
+};
+use std::borrow::Cow;
+use std::io::{self, BufRead, Read, Write};
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// Longest record read by default, in bytes.
+const DEFAULT_MAX_RECORD_LEN: usize = 1 << 20;
+
+/// A column, by header name or by zero-based position.
+///
+/// Names are matched against the header row ignoring ASCII case and
+/// surrounding whitespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Column {
+    /// The column with this header
+    Name(String),
+    /// The column at this zero-based position
+    Index(usize),
+}
+
+impl From<&str> for Column {
+    fn from(name: &str) -> Self {
+        Column::Name(name.to_string())
+    }
+}
+
+impl From<String> for Column {
+    fn from(name: String) -> Self {
+        Column::Name(name)
+    }
+}
+
+impl From<usize> for Column {
+    fn from(index: usize) -> Self {
+        Column::Index(index)
+    }
+}
+
+/// Where the amount of a row is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum MoneyColumns {
+    Value(Column),
+    DebitCredit { debit: Column, credit: Column },
+}
+
+/// The layout of a CSV file: delimiter, header and money columns.
+///
+/// By default fields are separated by commas, the first line is a header,
+/// amounts are read from the `amount` column with a dot for decimals and
+/// optional commas between thousands, and records may be up to 1 MiB long.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::csv::CsvFormat;
+/// use typed_money::Locale;
+///
+/// // A German bank export: "Betrag;Währung" with 1.234,56 style numbers
+/// let format = CsvFormat::new()
+///     .with_delimiter(';')
+///     .with_value_column("Betrag")
+///     .with_currency_column("Währung")
+///     .with_locale(&Locale::parse("de-DE")?);
+/// # Ok::<(), typed_money::MoneyError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvFormat {
+    delimiter: char,
+    has_header: bool,
+    money: MoneyColumns,
+    currency: Option<Column>,
+    locale: Option<Locale>,
+    hints: CurrencyHints,
+    max_record_len: usize,
+}
+
+impl Default for CsvFormat {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: true,
+            money: MoneyColumns::Value(Column::from("amount")),
+            currency: None,
+            locale: None,
+            hints: CurrencyHints::new(),
+            max_record_len: DEFAULT_MAX_RECORD_LEN,
+        }
+    }
+}
+
+impl CsvFormat {
+    /// Creates the default format.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Separates fields with `delimiter` instead of a comma.
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Treats the first line as data. Columns must then be given by index.
+    pub fn without_header(mut self) -> Self {
+        self.has_header = false;
+        self
+    }
+
+    /// Reads each amount from a single column.
+    pub fn with_value_column(mut self, column: impl Into<Column>) -> Self {
+        self.money = MoneyColumns::Value(column.into());
+        self
+    }
+
+    /// Reads each amount as `credit - debit` from two columns.
+    ///
+    /// Either column may be empty on a given row, but not both. Both hold
+    /// positive numbers for ordinary entries.
+    pub fn with_debit_credit_columns(
+        mut self,
+        debit: impl Into<Column>,
+        credit: impl Into<Column>,
+    ) -> Self {
+        self.money = MoneyColumns::DebitCredit {
+            debit: debit.into(),
+            credit: credit.into(),
+        };
+        self
+    }
+
+    /// Reads the currency code (or alias) of each row from a column.
+    ///
+    /// Typed reads reject rows in another currency; dynamic reads use it as
+    /// the row's currency. Rows with an empty currency cell fall back to the
+    /// amount itself.
+    pub fn with_currency_column(mut self, column: impl Into<Column>) -> Self {
+        self.currency = Some(column.into());
+        self
+    }
+
+    /// Reads and writes numbers with the locale's separators, digits and
+    /// minus sign, as [`Amount::parse_locale`] does.
+    ///
+    /// The locale's region also resolves shared symbols for dynamic reads.
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.locale = Some(locale.clone());
+        self
+    }
+
+    /// Resolves shared symbols such as `$` with `hints` for dynamic reads.
+    pub fn with_hints(mut self, hints: CurrencyHints) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    /// Limits records to `bytes`, counting line breaks and quoted lines.
+    ///
+    /// A longer record, such as one left open by a stray quote, is an error
+    /// that ends the stream instead of buffering the rest of the input.
+    pub fn with_max_record_len(mut self, bytes: usize) -> Self {
+        self.max_record_len = bytes;
+        self
+    }
+
+    fn hints(&self) -> CurrencyHints {
+        match &self.locale {
+            Some(locale) => self.hints.clone().with_locale(locale),
+            None => self.hints.clone(),
+        }
+    }
+}
+
+/// A parsed row: its line number, the amount and every raw field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRow<T> {
+    /// The one-based line the record starts on
+    pub line: usize,
+    /// The amount read from the money columns
+    pub amount: T,
+    /// All fields of the record, unparsed
+    pub fields: Vec<String>,
+}
+
+/// Column positions after matching the format against the header.
+struct Positions {
+    value: Option<usize>,
+    debit_credit: Option<(usize, usize)>,
+    currency: Option<usize>,
+}
+
+/// Streams amounts from CSV input, one record at a time.
+///
+/// Only the current record is held in memory, so files of any size can be
+/// read.
+pub struct CsvReader<R> {
+    input: R,
+    format: CsvFormat,
+    headers: Option<Vec<String>>,
+    positions: Positions,
+    line: usize,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl<R: BufRead> CsvReader<R> {
+    /// Creates a reader, reading the header row if the format has one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`] if the header cannot be read or
+    /// lacks a named column, or if a column is named but the format has no
+    /// header.
+    pub fn new(input: R, format: CsvFormat) -> MoneyResult<Self> {
+        let mut reader = Self {
+            input,
+            format,
+            headers: None,
+            positions: Positions {
+                value: None,
+                debit_credit: None,
+                currency: None,
+            },
+            line: 0,
+            buffer: Vec::new(),
+            done: false,
+        };
+        if reader.format.has_header {
+            let (_, headers) = reader.read_record().unwrap_or_else(|| {
+                Err(MoneyError::ParseError {
+                    input: String::new(),
+                    expected_currency: None,
+                    reason: "line 1: Missing header row".to_string(),
+                })
+            })?;
+            reader.headers = Some(headers.iter().map(|name| name.trim().to_string()).collect());
+        }
+
+        reader.positions = Positions {
+            value: match &reader.format.money {
+                MoneyColumns::Value(column) => Some(reader.position(column)?),
+                MoneyColumns::DebitCredit { .. } => None,
+            },
+            debit_credit: match &reader.format.money {
+                MoneyColumns::Value(_) => None,
+                MoneyColumns::DebitCredit { debit, credit } => {
+                    Some((reader.position(debit)?, reader.position(credit)?))
+                }
+            },
+            currency: match &reader.format.currency {
+                Some(column) => Some(reader.position(column)?),
+                None => None,
+            },
+        };
+        Ok(reader)
+    }
+
+    /// Returns the header row, if the format has one.
+    pub fn headers(&self) -> Option<&[String]> {
+        self.headers.as_deref()
+    }
+
+    /// Returns the position of `name` in the header row, ignoring ASCII case.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.headers
+            .as_ref()?
+            .iter()
+            .position(|header| header.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Reads the next record as raw fields, with the line it starts on.
+    ///
+    /// A record that is not valid UTF-8 is an error for that record only.
+    /// Returns `None` at the end of the input, and after an I/O error, an
+    /// unterminated quote or a record longer than the format allows, which
+    /// end the stream.
+    pub fn read_record(&mut self) -> Option<MoneyResult<(usize, Vec<String>)>> {
+        while !self.done {
+            let start = self.line + 1;
+            let mut fields = Vec::new();
+            let mut field = String::new();
+            let mut in_quotes = false;
+            let mut read_any = false;
+            let mut record_len = 0;
+            let mut invalid_utf8 = None;
+
+            loop {
+                self.buffer.clear();
+                // One byte past the limit tells a full record from a long one
+                let limit = (self.format.max_record_len.saturating_sub(record_len) as u64)
+                    .saturating_add(1);
+                match (&mut self.input)
+                    .take(limit)
+                    .read_until(b'\n', &mut self.buffer)
+                {
+                    Ok(0) => {
+                        self.done = true;
+                        if in_quotes {
+                            return Some(Err(line_error(
+                                start,
+                                None,
+                                &field,
+                                "Unterminated quoted field",
+                            )));
+                        }
+                        break;
+                    }
+                    Ok(read) => {
+                        self.line += 1;
+                        read_any = true;
+                        record_len += read;
+                    }
+                    Err(error) => {
+                        self.done = true;
+                        return Some(Err(io_error(self.line + 1, &error)));
+                    }
+                }
+                if record_len > self.format.max_record_len {
+                    self.done = true;
+                    return Some(Err(line_error(
+                        start,
+                        None,
+                        &field,
+                        &format!("Record longer than {} bytes", self.format.max_record_len),
+                    )));
+                }
+
+                let mut bytes = self.buffer.as_slice();
+                if self.line == 1 {
+                    bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+                }
+                let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+                let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+                let text = String::from_utf8_lossy(bytes);
+                if let (Cow::Owned(text), None) = (&text, &invalid_utf8) {
+                    invalid_utf8 = Some(text.clone());
+                }
+
+                let mut chars = text.chars().peekable();
+                while let Some(ch) = chars.next() {
+                    if in_quotes {
+                        if ch != '"' {
+                            field.push(ch);
+                        } else if chars.peek() == Some(&'"') {
+                            field.push('"');
+                            chars.next();
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else if ch == self.format.delimiter {
+                        fields.push(std::mem::take(&mut field));
+                    } else if ch == '"' && field.is_empty() {
+                        in_quotes = true;
+                    } else {
+                        field.push(ch);
+                    }
+                }
+                if !in_quotes {
+                    break;
+                }
+                // A quoted field continues on the next line
+                field.push('\n');
+            }
+
+            if !read_any {
+                return None;
+            }
+            if let Some(text) = invalid_utf8 {
+                return Some(Err(line_error(start, None, &text, "Invalid UTF-8")));
+            }
+            fields.push(field);
+            if fields.len() == 1 && fields[0].trim().is_empty() {
+                continue;
+            }
+            return Some(Ok((start, fields)));
+        }
+        None
+    }
+
+    /// Returns an iterator over the remaining rows as amounts in `C`.
+    ///
+    /// Rows naming another currency, in the amount or the currency column,
+    /// are errors. Each amount is validated against the current
+    /// [`AmountPolicy`](crate::AmountPolicy).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::csv::{CsvFormat, CsvReader};
+    /// use typed_money::{MoneyError, USD};
+    ///
+    /// let data = "amount,currency\n12.50,USD\n3.00,EUR\nabc,USD\n";
+    /// let format = CsvFormat::new().with_currency_column("currency");
+    /// let rows: Vec<_> = CsvReader::new(data.as_bytes(), format)?
+    ///     .amounts::<USD>()
+    ///     .collect();
+    ///
+    /// assert_eq!(rows[0].as_ref().unwrap().amount.to_minor(), 1250);
+    /// assert!(matches!(&rows[1], Err(MoneyError::CurrencyMismatch { found: "EUR", .. })));
+    /// assert!(rows[2].as_ref().unwrap_err().to_string().contains("line 4"));
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn amounts<C: Currency>(
+        &mut self,
+    ) -> impl Iterator<Item = MoneyResult<CsvRow<Amount<C>>>> + '_ {
+        std::iter::from_fn(move || {
+            let (line, fields) = match self.read_record()? {
+                Ok(record) => record,
+                Err(error) => return Some(Err(error)),
+            };
+            Some(self.typed_amount(line, &fields).map(|amount| CsvRow {
+                line,
+                amount,
+                fields,
+            }))
+        })
+    }
+
+    /// Returns an iterator over the remaining rows as dynamic amounts.
+    ///
+    /// The currency comes from the currency column when it is set, otherwise
+    /// from the amount itself, resolved with the format's hints as in
+    /// [`DynAmount::parse`]. Each amount is validated against the current
+    /// [`AmountPolicy`](crate::AmountPolicy).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::csv::{CsvFormat, CsvReader};
+    ///
+    /// let data = "amount\n12.50 CAD\n€3\n";
+    /// let mut reader = CsvReader::new(data.as_bytes(), CsvFormat::new())?;
+    /// let codes: Vec<_> = reader
+    ///     .dyn_amounts()
+    ///     .map(|row| row.map(|row| row.amount.code()))
+    ///     .collect::<Result<_, _>>()?;
+    /// assert_eq!(codes, ["CAD", "EUR"]);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn dyn_amounts(&mut self) -> impl Iterator<Item = MoneyResult<CsvRow<DynAmount>>> + '_ {
+        let hints = self.format.hints();
+        std::iter::from_fn(move || {
+            let (line, fields) = match self.read_record()? {
+                Ok(record) => record,
+                Err(error) => return Some(Err(error)),
+            };
+            Some(self.dyn_amount(line, &fields, &hints).map(|amount| CsvRow {
+                line,
+                amount,
+                fields,
+            }))
+        })
+    }
+
+    fn position(&self, column: &Column) -> MoneyResult<usize> {
+        match column {
+            Column::Index(index) => Ok(*index),
+            Column::Name(name) if self.headers.is_none() => Err(MoneyError::ParseError {
+                input: name.clone(),
+                expected_currency: None,
+                reason: format!("Column '{}' is named but the format has no header", name),
+            }),
+            Column::Name(name) => self.column_index(name).ok_or_else(|| {
+                line_error(
+                    1,
+                    None,
+                    name,
+                    &format!("Column '{}' not found in header", name),
+                )
+            }),
+        }
+    }
+
+    /// Names a column in errors: its header, or its index without one.
+    fn label(&self, index: usize) -> String {
+        match self.headers.as_ref().and_then(|headers| headers.get(index)) {
+            Some(name) => format!("column '{}'", name),
+            None => format!("column {}", index),
+        }
+    }
+
+    /// Returns the trimmed cell at `index`, or an error if the row is short.
+    fn cell<'f>(&self, line: usize, fields: &'f [String], index: usize) -> MoneyResult<&'f str> {
+        fields
+            .get(index)
+            .map(|field| field.trim())
+            .ok_or_else(|| line_error(line, Some(&self.label(index)), "", "Missing field"))
+    }
+
+    fn parse_typed<C: Currency>(&self, text: &str) -> MoneyResult<Amount<C>> {
+        match &self.format.locale {
+            Some(locale) => Amount::parse_locale(text, locale),
+            None => Amount::parse_plain(text),
+        }
+    }
+
+    fn typed_amount<C: Currency>(&self, line: usize, fields: &[String]) -> MoneyResult<Amount<C>> {
+        if let Some(index) = self.positions.currency {
+            let code = self.cell(line, fields, index)?;
+            if !code.is_empty() {
+                let at = |error| at_line(error, line, &self.label(index), code);
+                match find_currency(code) {
+                    Some(info) if info.code() == C::CODE => {}
+                    Some(info) => {
+                        return Err(at(MoneyError::CurrencyMismatch {
+                            expected: C::CODE,
+                            found: info.code(),
+                            context: "reading CSV".to_string(),
+                        }))
+                    }
+                    None => {
+                        return Err(at(MoneyError::ParseError {
+                            input: code.to_string(),
+                            expected_currency: Some(C::CODE),
+                            reason: "Unknown currency code".to_string(),
+                        }))
+                    }
+                }
+            }
+        }
+
+        let read = |index: usize| -> MoneyResult<Option<Decimal>> {
+            let text = self.cell(line, fields, index)?;
+            if text.is_empty() && self.positions.debit_credit.is_some() {
+                return Ok(None);
+            }
+            self.parse_typed::<C>(text)
+                .map(|amount| Some(cloned_value(amount.value())))
+                .map_err(|error| at_line(error, line, &self.label(index), text))
+        };
+        let amount = match (self.positions.value, self.positions.debit_credit) {
+            (Some(index), _) => read(index)?.map(Amount::new),
+            (None, Some((debit, credit))) => {
+                let amount = net(read(debit)?, read(credit)?).map(Amount::new);
+                // Each cell passed the policy on its own; the net may not
+                if let Some(amount) = &amount {
+                    AmountPolicy::with_current(|policy| policy.check(amount)).map_err(|error| {
+                        let column = format!("{} and {}", self.label(debit), self.label(credit));
+                        at_line(error, line, &column, "")
+                    })?;
+                }
+                amount
+            }
+            (None, None) => None,
+        };
+        amount.ok_or_else(|| line_error(line, None, "", "Neither debit nor credit is set"))
+    }
+
+    fn dyn_amount(
+        &self,
+        line: usize,
+        fields: &[String],
+        hints: &CurrencyHints,
+    ) -> MoneyResult<DynAmount> {
+        let currency = match self.positions.currency {
+            Some(index) => {
+                let code = self.cell(line, fields, index)?;
+                if code.is_empty() {
+                    None
+                } else {
+                    let info = find_currency(code).ok_or_else(|| {
+                        line_error(
+                            line,
+                            Some(&self.label(index)),
+                            code,
+                            "Unknown currency code",
+                        )
+                    })?;
+                    Some(info)
+                }
+            }
+            None => None,
+        };
+
+        let read = |index: usize| -> MoneyResult<Option<DynAmount>> {
+            let text = self.cell(line, fields, index)?;
+            if text.is_empty() && self.positions.debit_credit.is_some() {
+                return Ok(None);
+            }
+            let parsed = match currency {
+                // The code pins the currency; a conflicting symbol is an error
+                Some(info) => DynAmount::parse(&format!("{} {}", text, info.code()), hints),
+                None => DynAmount::parse(text, hints),
+            };
+            parsed
+                .map(Some)
+                .map_err(|error| at_line(error, line, &self.label(index), text))
+        };
+        match (self.positions.value, self.positions.debit_credit) {
+            (Some(index), _) => read(index)?,
+            (None, Some((debit, credit))) => match (read(debit)?, read(credit)?) {
+                (Some(debit_amount), Some(credit_amount))
+                    if debit_amount.code() != credit_amount.code() =>
+                {
+                    let input = format!(
+                        "{}{}{}",
+                        self.cell(line, fields, debit)?,
+                        self.format.delimiter,
+                        self.cell(line, fields, credit)?
+                    );
+                    return Err(line_error(
+                        line,
+                        None,
+                        &input,
+                        &format!(
+                            "Debit in {} but credit in {}",
+                            debit_amount.code(),
+                            credit_amount.code()
+                        ),
+                    ));
+                }
+                (debit_amount, credit_amount) => {
+                    let info = debit_amount
+                        .as_ref()
+                        .or(credit_amount.as_ref())
+                        .map(DynAmount::currency);
+                    let value = net(
+                        debit_amount.map(|amount| cloned_value(amount.value())),
+                        credit_amount.map(|amount| cloned_value(amount.value())),
+                    );
+                    let amount = value
+                        .zip(info)
+                        .map(|(value, info)| DynAmount::new(value, info));
+                    // Each cell passed the policy on its own; the net may not
+                    if let Some(amount) = &amount {
+                        AmountPolicy::with_current(|policy| {
+                            policy.check_in(
+                                &amount.value().to_string(),
+                                amount.value(),
+                                amount.currency(),
+                            )
+                        })
+                        .map_err(|error| {
+                            let column =
+                                format!("{} and {}", self.label(debit), self.label(credit));
+                            at_line(error, line, &column, "")
+                        })?;
+                    }
+                    amount
+                }
+            },
+            (None, None) => None,
+        }
+        .ok_or_else(|| line_error(line, None, "", "Neither debit nor credit is set"))
+    }
+}
+
+/// Writes amounts as CSV records.
+///
+/// Each record holds the caller's fields first, then the money columns of
+/// the format: the value (or debit and credit), then the currency code if the
+/// format has a currency column. Header names come from named columns, or
+/// default to `amount`, `debit`, `credit` and `currency`.
+///
+/// Values are written without grouping, with the locale's decimal separator
+/// if the format has one, so a [`CsvReader`] with the same format reads them
+/// back.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::csv::{CsvFormat, CsvWriter};
+/// use typed_money::{Amount, USD};
+///
+/// let format = CsvFormat::new().with_currency_column("currency");
+/// let mut writer = CsvWriter::new(Vec::new(), format);
+/// writer.write_header(&["memo"])?;
+/// writer.write_amount(&["Coffee, large"], &Amount::<USD>::from_minor(-450))?;
+///
+/// let csv = String::from_utf8(writer.into_inner()).unwrap();
+/// assert_eq!(csv, "memo,amount,currency\n\"Coffee, large\",-4.50,USD\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CsvWriter<W> {
+    output: W,
+    format: CsvFormat,
+}
+
+impl<W: Write> CsvWriter<W> {
+    /// Creates a writer that writes records to `output`.
+    pub fn new(output: W, format: CsvFormat) -> Self {
+        Self { output, format }
+    }
+
+    /// Writes the header row: the names of the caller's fields, then the
+    /// money columns.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying writer.
+    pub fn write_header(&mut self, leading: &[&str]) -> io::Result<()> {
+        let name = |column: &Column, default: &str| match column {
+            Column::Name(name) => name.clone(),
+            Column::Index(_) => default.to_string(),
+        };
+        let mut fields: Vec<String> = leading.iter().map(|field| field.to_string()).collect();
+        match &self.format.money {
+            MoneyColumns::Value(column) => fields.push(name(column, "amount")),
+            MoneyColumns::DebitCredit { debit, credit } => {
+                fields.push(name(debit, "debit"));
+                fields.push(name(credit, "credit"));
+            }
+        }
+        if let Some(column) = &self.format.currency {
+            fields.push(name(column, "currency"));
+        }
+        self.write_record(&fields)
+    }
+
+    /// Writes a record holding `leading` followed by `amount`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying writer.
+    pub fn write_amount<C: Currency>(
+        &mut self,
+        leading: &[&str],
+        amount: &Amount<C>,
+    ) -> io::Result<()> {
+        self.write_money(leading, &amount.value().to_string(), C::CODE)
+    }
+
+    /// Writes a record holding `leading` followed by a dynamic amount.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying writer.
+    pub fn write_dyn_amount(&mut self, leading: &[&str], amount: &DynAmount) -> io::Result<()> {
+        self.write_money(leading, &amount.value().to_string(), amount.code())
+    }
+
+    /// Writes a record of raw fields, quoting them as needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying writer.
+    pub fn write_record<S: AsRef<str>>(&mut self, fields: &[S]) -> io::Result<()> {
+        let mut record = String::new();
+        for (index, field) in fields.iter().enumerate() {
+            if index > 0 {
+                record.push(self.format.delimiter);
+            }
+            let field = field.as_ref();
+            if field.contains([self.format.delimiter, '"', '\n', '\r']) {
+                record.push('"');
+                record.push_str(&field.replace('"', "\"\""));
+                record.push('"');
+            } else {
+                record.push_str(field);
+            }
+        }
+        record.push('\n');
+        self.output.write_all(record.as_bytes())
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.output.flush()
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+
+    fn write_money(&mut self, leading: &[&str], plain: &str, code: &str) -> io::Result<()> {
+        let number = |plain: &str| match &self.format.locale {
+            Some(locale) => plain.replace('.', &locale.decimal_separator().to_string()),
+            None => plain.to_string(),
+        };
+        let mut fields: Vec<String> = leading.iter().map(|field| field.to_string()).collect();
+        match &self.format.money {
+            MoneyColumns::Value(_) => fields.push(number(plain)),
+            MoneyColumns::DebitCredit { .. } => match plain.strip_prefix('-') {
+                Some(magnitude) => {
+                    fields.push(number(magnitude));
+                    fields.push(String::new());
+                }
+                None => {
+                    fields.push(String::new());
+                    fields.push(number(plain));
+                }
+            },
+        }
+        if self.format.currency.is_some() {
+            fields.push(code.to_string());
+        }
+        self.write_record(&fields)
+    }
+}
+
+/// Combines debit and credit into `credit - debit`; `None` if both are empty.
+fn net(debit: Option<Decimal>, credit: Option<Decimal>) -> Option<Decimal> {
+    match (debit, credit) {
+        (None, None) => None,
+        (Some(debit), None) => Some(-debit),
+        (None, Some(credit)) => Some(credit),
+        (Some(debit), Some(credit)) => Some(credit - debit),
+    }
+}
+
+/// Builds a parse error for `input`, the offending text, at a line and
+/// optionally a column.
+fn line_error(line: usize, column: Option<&str>, input: &str, reason: &str) -> MoneyError {
+    MoneyError::ParseError {
+        input: input.to_string(),
+        expected_currency: None,
+        reason: match column {
+            Some(column) => format!("line {}, {}: {}", line, column, reason),
+            None => format!("line {}: {}", line, reason),
+        },
+    }
+}
+
+fn io_error(line: usize, error: &io::Error) -> MoneyError {
+    line_error(line, None, "", &format!("I/O error: {}", error))
+}
+
+/// Adds the line and column to an error from parsing the cell `text`.
+///
+/// Currency mismatches keep their variant; other errors become parse errors
+/// carrying the original message and the cell text.
+fn at_line(error: MoneyError, line: usize, column: &str, text: &str) -> MoneyError {
+    match error {
+        MoneyError::CurrencyMismatch {
+            expected,
+            found,
+            context,
+        } => MoneyError::CurrencyMismatch {
+            expected,
+            found,
+            context: format!("line {}, {}: {}", line, column, context),
+        },
+        MoneyError::ParseError {
+            input,
+            expected_currency,
+            reason,
+        } => MoneyError::ParseError {
+            input,
+            expected_currency,
+            reason: format!("line {}, {}: {}", line, column, reason),
+        },
+        other => MoneyError::ParseError {
+            input: text.to_string(),
+            expected_currency: other.currency(),
+            reason: format!("line {}, {}: {}", line, column, other),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EUR, USD};
+
+    fn reader(data: &str, format: CsvFormat) -> CsvReader<&[u8]> {
+        CsvReader::new(data.as_bytes(), format).unwrap()
+    }
+
+    fn reason(error: MoneyError) -> String {
+        error.to_string()
+    }
+
+    #[test]
+    fn test_records_follow_rfc_4180() {
+        let data = "\u{feff}a,b,c\r\n\"x, y\",\"say \"\"hi\"\"\",\"two\nlines\"\r\n\r\n1,,3\n";
+        let mut reader = reader(data, CsvFormat::new().with_value_column(0));
+        assert_eq!(
+            reader.headers(),
+            Some(&["a".to_string(), "b".into(), "c".into()][..])
+        );
+        assert_eq!(
+            reader.read_record().unwrap().unwrap(),
+            (
+                2,
+                vec!["x, y".to_string(), "say \"hi\"".into(), "two\nlines".into()]
+            )
+        );
+        assert_eq!(
+            reader.read_record().unwrap().unwrap(),
+            (5, vec!["1".to_string(), String::new(), "3".into()])
+        );
+        assert!(reader.read_record().is_none());
+    }
+
+    #[test]
+    fn test_unterminated_quote_ends_stream() {
+        let mut reader = reader("amount\n\"12\n", CsvFormat::new());
+        let error = reader.read_record().unwrap().unwrap_err();
+        assert!(reason(error).contains("line 2: Unterminated quoted field"));
+        assert!(reader.read_record().is_none());
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_a_row_error() {
+        let data = b"amount\n1.00\n\"2\xFF\n3\"\n4.00\n";
+        let mut reader = CsvReader::new(&data[..], CsvFormat::new()).unwrap();
+        let rows: Vec<_> = reader.amounts::<USD>().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].as_ref().unwrap().amount.to_minor(), 100);
+        let error = rows[1].clone().unwrap_err();
+        assert!(matches!(
+            &error,
+            MoneyError::ParseError { input, .. } if input == "\"2\u{fffd}"
+        ));
+        assert!(reason(error).contains("line 3: Invalid UTF-8"));
+        assert_eq!(rows[2].as_ref().unwrap().line, 5);
+    }
+
+    #[test]
+    fn test_max_record_len_ends_stream() {
+        let format = CsvFormat::new().with_max_record_len(10);
+        let mut long = reader("amount\n1.00\n\"2\n3\n4\n5\n6\n7\n8\n", format);
+        assert_eq!(long.read_record().unwrap().unwrap().1, ["1.00"]);
+        let error = long.read_record().unwrap().unwrap_err();
+        assert!(reason(error).contains("line 3: Record longer than 10 bytes"));
+        assert!(long.read_record().is_none());
+
+        // A record of exactly the limit is fine
+        let format = CsvFormat::new().with_max_record_len(7);
+        let mut exact = reader("amount\n123.45\n", format);
+        assert_eq!(exact.read_record().unwrap().unwrap().1, ["123.45"]);
+
+        // No limit at all
+        let format = CsvFormat::new().with_max_record_len(usize::MAX);
+        let mut unlimited = reader("amount\n123.45\n", format);
+        assert_eq!(unlimited.read_record().unwrap().unwrap().1, ["123.45"]);
+    }
+
+    #[test]
+    fn test_typed_amounts_with_line_numbers() {
+        let data = "memo,amount\nok,12.34\nbad,12.3.4\nempty,\neuro,€5\nshort\n";
+        let mut reader = reader(data, CsvFormat::new());
+        let rows: Vec<_> = reader.amounts::<USD>().collect();
+        assert_eq!(rows.len(), 5);
+
+        let ok = rows[0].as_ref().unwrap();
+        assert_eq!((ok.line, ok.amount.to_minor()), (2, 1234));
+        assert_eq!(ok.fields, ["ok", "12.34"]);
+
+        let bad = rows[1].clone().unwrap_err();
+        assert!(matches!(&bad, MoneyError::ParseError { input, .. } if input == "12.3.4"));
+        let bad = reason(bad);
+        assert!(bad.contains("line 3, column 'amount'"), "{}", bad);
+        assert!(
+            reason(rows[2].clone().unwrap_err()).contains("line 4, column 'amount': Empty string")
+        );
+        assert!(matches!(
+            &rows[3],
+            Err(MoneyError::CurrencyMismatch { found: "EUR", context, .. }) if context.starts_with("line 5")
+        ));
+        assert!(
+            reason(rows[4].clone().unwrap_err()).contains("line 6, column 'amount': Missing field")
+        );
+    }
+
+    #[test]
+    fn test_debit_credit_columns() {
+        let data = "debit,credit\n10.00,\n,2.50\n1.00,3.00\n,\n";
+        let format = CsvFormat::new().with_debit_credit_columns("Debit", "CREDIT");
+        let rows: Vec<_> = reader(data, format).amounts::<USD>().collect();
+        let minors: Vec<_> = rows[..3]
+            .iter()
+            .map(|row| row.as_ref().unwrap().amount.to_minor())
+            .collect();
+        assert_eq!(minors, [-1000, 250, 200]);
+        assert!(reason(rows[3].clone().unwrap_err()).contains("line 5: Neither debit nor credit"));
+    }
+
+    #[test]
+    fn test_locale_and_index_columns() {
+        let de = Locale::parse("de-DE").unwrap();
+        let data = "2024-01-02;Miete;-1.234,56;EUR\n2024-01-03;Gehalt;2500;eur\n";
+        let format = CsvFormat::new()
+            .without_header()
+            .with_delimiter(';')
+            .with_value_column(2)
+            .with_currency_column(3)
+            .with_locale(&de);
+        let rows: Vec<_> = reader(data, format)
+            .amounts::<EUR>()
+            .map(|row| row.unwrap().amount.to_minor())
+            .collect();
+        assert_eq!(rows, [-123_456, 250_000]);
+
+        let error = CsvReader::new("".as_bytes(), CsvFormat::new().without_header())
+            .err()
+            .unwrap();
+        assert!(reason(error).contains("no header"));
+        let error = CsvReader::new("value\n".as_bytes(), CsvFormat::new())
+            .err()
+            .unwrap();
+        assert!(reason(error).contains("line 1: Column 'amount' not found"));
+    }
+
+    #[test]
+    fn test_dyn_amounts() {
+        let data = "amount,currency\n$5,CAD\n12,usd\n¥100,\n€1,USD\n1,ZZZ\n";
+        let format = CsvFormat::new()
+            .with_currency_column("currency")
+            .with_hints(CurrencyHints::new().with_region("JP"));
+        let rows: Vec<_> = reader(data, format).dyn_amounts().collect();
+        let ok: Vec<_> = rows[..3]
+            .iter()
+            .map(|row| row.as_ref().unwrap().amount.to_string())
+            .collect();
+        assert_eq!(ok, ["5 CAD", "12 USD", "100 JPY"]);
+        assert!(reason(rows[3].clone().unwrap_err()).contains("line 5, column 'amount'"));
+        assert!(reason(rows[4].clone().unwrap_err())
+            .contains("line 6, column 'currency': Unknown currency code"));
+
+        let data = "debit,credit\n€4,\n1 USD,2 EUR\n";
+        let format = CsvFormat::new().with_debit_credit_columns("debit", "credit");
+        let rows: Vec<_> = reader(data, format).dyn_amounts().collect();
+        assert_eq!(rows[0].as_ref().unwrap().amount.to_string(), "-4 EUR");
+        assert!(reason(rows[1].clone().unwrap_err()).contains("Debit in USD but credit in EUR"));
+    }
+
+    #[test]
+    fn test_policy_applies_to_cells() {
+        let rows: Vec<_> = AmountPolicy::new().without_negatives().scope(|| {
+            reader("amount\n-1\n1\n", CsvFormat::new())
+                .amounts::<USD>()
+                .collect()
+        });
+        assert!(rows[0].is_err());
+        assert!(rows[1].is_ok());
+
+        // The net of debit and credit is checked as well
+        let format = CsvFormat::new().with_debit_credit_columns("debit", "credit");
+        let rows: Vec<_> = AmountPolicy::new().without_negatives().scope(|| {
+            reader("debit,credit\n10,\n,10\n", format.clone())
+                .amounts::<USD>()
+                .collect()
+        });
+        let error = rows[0].clone().unwrap_err();
+        assert!(matches!(&error, MoneyError::ParseError { input, .. } if input == "-10"));
+        assert!(reason(error).contains("line 2, column 'debit' and column 'credit'"));
+        assert_eq!(rows[1].as_ref().unwrap().amount.to_minor(), 1000);
+
+        let rows: Vec<_> = AmountPolicy::new().without_negatives().scope(|| {
+            reader("debit,credit\n10 USD,\n", format.clone())
+                .dyn_amounts()
+                .collect()
+        });
+        assert!(reason(rows[0].clone().unwrap_err())
+            .contains("line 2, column 'debit' and column 'credit': allow_negative"));
+    }
+
+    #[test]
+    fn test_writer_round_trips_through_reader() {
+        let de = Locale::parse("de-DE").unwrap();
+        let format = CsvFormat::new()
+            .with_delimiter(';')
+            .with_debit_credit_columns("Soll", "Haben")
+            .with_currency_column("Währung")
+            .with_locale(&de);
+        let mut writer = CsvWriter::new(Vec::new(), format.clone());
+        writer.write_header(&["Text"]).unwrap();
+        writer
+            .write_amount(&["Miete; Januar"], &Amount::<EUR>::from_minor(-123_456))
+            .unwrap();
+        writer
+            .write_dyn_amount(&["Zins"], &Amount::<EUR>::from_minor(5).into())
+            .unwrap();
+        let csv = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(
+            csv,
+            "Text;Soll;Haben;Währung\n\"Miete; Januar\";1234,56;;EUR\nZins;;0,05;EUR\n"
+        );
+
+        let minors: Vec<_> = reader(&csv, format)
+            .amounts::<EUR>()
+            .map(|row| row.unwrap().amount.to_minor())
+            .collect();
+        assert_eq!(minors, [-123_456, 5]);
+    }
+}
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// United Arab Emirates Dirham (AED)
@@ -26,6 +26,8 @@ impl Currency for AED {
 
     // Rich metadata
     const NAME: &'static str = "United Arab Emirates Dirham";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dirham", "dirhams");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("fils", "fils");
     const COUNTRY: &'static str = "United Arab Emirates";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Argentine Peso (ARS)
@@ -26,6 +26,8 @@ impl Currency for ARS {
 
     // Rich metadata
     const NAME: &'static str = "Argentine Peso";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("peso", "pesos");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centavo", "centavos");
     const COUNTRY: &'static str = "Argentina";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
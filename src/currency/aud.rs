@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Australian Dollar (AUD)
@@ -23,9 +23,12 @@ impl Currency for AUD {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "AUD";
     const SYMBOL: &'static str = "A$";
+    const NARROW_SYMBOL: &'static str = "$";
 
     // Rich metadata
     const NAME: &'static str = "Australian Dollar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dollar", "dollars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "Australia";
     const REGION: &'static str = "Oceania";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +40,7 @@ impl Currency for AUD {
     const DECIMAL_SEPARATOR: char = '.';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::Before;
     const SPACE_BETWEEN: bool = false;
+    const CASH_ROUNDING_INCREMENT: u32 = 5;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::High;
 }
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Bitcoin Cash (BCH)
@@ -27,6 +27,8 @@ impl Currency for BCH {
 
     // Cryptocurrency metadata
     const NAME: &'static str = "Bitcoin Cash";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("bitcoin cash", "bitcoin cash");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("satoshi", "satoshis");
     const COUNTRY: &'static str = "Global";
     const REGION: &'static str = "Worldwide";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Cryptocurrency;
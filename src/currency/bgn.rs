@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Bulgarian Lev (BGN)
@@ -26,6 +26,8 @@ impl Currency for BGN {
 
     // Rich metadata
     const NAME: &'static str = "Bulgarian Lev";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("lev", "leva");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("stotinka", "stotinki");
     const COUNTRY: &'static str = "Bulgaria";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
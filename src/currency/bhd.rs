@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Bahraini Dinar (BHD)
@@ -26,6 +26,8 @@ impl Currency for BHD {
 
     // Rich metadata
     const NAME: &'static str = "Bahraini Dinar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dinar", "dinars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("fils", "fils");
     const COUNTRY: &'static str = "Bahrain";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
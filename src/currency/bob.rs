@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Bolivian Boliviano (BOB)
 ///
@@ -25,6 +25,8 @@ impl Currency for BOB {
 
     // Rich metadata
     const NAME: &'static str = "Bolivian Boliviano";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("boliviano", "bolivianos");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centavo", "centavos");
     const COUNTRY: &'static str = "Bolivia";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Brazilian Real (BRL)
@@ -26,6 +26,8 @@ impl Currency for BRL {
 
     // Rich metadata
     const NAME: &'static str = "Brazilian Real";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("real", "reais");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centavo", "centavos");
     const COUNTRY: &'static str = "Brazil";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
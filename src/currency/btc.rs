@@ -1,6 +1,6 @@
 //! Bitcoin currency implementation.
 
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Bitcoin
 ///
@@ -19,9 +19,12 @@ impl Currency for BTC {
     const DECIMALS: u8 = 8;
     const CODE: &'static str = "BTC";
     const SYMBOL: &'static str = "₿";
+    const ALIASES: &'static [&'static str] = &["XBT"];
 
     // Cryptocurrency metadata
     const NAME: &'static str = "Bitcoin";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("bitcoin", "bitcoins");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("satoshi", "satoshis");
     const COUNTRY: &'static str = "Global";
     const REGION: &'static str = "Worldwide";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Cryptocurrency;
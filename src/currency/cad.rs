@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Canadian Dollar (CAD)
@@ -23,9 +23,12 @@ impl Currency for CAD {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "CAD";
     const SYMBOL: &'static str = "C$";
+    const NARROW_SYMBOL: &'static str = "$";
 
     // Rich metadata
     const NAME: &'static str = "Canadian Dollar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dollar", "dollars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "Canada";
     const REGION: &'static str = "North America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +40,7 @@ impl Currency for CAD {
     const DECIMAL_SEPARATOR: char = '.';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::Before;
     const SPACE_BETWEEN: bool = false;
+    const CASH_ROUNDING_INCREMENT: u32 = 5;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::High;
 }
@@ -49,6 +53,7 @@ mod tests {
     #[test]
     fn test_cad_currency_properties() {
         assert_eq!(CAD::DECIMALS, 2);
+        assert_eq!(CAD::CASH_ROUNDING_INCREMENT, 5);
         assert_eq!(CAD::CODE, "CAD");
         assert_eq!(CAD::SYMBOL, "C$");
     }
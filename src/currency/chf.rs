@@ -1,5 +1,5 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
-use crate::Currency;
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
+use crate::{Currency, RoundingMode};
 
 /// Swiss Franc (CHF)
 ///
@@ -26,6 +26,8 @@ impl Currency for CHF {
 
     // Rich metadata
     const NAME: &'static str = "Swiss Franc";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("franc", "francs");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centime", "centimes");
     const COUNTRY: &'static str = "Switzerland";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +39,8 @@ impl Currency for CHF {
     const DECIMAL_SEPARATOR: char = '.';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::After;
     const SPACE_BETWEEN: bool = true;
+    const CASH_ROUNDING_INCREMENT: u32 = 5;
+    const DEFAULT_ROUNDING: RoundingMode = RoundingMode::HalfUp;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::High;
 }
@@ -49,6 +53,7 @@ mod tests {
     #[test]
     fn test_chf_currency_properties() {
         assert_eq!(CHF::DECIMALS, 2);
+        assert_eq!(CHF::CASH_ROUNDING_INCREMENT, 5);
         assert_eq!(CHF::CODE, "CHF");
         assert_eq!(CHF::SYMBOL, "CHF");
     }
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Chilean Peso (CLP)
@@ -26,6 +26,7 @@ impl Currency for CLP {
 
     // Rich metadata
     const NAME: &'static str = "Chilean Peso";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("peso", "pesos");
     const COUNTRY: &'static str = "Chile";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
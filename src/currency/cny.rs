@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Chinese Yuan (CNY)
@@ -23,9 +23,12 @@ impl Currency for CNY {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "CNY";
     const SYMBOL: &'static str = "¥";
+    const ALIASES: &'static [&'static str] = &["RMB"];
 
     // Rich metadata
     const NAME: &'static str = "Chinese Yuan";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("yuan", "yuan");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("fen", "fen");
     const COUNTRY: &'static str = "China";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
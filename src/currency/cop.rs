@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Colombian Peso (COP)
 ///
@@ -25,6 +25,8 @@ impl Currency for COP {
 
     // Rich metadata
     const NAME: &'static str = "Colombian Peso";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("peso", "pesos");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centavo", "centavos");
     const COUNTRY: &'static str = "Colombia";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
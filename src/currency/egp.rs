@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Egyptian Pound (EGP)
@@ -26,6 +26,8 @@ impl Currency for EGP {
 
     // Rich metadata
     const NAME: &'static str = "Egyptian Pound";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("pound", "pounds");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("piastre", "piastres");
     const COUNTRY: &'static str = "Egypt";
     const REGION: &'static str = "Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
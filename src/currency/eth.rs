@@ -1,6 +1,6 @@
 //! Ethereum currency implementation.
 
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Ethereum
 ///
@@ -22,6 +22,8 @@ impl Currency for ETH {
 
     // Cryptocurrency metadata
     const NAME: &'static str = "Ethereum";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("ether", "ether");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("wei", "wei");
     const COUNTRY: &'static str = "Global";
     const REGION: &'static str = "Worldwide";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Cryptocurrency;
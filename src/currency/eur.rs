@@ -1,6 +1,7 @@
 //! Euro currency implementation.
 
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
+use crate::RoundingMode;
 
 /// Euro
 ///
@@ -22,6 +23,8 @@ impl Currency for EUR {
 
     // Rich metadata
     const NAME: &'static str = "Euro";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("euro", "euros");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "European Union";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -33,6 +36,7 @@ impl Currency for EUR {
     const DECIMAL_SEPARATOR: char = ',';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::After;
     const SPACE_BETWEEN: bool = true;
+    const DEFAULT_ROUNDING: RoundingMode = RoundingMode::HalfUp;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::High;
 }
@@ -1,6 +1,6 @@
 //! British Pound Sterling currency implementation.
 
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// British Pound Sterling
 ///
@@ -22,6 +22,8 @@ impl Currency for GBP {
 
     // Rich metadata
     const NAME: &'static str = "British Pound Sterling";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("pound", "pounds");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("penny", "pence");
     const COUNTRY: &'static str = "United Kingdom";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Ghanaian Cedi (GHS)
 ///
@@ -25,6 +25,8 @@ impl Currency for GHS {
 
     // Rich metadata
     const NAME: &'static str = "Ghanaian Cedi";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("cedi", "cedis");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("pesewa", "pesewas");
     const COUNTRY: &'static str = "Ghana";
     const REGION: &'static str = "West Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
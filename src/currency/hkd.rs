@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Hong Kong Dollar (HKD)
@@ -23,9 +23,12 @@ impl Currency for HKD {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "HKD";
     const SYMBOL: &'static str = "HK$";
+    const NARROW_SYMBOL: &'static str = "$";
 
     // Rich metadata
     const NAME: &'static str = "Hong Kong Dollar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dollar", "dollars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "Hong Kong";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
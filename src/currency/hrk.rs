@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Croatian Kuna (HRK)
@@ -26,6 +26,8 @@ impl Currency for HRK {
 
     // Rich metadata (Historical currency - replaced by EUR in 2023)
     const NAME: &'static str = "Croatian Kuna";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("kuna", "kuna");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("lipa", "lipa");
     const COUNTRY: &'static str = "Croatia";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Hungarian Forint (HUF)
@@ -26,6 +26,7 @@ impl Currency for HUF {
 
     // Rich metadata
     const NAME: &'static str = "Hungarian Forint";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("forint", "forints");
     const COUNTRY: &'static str = "Hungary";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +38,7 @@ impl Currency for HUF {
     const DECIMAL_SEPARATOR: char = ',';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::After;
     const SPACE_BETWEEN: bool = true;
+    const CASH_ROUNDING_INCREMENT: u32 = 5;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::High;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::Low;
 }
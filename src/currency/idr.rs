@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Indonesian Rupiah (IDR)
@@ -26,6 +26,7 @@ impl Currency for IDR {
 
     // Rich metadata
     const NAME: &'static str = "Indonesian Rupiah";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("rupiah", "rupiahs");
     const COUNTRY: &'static str = "Indonesia";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
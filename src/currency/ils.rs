@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Israeli Shekel (ILS)
@@ -23,9 +23,12 @@ impl Currency for ILS {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "ILS";
     const SYMBOL: &'static str = "₪";
+    const ALIASES: &'static [&'static str] = &["NIS"];
 
     // Rich metadata
     const NAME: &'static str = "Israeli Shekel";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("shekel", "shekels");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("agora", "agorot");
     const COUNTRY: &'static str = "Israel";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +40,7 @@ impl Currency for ILS {
     const DECIMAL_SEPARATOR: char = '.';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::Before;
     const SPACE_BETWEEN: bool = false;
+    const CASH_ROUNDING_INCREMENT: u32 = 10;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Medium;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::Medium;
 }
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, Grouping, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Indian Rupee (INR)
@@ -26,6 +26,8 @@ impl Currency for INR {
 
     // Rich metadata
     const NAME: &'static str = "Indian Rupee";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("rupee", "rupees");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("paisa", "paise");
     const COUNTRY: &'static str = "India";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -35,6 +37,7 @@ impl Currency for INR {
     const ISO_4217_NUMBER: u16 = 356;
     const THOUSANDS_SEPARATOR: char = ',';
     const DECIMAL_SEPARATOR: char = '.';
+    const GROUPING: Grouping = Grouping::INDIAN;
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::Before;
     const SPACE_BETWEEN: bool = false;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Medium;
@@ -65,4 +68,12 @@ mod tests {
         assert_eq!(amount.to_major_floor(), 100);
         assert_eq!(amount.to_minor(), 10050);
     }
+
+    #[test]
+    fn test_inr_lakh_crore_grouping() {
+        assert_eq!(INR::GROUPING, Grouping::INDIAN);
+        let amount = Amount::<INR>::from_major(123_456_789);
+        assert_eq!(amount.format_native(), "₹12,34,56,789.00");
+        assert_eq!(amount.format_locale("en_US"), "₹12,34,56,789.00 INR");
+    }
 }
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Jordanian Dinar (JOD)
@@ -26,6 +26,8 @@ impl Currency for JOD {
 
     // Rich metadata
     const NAME: &'static str = "Jordanian Dinar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dinar", "dinars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("fils", "fils");
     const COUNTRY: &'static str = "Jordan";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
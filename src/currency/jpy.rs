@@ -1,6 +1,6 @@
 //! Japanese Yen currency implementation.
 
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Japanese Yen
 ///
@@ -22,6 +22,7 @@ impl Currency for JPY {
 
     // Rich metadata
     const NAME: &'static str = "Japanese Yen";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("yen", "yen");
     const COUNTRY: &'static str = "Japan";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
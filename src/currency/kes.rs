@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Kenyan Shilling (KES)
 ///
@@ -25,6 +25,8 @@ impl Currency for KES {
 
     // Rich metadata
     const NAME: &'static str = "Kenyan Shilling";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("shilling", "shillings");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "Kenya";
     const REGION: &'static str = "East Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// South Korean Won (KRW)
@@ -26,6 +26,7 @@ impl Currency for KRW {
 
     // Rich metadata
     const NAME: &'static str = "South Korean Won";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("won", "won");
     const COUNTRY: &'static str = "South Korea";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
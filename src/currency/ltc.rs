@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Litecoin (LTC)
@@ -27,6 +27,8 @@ impl Currency for LTC {
 
     // Cryptocurrency metadata
     const NAME: &'static str = "Litecoin";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("litecoin", "litecoins");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("litoshi", "litoshis");
     const COUNTRY: &'static str = "Global";
     const REGION: &'static str = "Worldwide";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Cryptocurrency;
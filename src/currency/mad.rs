@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Moroccan Dirham (MAD)
 ///
@@ -25,6 +25,8 @@ impl Currency for MAD {
 
     // Rich metadata
     const NAME: &'static str = "Moroccan Dirham";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dirham", "dirhams");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centime", "centimes");
     const COUNTRY: &'static str = "Morocco";
     const REGION: &'static str = "North Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -4,6 +4,10 @@ use std::fmt;
 
 /// Type of currency (Fiat, Cryptocurrency, or Commodity).
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum CurrencyType {
     /// Traditional government-issued currency (USD, EUR, GBP, etc.)
     Fiat,
@@ -25,6 +29,10 @@ impl fmt::Display for CurrencyType {
 
 /// Position of currency symbol relative to the amount.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum SymbolPosition {
     /// Symbol appears before the amount (e.g., "$100")
     Before,
@@ -41,8 +49,143 @@ impl fmt::Display for SymbolPosition {
     }
 }
 
+/// Digit grouping pattern for the integer part of an amount.
+///
+/// The `primary` group size applies to the rightmost group and the
+/// `secondary` size to every group further left. Most locales group in
+/// threes; Indian numbering uses a primary size of 3 and a secondary size of
+/// 2 (12,34,567).
+///
+/// `min_grouping` is the minimum number of digits that must appear left of
+/// the rightmost group before any separator is written. Spanish, for
+/// example, uses 2: 1234 stays ungrouped while 12.345 is grouped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Grouping {
+    primary: u8,
+    secondary: u8,
+    min_grouping: u8,
+}
+
+impl Grouping {
+    /// Groups of three digits (1,234,567).
+    pub const THOUSANDS: Grouping = Grouping::new(3, 3);
+
+    /// Indian lakh/crore grouping (12,34,567).
+    pub const INDIAN: Grouping = Grouping::new(3, 2);
+
+    /// No grouping at all (1234567).
+    pub const NONE: Grouping = Grouping::new(0, 0);
+
+    /// Creates a grouping pattern. A `primary` size of zero disables grouping;
+    /// a `secondary` size of zero repeats the primary size.
+    pub const fn new(primary: u8, secondary: u8) -> Self {
+        let secondary = if secondary == 0 { primary } else { secondary };
+        Self {
+            primary,
+            secondary,
+            min_grouping: 1,
+        }
+    }
+
+    /// Sets the minimum number of leading digits required before grouping
+    /// applies. Values below 1 are treated as 1.
+    pub const fn with_min_grouping(mut self, min_grouping: u8) -> Self {
+        self.min_grouping = if min_grouping == 0 { 1 } else { min_grouping };
+        self
+    }
+
+    /// Returns the size of the rightmost group.
+    pub const fn primary(&self) -> u8 {
+        self.primary
+    }
+
+    /// Returns the size of the groups left of the rightmost one.
+    pub const fn secondary(&self) -> u8 {
+        self.secondary
+    }
+
+    /// Returns the minimum number of digits left of the rightmost group
+    /// needed before separators are inserted.
+    pub const fn min_grouping(&self) -> u8 {
+        self.min_grouping
+    }
+
+    /// Inserts `separator` between the groups of a string of integer digits.
+    pub(crate) fn apply(&self, digits: &str, separator: char) -> String {
+        let len = digits.chars().count();
+        let primary = usize::from(self.primary);
+        if primary == 0 || len < primary + usize::from(self.min_grouping) {
+            return digits.to_string();
+        }
+
+        let secondary = usize::from(self.secondary);
+        let mut result = String::with_capacity(digits.len() + len / secondary.max(1));
+        for (i, ch) in digits.chars().enumerate() {
+            let remaining = len - i;
+            if i > 0 && remaining >= primary && (remaining - primary) % secondary == 0 {
+                result.push(separator);
+            }
+            result.push(ch);
+        }
+        result
+    }
+}
+
+impl Default for Grouping {
+    fn default() -> Self {
+        Self::THOUSANDS
+    }
+}
+
+/// Singular and plural name of a currency unit (e.g., "penny" and "pence").
+///
+/// An empty name means the unit has no name on record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnitName {
+    singular: &'static str,
+    plural: &'static str,
+}
+
+impl UnitName {
+    /// A unit without a name.
+    pub const NONE: UnitName = UnitName::new("", "");
+
+    /// Creates a unit name from its singular and plural forms.
+    pub const fn new(singular: &'static str, plural: &'static str) -> Self {
+        Self { singular, plural }
+    }
+
+    /// Returns the singular form (e.g., "dollar").
+    pub const fn singular(&self) -> &'static str {
+        self.singular
+    }
+
+    /// Returns the plural form (e.g., "dollars").
+    pub const fn plural(&self) -> &'static str {
+        self.plural
+    }
+
+    /// Returns whether the unit has no name.
+    pub const fn is_empty(&self) -> bool {
+        self.singular.is_empty()
+    }
+
+    /// Returns the form used for `count` units in English.
+    pub const fn for_count(&self, count: u128) -> &'static str {
+        if count == 1 {
+            self.singular
+        } else {
+            self.plural
+        }
+    }
+}
+
 /// Volatility rating for a currency.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum VolatilityRating {
     /// Low volatility (stable currencies, major fiat)
     Low,
@@ -64,6 +207,10 @@ impl fmt::Display for VolatilityRating {
 
 /// Liquidity rating for a currency.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum LiquidityRating {
     /// Low liquidity (rarely traded, limited markets)
     Low,
@@ -100,6 +247,40 @@ mod tests {
         assert_eq!(SymbolPosition::After.to_string(), "After");
     }
 
+    #[test]
+    fn test_grouping_apply() {
+        assert_eq!(Grouping::THOUSANDS.apply("1234567", ','), "1,234,567");
+        assert_eq!(Grouping::THOUSANDS.apply("123", ','), "123");
+        assert_eq!(Grouping::INDIAN.apply("123456789", ','), "12,34,56,789");
+        assert_eq!(Grouping::INDIAN.apply("12345", ','), "12,345");
+        assert_eq!(Grouping::NONE.apply("1234567", ','), "1234567");
+        assert_eq!(Grouping::new(4, 0).apply("123456789", ' '), "1 2345 6789");
+    }
+
+    #[test]
+    fn test_grouping_min_grouping() {
+        let spanish = Grouping::THOUSANDS.with_min_grouping(2);
+        assert_eq!(spanish.min_grouping(), 2);
+        assert_eq!(spanish.apply("1234", '.'), "1234");
+        assert_eq!(spanish.apply("12345", '.'), "12.345");
+        assert_eq!(spanish.apply("1234567", '.'), "1.234.567");
+        assert_eq!(
+            Grouping::THOUSANDS.with_min_grouping(0),
+            Grouping::THOUSANDS
+        );
+        assert_ne!(spanish, Grouping::THOUSANDS);
+    }
+
+    #[test]
+    fn test_unit_name() {
+        let penny = UnitName::new("penny", "pence");
+        assert_eq!(penny.for_count(1), "penny");
+        assert_eq!(penny.for_count(0), "pence");
+        assert_eq!(penny.for_count(2), "pence");
+        assert!(!penny.is_empty());
+        assert!(UnitName::NONE.is_empty());
+    }
+
     #[test]
     fn test_volatility_rating_display() {
         assert_eq!(VolatilityRating::Low.to_string(), "Low");
@@ -128,4 +309,25 @@ mod tests {
         assert!(LiquidityRating::Low < LiquidityRating::Medium);
         assert!(LiquidityRating::Medium < LiquidityRating::High);
     }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_metadata_enums_serde() {
+        assert_eq!(
+            serde_json::to_string(&CurrencyType::Cryptocurrency).unwrap(),
+            r#""Cryptocurrency""#
+        );
+        assert_eq!(
+            serde_json::from_str::<SymbolPosition>(r#""After""#).unwrap(),
+            SymbolPosition::After
+        );
+        assert_eq!(
+            serde_json::from_str::<VolatilityRating>(r#""High""#).unwrap(),
+            VolatilityRating::High
+        );
+        assert_eq!(
+            serde_json::from_str::<LiquidityRating>(r#""Low""#).unwrap(),
+            LiquidityRating::Low
+        );
+    }
 }
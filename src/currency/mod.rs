@@ -188,6 +188,7 @@
 //! ```
 
 mod metadata;
+mod registry;
 mod trait_def;
 
 // Built-in currencies
@@ -304,7 +305,11 @@ mod xcu;
 mod xni;
 mod xzn;
 
-pub use metadata::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+pub use metadata::{
+    CurrencyType, Grouping, LiquidityRating, SymbolPosition, UnitName, VolatilityRating,
+};
+pub(crate) use registry::{currency_of_region, with_known_currencies};
+pub use registry::{find_currency, known_currencies, register_currency, CurrencyInfo};
 pub use trait_def::Currency;
 
 // Core currencies
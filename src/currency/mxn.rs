@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Mexican Peso (MXN)
@@ -26,6 +26,8 @@ impl Currency for MXN {
 
     // Rich metadata
     const NAME: &'static str = "Mexican Peso";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("peso", "pesos");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centavo", "centavos");
     const COUNTRY: &'static str = "Mexico";
     const REGION: &'static str = "North America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
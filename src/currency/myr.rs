@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Malaysian Ringgit (MYR)
@@ -26,6 +26,8 @@ impl Currency for MYR {
 
     // Rich metadata
     const NAME: &'static str = "Malaysian Ringgit";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("ringgit", "ringgit");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("sen", "sen");
     const COUNTRY: &'static str = "Malaysia";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Nigerian Naira (NGN)
 ///
@@ -25,6 +25,8 @@ impl Currency for NGN {
 
     // Rich metadata
     const NAME: &'static str = "Nigerian Naira";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("naira", "naira");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("kobo", "kobo");
     const COUNTRY: &'static str = "Nigeria";
     const REGION: &'static str = "West Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
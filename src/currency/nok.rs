@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Norwegian Krone (NOK)
@@ -26,6 +26,8 @@ impl Currency for NOK {
 
     // Rich metadata
     const NAME: &'static str = "Norwegian Krone";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("krone", "kroner");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("øre", "øre");
     const COUNTRY: &'static str = "Norway";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +39,7 @@ impl Currency for NOK {
     const DECIMAL_SEPARATOR: char = ',';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::After;
     const SPACE_BETWEEN: bool = true;
+    const CASH_ROUNDING_INCREMENT: u32 = 100;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::Medium;
 }
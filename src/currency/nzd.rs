@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// New Zealand Dollar (NZD)
@@ -24,9 +24,12 @@ impl Currency for NZD {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "NZD";
     const SYMBOL: &'static str = "NZ$";
+    const NARROW_SYMBOL: &'static str = "$";
 
     // Rich metadata
     const NAME: &'static str = "New Zealand Dollar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dollar", "dollars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "New Zealand";
     const REGION: &'static str = "Oceania";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -38,6 +41,7 @@ impl Currency for NZD {
     const DECIMAL_SEPARATOR: char = '.';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::Before;
     const SPACE_BETWEEN: bool = false;
+    const CASH_ROUNDING_INCREMENT: u32 = 10;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::High;
 }
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Omani Rial (OMR)
@@ -26,6 +26,8 @@ impl Currency for OMR {
 
     // Rich metadata
     const NAME: &'static str = "Omani Rial";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("rial", "rials");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("baisa", "baisa");
     const COUNTRY: &'static str = "Oman";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
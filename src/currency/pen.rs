@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Peruvian Sol (PEN)
 ///
@@ -25,6 +25,8 @@ impl Currency for PEN {
 
     // Rich metadata
     const NAME: &'static str = "Peruvian Sol";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("sol", "soles");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("céntimo", "céntimos");
     const COUNTRY: &'static str = "Peru";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
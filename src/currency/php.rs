@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Philippine Peso (PHP)
@@ -26,6 +26,8 @@ impl Currency for PHP {
 
     // Rich metadata
     const NAME: &'static str = "Philippine Peso";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("peso", "pesos");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centavo", "centavos");
     const COUNTRY: &'static str = "Philippines";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
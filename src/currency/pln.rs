@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Polish Złoty (PLN)
@@ -26,6 +26,8 @@ impl Currency for PLN {
 
     // Rich metadata
     const NAME: &'static str = "Polish Złoty";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("złoty", "złotys");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("grosz", "groszy");
     const COUNTRY: &'static str = "Poland";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
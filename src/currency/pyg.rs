@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Paraguayan Guarani (PYG)
 ///
@@ -25,6 +25,7 @@ impl Currency for PYG {
 
     // Rich metadata
     const NAME: &'static str = "Paraguayan Guarani";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("guaraní", "guaraníes");
     const COUNTRY: &'static str = "Paraguay";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
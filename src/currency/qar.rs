@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Qatari Riyal (QAR)
@@ -26,6 +26,8 @@ impl Currency for QAR {
 
     // Rich metadata
     const NAME: &'static str = "Qatari Riyal";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("riyal", "riyals");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("dirham", "dirhams");
     const COUNTRY: &'static str = "Qatar";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -0,0 +1,424 @@
+//! Process-wide registry of the currencies recognized in text.
+//!
+//! Every built-in currency is known from the start. Applications add their
+//! own with [`register_currency`], after which the parser recognizes their
+//! codes, symbols and aliases too.
+
+use super::*;
+use std::iter::Chain;
+use std::slice;
+use std::sync::RwLock;
+
+/// How a currency is written in text: its code, symbols and aliases.
+///
+/// Obtained from [`CurrencyInfo::of`], [`find_currency`] or
+/// [`known_currencies`].
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{CurrencyInfo, CNY};
+///
+/// let info = CurrencyInfo::of::<CNY>();
+/// assert_eq!(info.code(), "CNY");
+/// assert_eq!(info.symbol(), "¥");
+/// assert_eq!(info.aliases(), &["RMB"]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CurrencyInfo {
+    code: &'static str,
+    symbol: &'static str,
+    narrow_symbol: &'static str,
+    aliases: &'static [&'static str],
+    name: &'static str,
+    decimals: u8,
+}
+
+impl CurrencyInfo {
+    /// Collects the text representations of `C`.
+    pub const fn of<C: Currency>() -> Self {
+        Self {
+            code: C::CODE,
+            symbol: C::SYMBOL,
+            narrow_symbol: C::NARROW_SYMBOL,
+            aliases: C::ALIASES,
+            name: C::NAME,
+            decimals: C::DECIMALS,
+        }
+    }
+
+    /// Returns the currency code (e.g., "USD").
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the currency symbol (e.g., "$").
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Returns the narrow symbol, or an empty string if it is the same as
+    /// the symbol.
+    pub const fn narrow_symbol(&self) -> &'static str {
+        self.narrow_symbol
+    }
+
+    /// Returns other codes or abbreviations of the currency (e.g., "RMB").
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Returns the English name of the currency, if on record.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the number of decimal places.
+    pub const fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the code, symbols and aliases that identify the currency.
+    pub(crate) fn markers(&self) -> impl Iterator<Item = &'static str> {
+        [self.code, self.symbol, self.narrow_symbol]
+            .into_iter()
+            .chain(self.aliases.iter().copied())
+            .filter(|marker| !marker.is_empty())
+    }
+}
+
+/// Built-in currencies, major ones first so that they win ties on shared
+/// symbols such as "$" and "¥".
+const BUILTIN: &[CurrencyInfo] = &[
+    CurrencyInfo::of::<USD>(),
+    CurrencyInfo::of::<EUR>(),
+    CurrencyInfo::of::<GBP>(),
+    CurrencyInfo::of::<JPY>(),
+    CurrencyInfo::of::<BTC>(),
+    CurrencyInfo::of::<ETH>(),
+    CurrencyInfo::of::<AAVE>(),
+    CurrencyInfo::of::<ADA>(),
+    CurrencyInfo::of::<BCH>(),
+    CurrencyInfo::of::<DOT>(),
+    CurrencyInfo::of::<LINK>(),
+    CurrencyInfo::of::<LTC>(),
+    CurrencyInfo::of::<UNI>(),
+    CurrencyInfo::of::<XRP>(),
+    CurrencyInfo::of::<BUSD>(),
+    CurrencyInfo::of::<DAI>(),
+    CurrencyInfo::of::<USDC>(),
+    CurrencyInfo::of::<USDT>(),
+    CurrencyInfo::of::<COMP>(),
+    CurrencyInfo::of::<MKR>(),
+    CurrencyInfo::of::<SUSHI>(),
+    CurrencyInfo::of::<YFI>(),
+    CurrencyInfo::of::<AUD>(),
+    CurrencyInfo::of::<CAD>(),
+    CurrencyInfo::of::<CHF>(),
+    CurrencyInfo::of::<NZD>(),
+    CurrencyInfo::of::<CNY>(),
+    CurrencyInfo::of::<HKD>(),
+    CurrencyInfo::of::<INR>(),
+    CurrencyInfo::of::<KRW>(),
+    CurrencyInfo::of::<SGD>(),
+    CurrencyInfo::of::<TWD>(),
+    CurrencyInfo::of::<CZK>(),
+    CurrencyInfo::of::<DKK>(),
+    CurrencyInfo::of::<HUF>(),
+    CurrencyInfo::of::<NOK>(),
+    CurrencyInfo::of::<PLN>(),
+    CurrencyInfo::of::<SEK>(),
+    CurrencyInfo::of::<ARS>(),
+    CurrencyInfo::of::<BRL>(),
+    CurrencyInfo::of::<CLP>(),
+    CurrencyInfo::of::<MXN>(),
+    CurrencyInfo::of::<AED>(),
+    CurrencyInfo::of::<EGP>(),
+    CurrencyInfo::of::<ILS>(),
+    CurrencyInfo::of::<SAR>(),
+    CurrencyInfo::of::<TRY>(),
+    CurrencyInfo::of::<ZAR>(),
+    CurrencyInfo::of::<BGN>(),
+    CurrencyInfo::of::<HRK>(),
+    CurrencyInfo::of::<RON>(),
+    CurrencyInfo::of::<RSD>(),
+    CurrencyInfo::of::<UAH>(),
+    CurrencyInfo::of::<IDR>(),
+    CurrencyInfo::of::<MYR>(),
+    CurrencyInfo::of::<PHP>(),
+    CurrencyInfo::of::<THB>(),
+    CurrencyInfo::of::<VND>(),
+    CurrencyInfo::of::<BOB>(),
+    CurrencyInfo::of::<COP>(),
+    CurrencyInfo::of::<PEN>(),
+    CurrencyInfo::of::<PYG>(),
+    CurrencyInfo::of::<UYU>(),
+    CurrencyInfo::of::<GHS>(),
+    CurrencyInfo::of::<KES>(),
+    CurrencyInfo::of::<MAD>(),
+    CurrencyInfo::of::<NGN>(),
+    CurrencyInfo::of::<TND>(),
+    CurrencyInfo::of::<BHD>(),
+    CurrencyInfo::of::<JOD>(),
+    CurrencyInfo::of::<KWD>(),
+    CurrencyInfo::of::<OMR>(),
+    CurrencyInfo::of::<QAR>(),
+    CurrencyInfo::of::<XAG>(),
+    CurrencyInfo::of::<XAU>(),
+    CurrencyInfo::of::<XDI>(),
+    CurrencyInfo::of::<XPD>(),
+    CurrencyInfo::of::<XPT>(),
+    CurrencyInfo::of::<XAL>(),
+    CurrencyInfo::of::<XCU>(),
+    CurrencyInfo::of::<XNI>(),
+    CurrencyInfo::of::<XZN>(),
+];
+
+/// Currency in use in each region (ISO 3166 code), for the built-in
+/// currencies.
+const REGIONS: &[(&str, &str)] = &[
+    ("AE", "AED"),
+    ("AR", "ARS"),
+    ("AT", "EUR"),
+    ("AU", "AUD"),
+    ("BE", "EUR"),
+    ("BG", "EUR"),
+    ("BH", "BHD"),
+    ("BO", "BOB"),
+    ("BR", "BRL"),
+    ("CA", "CAD"),
+    ("CH", "CHF"),
+    ("CL", "CLP"),
+    ("CN", "CNY"),
+    ("CO", "COP"),
+    ("CY", "EUR"),
+    ("CZ", "CZK"),
+    ("DE", "EUR"),
+    ("DK", "DKK"),
+    ("EC", "USD"),
+    ("EE", "EUR"),
+    ("EG", "EGP"),
+    ("ES", "EUR"),
+    ("FI", "EUR"),
+    ("FR", "EUR"),
+    ("GB", "GBP"),
+    ("GH", "GHS"),
+    ("GR", "EUR"),
+    ("HK", "HKD"),
+    ("HR", "EUR"),
+    ("HU", "HUF"),
+    ("ID", "IDR"),
+    ("IE", "EUR"),
+    ("IL", "ILS"),
+    ("IN", "INR"),
+    ("IT", "EUR"),
+    ("JO", "JOD"),
+    ("JP", "JPY"),
+    ("KE", "KES"),
+    ("KR", "KRW"),
+    ("KW", "KWD"),
+    ("LI", "CHF"),
+    ("LT", "EUR"),
+    ("LU", "EUR"),
+    ("LV", "EUR"),
+    ("MA", "MAD"),
+    ("MT", "EUR"),
+    ("MX", "MXN"),
+    ("MY", "MYR"),
+    ("NG", "NGN"),
+    ("NL", "EUR"),
+    ("NO", "NOK"),
+    ("NZ", "NZD"),
+    ("OM", "OMR"),
+    ("PA", "USD"),
+    ("PE", "PEN"),
+    ("PH", "PHP"),
+    ("PL", "PLN"),
+    ("PR", "USD"),
+    ("PT", "EUR"),
+    ("PY", "PYG"),
+    ("QA", "QAR"),
+    ("RO", "RON"),
+    ("RS", "RSD"),
+    ("SA", "SAR"),
+    ("SE", "SEK"),
+    ("SG", "SGD"),
+    ("SI", "EUR"),
+    ("SK", "EUR"),
+    ("SV", "USD"),
+    ("TH", "THB"),
+    ("TN", "TND"),
+    ("TR", "TRY"),
+    ("TW", "TWD"),
+    ("UA", "UAH"),
+    ("US", "USD"),
+    ("UY", "UYU"),
+    ("VN", "VND"),
+    ("ZA", "ZAR"),
+];
+
+static REGISTERED: RwLock<Vec<CurrencyInfo>> = RwLock::new(Vec::new());
+
+/// Makes a custom currency known process-wide, so that parsing recognizes
+/// its code, symbols and aliases, and reports them as a
+/// [`MoneyError::CurrencyMismatch`](crate::MoneyError::CurrencyMismatch)
+/// when an amount of another currency is expected.
+///
+/// Registering a currency whose code is already known has no effect.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{find_currency, register_currency, Amount, Currency, MoneyError, USD};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Points;
+///
+/// impl Currency for Points {
+///     const DECIMALS: u8 = 0;
+///     const CODE: &'static str = "PTS";
+///     const SYMBOL: &'static str = "✪";
+/// }
+///
+/// register_currency::<Points>();
+/// assert_eq!(find_currency("PTS").map(|info| info.symbol()), Some("✪"));
+///
+/// let err = Amount::<USD>::parse("✪120").unwrap_err();
+/// assert!(matches!(err, MoneyError::CurrencyMismatch { found: "PTS", .. }));
+/// ```
+pub fn register_currency<C: Currency>() {
+    let info = CurrencyInfo::of::<C>();
+    let mut registered = REGISTERED
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let known = |code: &str| {
+        BUILTIN.iter().any(|known| known.code == code)
+            || registered.iter().any(|known| known.code == code)
+    };
+    if !known(info.code) {
+        registered.push(info);
+    }
+}
+
+/// Returns every known currency: the built-in ones, then those added with
+/// [`register_currency`] in registration order.
+pub fn known_currencies() -> Vec<CurrencyInfo> {
+    with_known_currencies(|currencies| currencies.copied().collect())
+}
+
+/// Calls `f` with every known currency, in the order of
+/// [`known_currencies`], without collecting them.
+///
+/// The registry stays locked while `f` runs, so `f` must not register
+/// currencies.
+pub(crate) fn with_known_currencies<R>(
+    f: impl for<'a> FnOnce(Chain<slice::Iter<'a, CurrencyInfo>, slice::Iter<'a, CurrencyInfo>>) -> R,
+) -> R {
+    let registered = REGISTERED
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(BUILTIN.iter().chain(registered.iter()))
+}
+
+/// Looks up a known currency by its code or one of its aliases, ignoring
+/// ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::find_currency;
+///
+/// assert_eq!(find_currency("eur").map(|info| info.name()), Some("Euro"));
+/// assert_eq!(find_currency("XBT").map(|info| info.code()), Some("BTC"));
+/// assert!(find_currency("€").is_none());
+/// ```
+pub fn find_currency(code: &str) -> Option<CurrencyInfo> {
+    with_known_currencies(|mut currencies| {
+        currencies
+            .find(|info| {
+                info.code.eq_ignore_ascii_case(code)
+                    || info
+                        .aliases
+                        .iter()
+                        .any(|alias| alias.eq_ignore_ascii_case(code))
+            })
+            .copied()
+    })
+}
+
+/// Returns the code of the currency in use in `region` (e.g. "CAD" for
+/// "CA"), ignoring ASCII case.
+pub(crate) fn currency_of_region(region: &str) -> Option<&'static str> {
+    REGIONS
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(region))
+        .map(|(_, code)| *code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Tokens;
+
+    impl Currency for Tokens {
+        const DECIMALS: u8 = 0;
+        const CODE: &'static str = "TKN";
+        const SYMBOL: &'static str = "Ŧ";
+        const ALIASES: &'static [&'static str] = &["TOK"];
+    }
+
+    #[test]
+    fn test_builtin_codes_are_unique() {
+        for (index, info) in BUILTIN.iter().enumerate() {
+            assert!(
+                BUILTIN[index + 1..]
+                    .iter()
+                    .all(|other| other.code != info.code),
+                "{} listed twice",
+                info.code
+            );
+        }
+    }
+
+    #[test]
+    fn test_regions_are_sorted_and_known() {
+        assert!(REGIONS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for (region, code) in REGIONS {
+            assert!(BUILTIN.iter().any(|info| info.code == *code), "{}", region);
+        }
+        assert_eq!(currency_of_region("ca"), Some("CAD"));
+        assert_eq!(currency_of_region("AQ"), None);
+    }
+
+    #[test]
+    fn test_find_builtin() {
+        assert_eq!(find_currency("USD"), Some(CurrencyInfo::of::<USD>()));
+        assert_eq!(find_currency("rmb").map(|info| info.code()), Some("CNY"));
+        assert_eq!(find_currency("NIS").map(|info| info.code()), Some("ILS"));
+        assert!(find_currency("$").is_none());
+        assert!(find_currency("").is_none());
+    }
+
+    #[test]
+    fn test_register_currency() {
+        register_currency::<Tokens>();
+        register_currency::<Tokens>();
+        // Built-in codes cannot be taken over
+        register_currency::<USD>();
+
+        let known = known_currencies();
+        assert_eq!(known.iter().filter(|info| info.code() == "TKN").count(), 1);
+        assert_eq!(known.iter().filter(|info| info.code() == "USD").count(), 1);
+        assert_eq!(find_currency("tok").map(|info| info.symbol()), Some("Ŧ"));
+    }
+
+    #[test]
+    fn test_markers_skip_empty() {
+        let markers: Vec<_> = CurrencyInfo::of::<AUD>().markers().collect();
+        assert_eq!(markers, ["AUD", "A$", "$"]);
+        let markers: Vec<_> = CurrencyInfo::of::<BTC>().markers().collect();
+        assert_eq!(markers, ["BTC", "₿", "XBT"]);
+    }
+}
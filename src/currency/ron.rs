@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Romanian Leu (RON)
@@ -26,6 +26,8 @@ impl Currency for RON {
 
     // Rich metadata
     const NAME: &'static str = "Romanian Leu";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("leu", "lei");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("ban", "bani");
     const COUNTRY: &'static str = "Romania";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
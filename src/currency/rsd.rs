@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Serbian Dinar (RSD)
@@ -26,6 +26,8 @@ impl Currency for RSD {
 
     // Rich metadata
     const NAME: &'static str = "Serbian Dinar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dinar", "dinars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("para", "para");
     const COUNTRY: &'static str = "Serbia";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Saudi Riyal (SAR)
@@ -26,6 +26,8 @@ impl Currency for SAR {
 
     // Rich metadata
     const NAME: &'static str = "Saudi Riyal";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("riyal", "riyals");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("halala", "halalas");
     const COUNTRY: &'static str = "Saudi Arabia";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Swedish Krona (SEK)
@@ -26,6 +26,8 @@ impl Currency for SEK {
 
     // Rich metadata
     const NAME: &'static str = "Swedish Krona";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("krona", "kronor");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("öre", "öre");
     const COUNTRY: &'static str = "Sweden";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -37,6 +39,7 @@ impl Currency for SEK {
     const DECIMAL_SEPARATOR: char = ',';
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::After;
     const SPACE_BETWEEN: bool = true;
+    const CASH_ROUNDING_INCREMENT: u32 = 100;
     const VOLATILITY_RATING: VolatilityRating = VolatilityRating::Low;
     const LIQUIDITY_RATING: LiquidityRating = LiquidityRating::Medium;
 }
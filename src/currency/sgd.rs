@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Singapore Dollar (SGD)
@@ -23,9 +23,12 @@ impl Currency for SGD {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "SGD";
     const SYMBOL: &'static str = "S$";
+    const NARROW_SYMBOL: &'static str = "$";
 
     // Rich metadata
     const NAME: &'static str = "Singapore Dollar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dollar", "dollars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "Singapore";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Thai Baht (THB)
@@ -26,6 +26,8 @@ impl Currency for THB {
 
     // Rich metadata
     const NAME: &'static str = "Thai Baht";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("baht", "baht");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("satang", "satang");
     const COUNTRY: &'static str = "Thailand";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
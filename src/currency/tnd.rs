@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Tunisian Dinar (TND)
 ///
@@ -25,6 +25,8 @@ impl Currency for TND {
 
     // Rich metadata
     const NAME: &'static str = "Tunisian Dinar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dinar", "dinars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("millime", "millimes");
     const COUNTRY: &'static str = "Tunisia";
     const REGION: &'static str = "North Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
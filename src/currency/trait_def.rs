@@ -2,7 +2,10 @@
 
 use std::fmt;
 
-use super::metadata::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::metadata::{
+    CurrencyType, Grouping, LiquidityRating, SymbolPosition, UnitName, VolatilityRating,
+};
+use crate::RoundingMode;
 
 /// Trait representing a currency type.
 ///
@@ -72,6 +75,13 @@ pub trait Currency: Copy + Clone + fmt::Debug + 'static {
     /// Full currency name (e.g., "US Dollar", "Euro", "Bitcoin")
     const NAME: &'static str = "";
 
+    /// English name of the major unit (e.g., dollar/dollars), used when
+    /// spelling out amounts in words
+    const MAJOR_UNIT_NAME: UnitName = UnitName::NONE;
+
+    /// English name of the minor unit (e.g., cent/cents)
+    const MINOR_UNIT_NAME: UnitName = UnitName::NONE;
+
     /// Primary country or region that issues this currency
     const COUNTRY: &'static str = "";
 
@@ -89,18 +99,41 @@ pub trait Currency: Copy + Clone + fmt::Debug + 'static {
 
     // === FORMATTING METADATA ===
 
+    /// Short form of the symbol for contexts where the currency is clear
+    /// (e.g., "$" for AUD's "A$"). Empty means the same as `SYMBOL`.
+    const NARROW_SYMBOL: &'static str = "";
+
+    /// Other codes or abbreviations that identify this currency in text
+    /// (e.g., "RMB" for CNY). Recognized by `Amount::parse`.
+    const ALIASES: &'static [&'static str] = &[];
+
     /// Character used to separate thousands (e.g., ',' for US, '.' for EU)
     const THOUSANDS_SEPARATOR: char = ',';
 
     /// Character used as decimal separator (e.g., '.' for US, ',' for EU)
     const DECIMAL_SEPARATOR: char = '.';
 
+    /// Digit grouping pattern for the integer part (e.g., `Grouping::INDIAN`
+    /// for INR's 12,34,567)
+    const GROUPING: Grouping = Grouping::THOUSANDS;
+
     /// Position of currency symbol relative to the amount
     const SYMBOL_POSITION: SymbolPosition = SymbolPosition::Before;
 
     /// Whether to include a space between symbol and amount
     const SPACE_BETWEEN: bool = false;
 
+    // === ROUNDING METADATA ===
+
+    /// Smallest increment used when settling in cash, in minor units
+    /// (e.g., 5 for CHF's 5 Rappen). Defaults to one minor unit.
+    const CASH_ROUNDING_INCREMENT: u32 = 1;
+
+    /// Rounding mode used when no mode is given explicitly (e.g., by
+    /// `Amount::round_default` or a `MoneyContext` without a rounding override).
+    /// Defaults to banker's rounding.
+    const DEFAULT_ROUNDING: RoundingMode = RoundingMode::HalfEven;
+
     // === HISTORICAL METADATA ===
 
     /// Year when this currency was introduced
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Turkish Lira (TRY)
@@ -26,6 +26,8 @@ impl Currency for TRY {
 
     // Rich metadata
     const NAME: &'static str = "Turkish Lira";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("lira", "liras");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("kuruş", "kuruş");
     const COUNTRY: &'static str = "Turkey";
     const REGION: &'static str = "Middle East";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Ukrainian Hryvnia (UAH)
@@ -26,6 +26,8 @@ impl Currency for UAH {
 
     // Rich metadata
     const NAME: &'static str = "Ukrainian Hryvnia";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("hryvnia", "hryvnias");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("kopiyka", "kopiyky");
     const COUNTRY: &'static str = "Ukraine";
     const REGION: &'static str = "Europe";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,6 +1,6 @@
 //! United States Dollar currency implementation.
 
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// United States Dollar
 ///
@@ -22,6 +22,8 @@ impl Currency for USD {
 
     // Rich metadata (users can provide this data)
     const NAME: &'static str = "US Dollar";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dollar", "dollars");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "United States";
     const REGION: &'static str = "North America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{Currency, CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 
 /// Uruguayan Peso (UYU)
 ///
@@ -22,9 +22,12 @@ impl Currency for UYU {
     const DECIMALS: u8 = 2;
     const CODE: &'static str = "UYU";
     const SYMBOL: &'static str = "$U";
+    const NARROW_SYMBOL: &'static str = "$";
 
     // Rich metadata
     const NAME: &'static str = "Uruguayan Peso";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("peso", "pesos");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("centésimo", "centésimos");
     const COUNTRY: &'static str = "Uruguay";
     const REGION: &'static str = "South America";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// Vietnamese Dong (VND)
@@ -26,6 +26,7 @@ impl Currency for VND {
 
     // Rich metadata
     const NAME: &'static str = "Vietnamese Dong";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("dong", "dong");
     const COUNTRY: &'static str = "Vietnam";
     const REGION: &'static str = "Asia";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -1,4 +1,4 @@
-use super::{CurrencyType, LiquidityRating, SymbolPosition, VolatilityRating};
+use super::{CurrencyType, LiquidityRating, SymbolPosition, UnitName, VolatilityRating};
 use crate::Currency;
 
 /// South African Rand (ZAR)
@@ -26,6 +26,8 @@ impl Currency for ZAR {
 
     // Rich metadata
     const NAME: &'static str = "South African Rand";
+    const MAJOR_UNIT_NAME: UnitName = UnitName::new("rand", "rand");
+    const MINOR_UNIT_NAME: UnitName = UnitName::new("cent", "cents");
     const COUNTRY: &'static str = "South Africa";
     const REGION: &'static str = "Africa";
     const CURRENCY_TYPE: CurrencyType = CurrencyType::Fiat;
@@ -84,10 +84,23 @@ use std::fmt;
 /// ```
 pub type MoneyResult<T> = Result<T, MoneyError>;
 
+/// A currency code; spelled as an alias so that serde does not try to borrow
+/// it from the input.
+type Code = &'static str;
+
 /// Errors that can occur during monetary operations.
 ///
 /// All error variants include context to help diagnose and fix issues.
+///
+/// With `serde_support`, errors serialize as an object tagged with the
+/// variant name, e.g. `{"kind":"ConversionRateMissing","from":"USD","to":"EUR"}`.
+/// Deserializing rejects currency codes that are not known currencies.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind")
+)]
 pub enum MoneyError {
     /// Attempted to perform an operation between incompatible currencies.
     ///
@@ -95,9 +108,17 @@ pub enum MoneyError {
     /// prevents this at compile time.
     CurrencyMismatch {
         /// The expected currency code
-        expected: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        expected: Code,
         /// The actual currency code found
-        found: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        found: Code,
         /// Additional context about the operation
         context: String,
     },
@@ -105,9 +126,17 @@ pub enum MoneyError {
     /// No conversion rate available for the requested currency pair.
     ConversionRateMissing {
         /// The source currency code
-        from: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        from: Code,
         /// The target currency code
-        to: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        to: Code,
     },
 
     /// Precision would be lost in the operation.
@@ -116,7 +145,11 @@ pub enum MoneyError {
     /// than the currency supports.
     PrecisionError {
         /// The currency code
-        currency: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        currency: Code,
         /// Expected precision (number of decimal places)
         expected: u8,
         /// Actual precision found
@@ -130,7 +163,11 @@ pub enum MoneyError {
         /// Description of what makes the amount invalid
         reason: String,
         /// The currency code if available
-        currency: Option<&'static str>,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(default, deserialize_with = "crate::serde::deserialize_optional_code")
+        )]
+        currency: Option<Code>,
     },
 
     /// Failed to parse a string into a monetary amount.
@@ -138,7 +175,11 @@ pub enum MoneyError {
         /// The input string that failed to parse
         input: String,
         /// The expected currency code
-        expected_currency: Option<&'static str>,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(default, deserialize_with = "crate::serde::deserialize_optional_code")
+        )]
+        expected_currency: Option<Code>,
         /// Description of why parsing failed
         reason: String,
     },
@@ -146,7 +187,11 @@ pub enum MoneyError {
     /// Rounding operation failed.
     RoundingError {
         /// The currency code
-        currency: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        currency: Code,
         /// Description of what went wrong
         reason: String,
     },
@@ -164,7 +209,11 @@ pub enum MoneyError {
         /// The operation that caused overflow
         operation: String,
         /// The currency code
-        currency: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        currency: Code,
     },
 
     /// Arithmetic underflow occurred.
@@ -172,7 +221,11 @@ pub enum MoneyError {
         /// The operation that caused underflow
         operation: String,
         /// The currency code
-        currency: &'static str,
+        #[cfg_attr(
+            feature = "serde_support",
+            serde(deserialize_with = "crate::serde::deserialize_code")
+        )]
+        currency: Code,
     },
 }
 
@@ -479,4 +532,47 @@ mod tests {
         let debug_str = format!("{:?}", error);
         assert!(debug_str.contains("InvalidAmount"));
     }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_error_serde_round_trip() {
+        let errors = [
+            MoneyError::ConversionRateMissing {
+                from: "USD",
+                to: "EUR",
+            },
+            MoneyError::PrecisionError {
+                currency: "USD",
+                expected: 2,
+                actual: 3,
+                suggestion: "Round first".to_string(),
+            },
+            MoneyError::ParseError {
+                input: "abc".to_string(),
+                expected_currency: None,
+                reason: "Invalid number".to_string(),
+            },
+            MoneyError::Overflow {
+                operation: "add".to_string(),
+                currency: "JPY",
+            },
+        ];
+        for error in errors {
+            let json = serde_json::to_string(&error).unwrap();
+            assert_eq!(serde_json::from_str::<MoneyError>(&json).unwrap(), error);
+        }
+
+        let json = serde_json::to_string(&MoneyError::ConversionRateMissing {
+            from: "USD",
+            to: "EUR",
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"ConversionRateMissing","from":"USD","to":"EUR"}"#
+        );
+
+        let unknown = r#"{"kind":"Overflow","operation":"add","currency":"XYZ"}"#;
+        assert!(serde_json::from_str::<MoneyError>(unknown).is_err());
+    }
 }
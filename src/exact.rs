@@ -0,0 +1,671 @@
+//! Exact amounts and rates with deferred rounding.
+//!
+//! [`ExactAmount<C>`] and [`ExactRate<From, To>`] are the [`Rational`]
+//! counterparts of [`Amount<C>`] and [`Rate<From, To>`]. Division,
+//! conversion and rate inversion never round. The value is rounded to the
+//! currency's precision once, when it is turned back into an `Amount<C>` with
+//! [`to_amount`](ExactAmount::to_amount).
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::{Amount, RoundingMode, USD};
+//!
+//! // Split a bill three ways and add the shares back up
+//! let bill = Amount::<USD>::from_minor(10_000).to_exact();
+//! let share = bill / 3;
+//! assert_eq!(share * 3, bill);
+//!
+//! assert_eq!(share.to_amount(RoundingMode::HalfEven)?.to_minor(), 3_333);
+//! # Ok::<(), typed_money::MoneyError>(())
+//! ```
+
+use crate::{Amount, Currency, MoneyError, MoneyResult, Rate, Rational, RoundingMode};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An amount of currency `C` held as an exact fraction.
+///
+/// Only amounts of the same currency can be combined, exactly as with
+/// [`Amount`]. Arithmetic panics on `i128` overflow; the `checked_*` methods
+/// return [`MoneyError::Overflow`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExactAmount<C: Currency> {
+    value: Rational,
+    _currency: PhantomData<C>,
+}
+
+impl<C: Currency> ExactAmount<C> {
+    /// Creates an exact amount from a rational number of major units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{ExactAmount, Rational, USD};
+    ///
+    /// let third = ExactAmount::<USD>::new(Rational::new(1, 3));
+    /// assert_eq!(third * 3, ExactAmount::from_major(1));
+    /// ```
+    pub const fn new(value: Rational) -> Self {
+        Self {
+            value,
+            _currency: PhantomData,
+        }
+    }
+
+    /// Creates an exact amount from whole major units.
+    pub fn from_major(amount: i64) -> Self {
+        Self::new(Rational::from(amount))
+    }
+
+    /// Creates an exact amount from minor units (e.g., cents for USD).
+    pub fn from_minor(amount: i64) -> Self {
+        Self::new(Rational::new(
+            i128::from(amount),
+            10_i128.pow(C::DECIMALS.into()),
+        ))
+    }
+
+    /// Returns the exact value in major units.
+    #[inline]
+    pub const fn value(&self) -> &Rational {
+        &self.value
+    }
+
+    /// Returns `true` if the amount is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns `true` if the amount is strictly negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
+
+    /// Rounds to the currency's precision and returns a regular amount.
+    ///
+    /// This is the only step at which an exact amount loses precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the rounded value does not fit in
+    /// the decimal backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{ExactAmount, RoundingMode, USD};
+    ///
+    /// let share = ExactAmount::<USD>::from_minor(100) / 8; // $0.125
+    /// assert_eq!(share.to_amount(RoundingMode::HalfUp)?.to_minor(), 13);
+    /// assert_eq!(share.to_amount(RoundingMode::HalfEven)?.to_minor(), 12);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_amount(&self, mode: RoundingMode) -> MoneyResult<Amount<C>> {
+        self.value
+            .to_decimal(C::DECIMALS.into(), mode)
+            .map(Amount::new)
+            .map_err(|_| overflow::<C>("rounding"))
+    }
+
+    /// Returns `self + other`, or an error on overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the result does not fit in `i128`.
+    pub fn checked_add(&self, other: &Self) -> MoneyResult<Self> {
+        self.value
+            .checked_add(&other.value)
+            .map(Self::new)
+            .ok_or_else(|| overflow::<C>("addition"))
+    }
+
+    /// Returns `self - other`, or an error on overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the result does not fit in `i128`.
+    pub fn checked_sub(&self, other: &Self) -> MoneyResult<Self> {
+        self.value
+            .checked_sub(&other.value)
+            .map(Self::new)
+            .ok_or_else(|| overflow::<C>("subtraction"))
+    }
+
+    /// Multiplies by an exact factor, or returns an error on overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the result does not fit in `i128`.
+    pub fn checked_mul(&self, factor: &Rational) -> MoneyResult<Self> {
+        self.value
+            .checked_mul(factor)
+            .map(Self::new)
+            .ok_or_else(|| overflow::<C>("multiplication"))
+    }
+
+    /// Divides by an exact divisor without rounding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the divisor is zero and
+    /// [`MoneyError::Overflow`] if the result does not fit in `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{ExactAmount, Rational, USD};
+    ///
+    /// let total = ExactAmount::<USD>::from_major(10);
+    /// let third = total.checked_div(&Rational::from(3))?;
+    /// assert_eq!(third.checked_mul(&Rational::from(3))?, total);
+    ///
+    /// assert!(total.checked_div(&Rational::ZERO).is_err());
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn checked_div(&self, divisor: &Rational) -> MoneyResult<Self> {
+        if divisor.is_zero() {
+            return Err(MoneyError::InvalidAmount {
+                reason: "Division by zero".to_string(),
+                currency: Some(C::CODE),
+            });
+        }
+        self.value
+            .checked_div(divisor)
+            .map(Self::new)
+            .ok_or_else(|| overflow::<C>("division"))
+    }
+
+    /// Converts to another currency without rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in `i128`. For a non-panicking
+    /// version, use [`try_convert`](Self::try_convert).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, ExactRate, RoundingMode, EUR, USD};
+    ///
+    /// let rate = ExactRate::<USD, EUR>::new(17, 20); // 0.85
+    /// let usd = Amount::<USD>::from_major(100).to_exact();
+    ///
+    /// // There and back again loses nothing
+    /// let eur = usd.convert(&rate);
+    /// assert_eq!(eur.convert(&rate.inverse()), usd);
+    /// assert_eq!(eur.to_amount(RoundingMode::HalfEven)?.to_major_floor(), 85);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn convert<To: Currency>(&self, rate: &ExactRate<C, To>) -> ExactAmount<To> {
+        expect_exact(self.try_convert(rate))
+    }
+
+    /// Converts to another currency without rounding, or returns an error on
+    /// overflow.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the result does not fit in `i128`.
+    pub fn try_convert<To: Currency>(
+        &self,
+        rate: &ExactRate<C, To>,
+    ) -> MoneyResult<ExactAmount<To>> {
+        self.value
+            .checked_mul(&rate.rate)
+            .map(ExactAmount::new)
+            .ok_or_else(|| overflow::<To>("conversion"))
+    }
+}
+
+impl<C: Currency> Amount<C> {
+    /// Tries to convert this amount into an [`ExactAmount`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the value has too many digits for
+    /// an `i128` fraction. This cannot happen with the `rust_decimal` backend.
+    pub fn try_to_exact(&self) -> MoneyResult<ExactAmount<C>> {
+        Rational::try_from_decimal(self.value())
+            .map(ExactAmount::new)
+            .map_err(|_| overflow::<C>("exact conversion"))
+    }
+
+    /// Converts this amount into an [`ExactAmount`] for deferred rounding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value has too many digits for an `i128` fraction, which
+    /// cannot happen with the `rust_decimal` backend. For a non-panicking
+    /// version, use [`try_to_exact`](Self::try_to_exact).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, RoundingMode, USD};
+    ///
+    /// let price = Amount::<USD>::from_minor(1999);
+    /// let exact = price.to_exact();
+    /// assert_eq!(exact.to_amount(RoundingMode::HalfEven)?, price);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_exact(&self) -> ExactAmount<C> {
+        expect_exact(self.try_to_exact())
+    }
+}
+
+impl<C: Currency> fmt::Display for ExactAmount<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.value, C::CODE)
+    }
+}
+
+impl<C: Currency> Add for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    fn add(self, other: ExactAmount<C>) -> ExactAmount<C> {
+        expect_exact(self.checked_add(&other))
+    }
+}
+
+impl<C: Currency> Sub for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    fn sub(self, other: ExactAmount<C>) -> ExactAmount<C> {
+        expect_exact(self.checked_sub(&other))
+    }
+}
+
+impl<C: Currency> Mul<i64> for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    fn mul(self, factor: i64) -> ExactAmount<C> {
+        expect_exact(self.checked_mul(&Rational::from(factor)))
+    }
+}
+
+impl<C: Currency> Mul<Rational> for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    fn mul(self, factor: Rational) -> ExactAmount<C> {
+        expect_exact(self.checked_mul(&factor))
+    }
+}
+
+impl<C: Currency> Div<i64> for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn div(self, divisor: i64) -> ExactAmount<C> {
+        expect_exact(self.checked_div(&Rational::from(divisor)))
+    }
+}
+
+impl<C: Currency> Div<Rational> for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    fn div(self, divisor: Rational) -> ExactAmount<C> {
+        expect_exact(self.checked_div(&divisor))
+    }
+}
+
+impl<C: Currency> Neg for ExactAmount<C> {
+    type Output = ExactAmount<C>;
+
+    fn neg(self) -> ExactAmount<C> {
+        self.value
+            .checked_neg()
+            .map(Self::new)
+            .unwrap_or_else(|| panic!("{}", overflow::<C>("negation")))
+    }
+}
+
+/// An exchange rate held as an exact fraction.
+///
+/// Unlike [`Rate::inverse`], which divides in decimal and rounds,
+/// [`ExactRate::inverse`] swaps numerator and denominator, so inverting twice
+/// always gives back the original rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExactRate<From: Currency, To: Currency> {
+    rate: Rational,
+    metadata_timestamp_unix_secs: Option<u64>,
+    metadata_source: Option<&'static str>,
+    _from: PhantomData<From>,
+    _to: PhantomData<To>,
+}
+
+impl<From: Currency, To: Currency> ExactRate<From, To> {
+    /// Tries to create a rate from an exact fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRate`] if the rate is zero or negative.
+    pub fn try_from_rational(rate: Rational) -> MoneyResult<Self> {
+        if !rate.is_positive() {
+            return Err(MoneyError::InvalidRate {
+                value: rate.to_string(),
+                reason: "Exchange rate must be positive and non-zero".to_string(),
+            });
+        }
+
+        Ok(Self {
+            rate,
+            metadata_timestamp_unix_secs: None,
+            metadata_source: None,
+            _from: PhantomData,
+            _to: PhantomData,
+        })
+    }
+
+    /// Tries to create the rate `numerator / denominator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRate`] if the fraction is invalid, zero or
+    /// negative.
+    pub fn try_new(numerator: i128, denominator: i128) -> MoneyResult<Self> {
+        let rate =
+            Rational::try_new(numerator, denominator).map_err(|err| MoneyError::InvalidRate {
+                value: format!("{}/{}", numerator, denominator),
+                reason: err.to_string(),
+            })?;
+        Self::try_from_rational(rate)
+    }
+
+    /// Creates the rate `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fraction is invalid, zero or negative. For a
+    /// non-panicking version, use [`try_new`](Self::try_new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{ExactRate, USD, JPY};
+    ///
+    /// let rate = ExactRate::<USD, JPY>::new(300, 2); // 1 USD = 150 JPY
+    /// assert_eq!(rate.value().numerator(), 150);
+    /// ```
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        Self::try_new(numerator, denominator).expect("Invalid exchange rate")
+    }
+
+    /// Tries to convert a decimal [`Rate`] into an exact rate, keeping its
+    /// metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRate`] if the rate has too many digits for
+    /// an `i128` fraction. This cannot happen with the `rust_decimal` backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{ExactRate, Rate, Rational, USD, EUR};
+    ///
+    /// let rate = Rate::<USD, EUR>::new(0.85).with_source("ECB");
+    /// let exact = ExactRate::try_from_rate(&rate)?;
+    /// assert_eq!(exact.value(), &Rational::new(17, 20));
+    /// assert_eq!(exact.source(), Some("ECB"));
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn try_from_rate(rate: &Rate<From, To>) -> MoneyResult<Self> {
+        let value =
+            Rational::try_from_decimal(rate.value()).map_err(|err| MoneyError::InvalidRate {
+                value: rate.value().to_string(),
+                reason: err.to_string(),
+            })?;
+        let mut exact = Self::try_from_rational(value)?;
+        exact.metadata_timestamp_unix_secs = rate.timestamp_unix_secs();
+        exact.metadata_source = rate.source();
+        Ok(exact)
+    }
+
+    /// Returns the exact rate value.
+    #[inline]
+    pub const fn value(&self) -> &Rational {
+        &self.rate
+    }
+
+    /// Returns the optional UNIX timestamp (seconds) metadata.
+    #[inline]
+    pub const fn timestamp_unix_secs(&self) -> Option<u64> {
+        self.metadata_timestamp_unix_secs
+    }
+
+    /// Returns the optional static source identifier metadata.
+    #[inline]
+    pub const fn source(&self) -> Option<&'static str> {
+        self.metadata_source
+    }
+
+    /// Returns a new `ExactRate` with the given UNIX timestamp (seconds)
+    /// metadata set.
+    #[inline]
+    pub const fn with_timestamp_unix_secs(mut self, timestamp_unix_secs: u64) -> Self {
+        self.metadata_timestamp_unix_secs = Some(timestamp_unix_secs);
+        self
+    }
+
+    /// Returns a new `ExactRate` with the given static source identifier set.
+    #[inline]
+    pub const fn with_source(mut self, source: &'static str) -> Self {
+        self.metadata_source = Some(source);
+        self
+    }
+
+    /// Returns the inverse rate (To -> From) without any loss of precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{ExactRate, USD, EUR};
+    ///
+    /// let usd_to_eur = ExactRate::<USD, EUR>::new(17, 20);
+    /// let eur_to_usd = usd_to_eur.inverse();
+    ///
+    /// assert_eq!(eur_to_usd.value().to_string(), "20/17");
+    /// assert_eq!(eur_to_usd.inverse(), usd_to_eur);
+    /// ```
+    pub fn inverse(&self) -> ExactRate<To, From> {
+        ExactRate {
+            rate: self
+                .rate
+                .checked_recip()
+                .expect("Exchange rates are always non-zero"),
+            metadata_timestamp_unix_secs: self.metadata_timestamp_unix_secs,
+            metadata_source: self.metadata_source,
+            _from: PhantomData,
+            _to: PhantomData,
+        }
+    }
+
+    /// Rounds the rate to `scale` decimal places and returns a decimal
+    /// [`Rate`], keeping its metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRate`] if the rounded rate is zero or does
+    /// not fit in the decimal backend.
+    pub fn to_rate(&self, scale: u32, mode: RoundingMode) -> MoneyResult<Rate<From, To>> {
+        let value = self
+            .rate
+            .to_decimal(scale, mode)
+            .map_err(|err| MoneyError::InvalidRate {
+                value: self.rate.to_string(),
+                reason: err.to_string(),
+            })?;
+        let mut rate = Rate::try_from_decimal(value)?;
+        if let Some(timestamp) = self.metadata_timestamp_unix_secs {
+            rate = rate.with_timestamp_unix_secs(timestamp);
+        }
+        if let Some(source) = self.metadata_source {
+            rate = rate.with_source(source);
+        }
+        Ok(rate)
+    }
+}
+
+impl<From: Currency, To: Currency> fmt::Display for ExactRate<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "1 {} = {} {}", From::CODE, self.rate, To::CODE)
+    }
+}
+
+fn overflow<C: Currency>(operation: &str) -> MoneyError {
+    MoneyError::Overflow {
+        operation: format!("exact {}", operation),
+        currency: C::CODE,
+    }
+}
+
+fn expect_exact<T>(result: MoneyResult<T>) -> T {
+    result.unwrap_or_else(|err| panic!("{}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EUR, JPY, USD};
+
+    #[test]
+    fn test_split_and_add_back_is_exact() {
+        let bill = Amount::<USD>::from_minor(10_000);
+        let share = bill.to_exact() / 7;
+        let total = (0..7).fold(ExactAmount::from_major(0), |sum, _| sum + share);
+        assert_eq!(total, bill.to_exact());
+        assert_eq!(share * 7, bill.to_exact());
+
+        // The decimal backend drifts on the same calculation
+        let share = bill / 7;
+        let total = (0..7).fold(Amount::<USD>::from_major(0), |sum, _| sum + share);
+        assert_ne!(total, bill);
+    }
+
+    #[test]
+    fn test_to_amount_rounds_once() {
+        let share = ExactAmount::<USD>::from_major(10) / 3;
+        assert_eq!(
+            share.to_amount(RoundingMode::HalfEven).unwrap().to_minor(),
+            333
+        );
+        assert_eq!(share.to_amount(RoundingMode::Up).unwrap().to_minor(), 334);
+        assert_eq!(
+            (share * 3)
+                .to_amount(RoundingMode::Down)
+                .unwrap()
+                .to_minor(),
+            1000
+        );
+    }
+
+    #[test]
+    fn test_from_minor_respects_currency_decimals() {
+        assert_eq!(
+            ExactAmount::<USD>::from_minor(150).value(),
+            &Rational::new(3, 2)
+        );
+        assert_eq!(
+            ExactAmount::<JPY>::from_minor(150).value(),
+            &Rational::from(150)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_through_amount() {
+        let amount = Amount::<USD>::from_minor(-1234);
+        assert_eq!(
+            amount.to_exact().to_amount(RoundingMode::HalfEven).unwrap(),
+            amount
+        );
+    }
+
+    #[test]
+    fn test_checked_div_by_zero() {
+        let err = ExactAmount::<USD>::from_major(1)
+            .checked_div(&Rational::ZERO)
+            .unwrap_err();
+        assert!(matches!(err, MoneyError::InvalidAmount { .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero")]
+    fn test_div_by_zero_panics() {
+        let _ = ExactAmount::<USD>::from_major(1) / 0;
+    }
+
+    #[test]
+    fn test_overflow_is_reported() {
+        let huge = ExactAmount::<USD>::new(Rational::new(i128::MAX, 1));
+        let err = huge.checked_add(&huge).unwrap_err();
+        assert!(matches!(
+            err,
+            MoneyError::Overflow {
+                currency: "USD",
+                ..
+            }
+        ));
+        assert!(huge.to_amount(RoundingMode::HalfEven).is_err());
+    }
+
+    #[test]
+    fn test_conversion_is_lossless() {
+        let rate = ExactRate::<USD, EUR>::try_from_rate(&Rate::new(0.85)).unwrap();
+        let usd = ExactAmount::<USD>::from_minor(12_345);
+        let back = usd.convert(&rate).convert(&rate.inverse());
+        assert_eq!(back, usd);
+    }
+
+    #[test]
+    fn test_inverse_is_lossless_unlike_decimal_rate() {
+        let rate = Rate::<USD, EUR>::from_decimal(rust_decimal::Decimal::new(3, 0));
+        assert_ne!(rate.inverse().inverse(), rate);
+
+        let exact = ExactRate::try_from_rate(&rate).unwrap();
+        assert_eq!(exact.inverse().inverse(), exact);
+        assert_eq!(exact.inverse().value(), &Rational::new(1, 3));
+    }
+
+    #[test]
+    fn test_rate_validation() {
+        assert!(ExactRate::<USD, EUR>::try_new(0, 1).is_err());
+        assert!(ExactRate::<USD, EUR>::try_new(-1, 2).is_err());
+        assert!(matches!(
+            ExactRate::<USD, EUR>::try_new(1, 0),
+            Err(MoneyError::InvalidRate { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rate_metadata_survives_round_trip() {
+        let exact = ExactRate::<USD, EUR>::new(17, 20)
+            .with_timestamp_unix_secs(1_700_000_000)
+            .with_source("ECB");
+        let inverse = exact.inverse();
+        assert_eq!(inverse.source(), Some("ECB"));
+
+        let rate = exact.to_rate(4, RoundingMode::HalfEven).unwrap();
+        assert_eq!(rate.value(), &rust_decimal::Decimal::new(8500, 4));
+        assert_eq!(rate.timestamp_unix_secs(), Some(1_700_000_000));
+        assert_eq!(rate.source(), Some("ECB"));
+    }
+
+    #[test]
+    fn test_display() {
+        let share = ExactAmount::<USD>::from_major(10) / 3;
+        assert_eq!(share.to_string(), "10/3 USD");
+        assert_eq!(
+            ExactRate::<USD, EUR>::new(17, 20).to_string(),
+            "1 USD = 17/20 EUR"
+        );
+    }
+}
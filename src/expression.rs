@@ -0,0 +1,731 @@
+//! Lazy monetary expressions with a single final rounding.
+//!
+//! Rounding after every intermediate step compounds rounding errors. A
+//! [`MoneyExpr`] instead records the calculation as an expression tree over
+//! `Amount<C>`, evaluates it at full precision, and rounds exactly once at the
+//! end using a chosen [`RoundingMode`].
+//!
+//! Evaluation can also produce an [`Explanation`] listing every intermediate
+//! value, which doubles as audit output for invoices.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::{Amount, MoneyExpr, RoundingMode, USD};
+//! use rust_decimal::Decimal;
+//!
+//! let price = Amount::<USD>::from_minor(1999);
+//! let discount = Amount::<USD>::from_minor(500);
+//! let tax = Decimal::new(8, 2); // 8%
+//!
+//! // (price * qty - discount) * (1 + tax)
+//! let total = (MoneyExpr::named("price", price) * 3 - MoneyExpr::named("discount", discount))
+//!     * (Decimal::ONE + tax);
+//!
+//! let result = total.evaluate(RoundingMode::HalfEven)?;
+//! assert_eq!(result.to_minor(), 5937); // 54.97 * 1.08 = 59.3676
+//!
+//! let explanation = total.explain(RoundingMode::HalfEven)?;
+//! println!("{}", explanation);
+//! # Ok::<(), typed_money::MoneyError>(())
+//! ```
+
+use crate::{Amount, Currency, MoneyError, MoneyResult, RoundingMode};
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// A lazily evaluated monetary expression in currency `C`.
+///
+/// Expressions are built from amounts with `+`, `-`, unary `-`, and
+/// multiplication or division by scalars (`i64` or `Decimal`). Nothing is
+/// computed until [`evaluate`](Self::evaluate) or [`explain`](Self::explain)
+/// is called.
+///
+/// Only amounts of the same currency can be combined, so the type system keeps
+/// expressions as safe as plain `Amount` arithmetic.
+#[derive(Debug, Clone)]
+pub struct MoneyExpr<C: Currency> {
+    node: Node,
+    label: Option<&'static str>,
+    _currency: PhantomData<C>,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Value(Decimal),
+    Add(Child, Child),
+    Sub(Child, Child),
+    Mul(Child, Decimal),
+    Div(Child, Decimal),
+    Neg(Child),
+}
+
+/// A sub-expression together with the name it was given, if any.
+#[derive(Debug, Clone)]
+struct Child {
+    node: Box<Node>,
+    label: Option<&'static str>,
+}
+
+impl<C: Currency> MoneyExpr<C> {
+    /// Creates an expression holding a single amount.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyExpr, RoundingMode, USD};
+    ///
+    /// let expr = MoneyExpr::new(Amount::<USD>::from_minor(1234));
+    /// assert_eq!(expr.evaluate(RoundingMode::HalfEven)?.to_minor(), 1234);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn new(amount: Amount<C>) -> Self {
+        Self::from_node(Node::Value(amount.value().to_owned()))
+    }
+
+    /// Creates an expression holding a single amount with a descriptive name.
+    ///
+    /// Names appear in [`Display`](fmt::Display) output and explanations.
+    pub fn named(name: &'static str, amount: Amount<C>) -> Self {
+        Self::new(amount).label(name)
+    }
+
+    /// Returns the expression with a descriptive name for its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyExpr, USD};
+    ///
+    /// let subtotal = (MoneyExpr::named("price", Amount::<USD>::from_major(10)) * 3)
+    ///     .label("subtotal");
+    /// assert_eq!(subtotal.to_string(), "subtotal");
+    /// ```
+    pub fn label(mut self, name: &'static str) -> Self {
+        self.label = Some(name);
+        self
+    }
+
+    /// Evaluates the expression at full precision and rounds the result once
+    /// to the currency's precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] on division by zero and
+    /// [`MoneyError::Overflow`] if an intermediate value overflows the decimal
+    /// backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyExpr, RoundingMode, USD};
+    ///
+    /// // Rounding once: 10 / 3 * 3 = 10.00, not 9.99
+    /// let expr = MoneyExpr::new(Amount::<USD>::from_major(10)) / 3 * 3;
+    /// assert_eq!(expr.evaluate(RoundingMode::HalfEven)?.to_minor(), 1000);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn evaluate(&self, mode: RoundingMode) -> MoneyResult<Amount<C>> {
+        Ok(self.evaluate_unrounded()?.round(mode))
+    }
+
+    /// Evaluates the expression at full precision without rounding.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`evaluate`](Self::evaluate).
+    pub fn evaluate_unrounded(&self) -> MoneyResult<Amount<C>> {
+        let value = eval::<C>(&self.node, &mut None)?;
+        Ok(Amount::new(value))
+    }
+
+    /// Evaluates the expression and records every intermediate value.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`evaluate`](Self::evaluate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyExpr, RoundingMode, USD};
+    ///
+    /// let expr = MoneyExpr::named("price", Amount::<USD>::from_minor(1000)) / 3;
+    /// let explanation = expr.explain(RoundingMode::HalfUp)?;
+    ///
+    /// assert_eq!(explanation.result().to_minor(), 333);
+    /// assert_eq!(explanation.steps().len(), 2);
+    /// println!("{}", explanation);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn explain(&self, mode: RoundingMode) -> MoneyResult<Explanation<C>> {
+        let mut steps = Some(Vec::new());
+        let value = eval_labelled::<C>(&self.node, self.label, &mut steps)?;
+        let steps = steps.unwrap_or_default();
+
+        let unrounded = Amount::new(value);
+        let result = unrounded.round(mode);
+
+        Ok(Explanation {
+            steps,
+            unrounded,
+            result,
+            mode,
+        })
+    }
+
+    fn from_node(node: Node) -> Self {
+        Self {
+            node,
+            label: None,
+            _currency: PhantomData,
+        }
+    }
+
+    fn into_child(self) -> Child {
+        Child {
+            node: Box::new(self.node),
+            label: self.label,
+        }
+    }
+}
+
+impl<C: Currency> From<Amount<C>> for MoneyExpr<C> {
+    fn from(amount: Amount<C>) -> Self {
+        Self::new(amount)
+    }
+}
+
+impl<C: Currency> Amount<C> {
+    /// Starts a lazily evaluated expression from this amount.
+    ///
+    /// See [`MoneyExpr`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, RoundingMode, USD};
+    ///
+    /// let total = Amount::<USD>::from_major(100).expr() / 3 * 3;
+    /// assert_eq!(total.evaluate(RoundingMode::HalfEven)?.to_minor(), 10000);
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn expr(&self) -> MoneyExpr<C> {
+        MoneyExpr::from_node(Node::Value(self.value().to_owned()))
+    }
+}
+
+// ============================================================================
+// Evaluation
+// ============================================================================
+
+/// One intermediate value in an [`Explanation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExplanationStep {
+    /// Optional name given to this value with [`MoneyExpr::label`]
+    pub label: Option<&'static str>,
+    /// The operation in readable form, e.g. `"59.97 - 5.00"`
+    pub description: String,
+    /// The full-precision value of this step
+    pub value: Decimal,
+}
+
+impl fmt::Display for ExplanationStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label {
+            Some(label) => write!(f, "{}: {} = {}", label, self.description, self.value),
+            None => write!(f, "{} = {}", self.description, self.value),
+        }
+    }
+}
+
+/// The result of [`MoneyExpr::explain`]: every intermediate value plus the
+/// single final rounding.
+#[derive(Debug, Clone)]
+pub struct Explanation<C: Currency> {
+    steps: Vec<ExplanationStep>,
+    unrounded: Amount<C>,
+    result: Amount<C>,
+    mode: RoundingMode,
+}
+
+impl<C: Currency> Explanation<C> {
+    /// Returns the intermediate steps in evaluation order.
+    pub fn steps(&self) -> &[ExplanationStep] {
+        &self.steps
+    }
+
+    /// Returns the full-precision value before the final rounding.
+    pub fn unrounded(&self) -> &Amount<C> {
+        &self.unrounded
+    }
+
+    /// Returns the final, rounded result.
+    pub fn result(&self) -> &Amount<C> {
+        &self.result
+    }
+
+    /// Returns the rounding mode used for the final rounding.
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.mode
+    }
+}
+
+impl<C: Currency> fmt::Display for Explanation<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{}", step)?;
+        }
+        write!(
+            f,
+            "round({}, {:?}) = {}",
+            self.unrounded.value(),
+            self.mode,
+            self.result
+        )
+    }
+}
+
+/// Evaluates a node, pushing a step for every operation and named leaf when
+/// `steps` is present.
+fn eval<C: Currency>(
+    node: &Node,
+    steps: &mut Option<Vec<ExplanationStep>>,
+) -> MoneyResult<Decimal> {
+    let (value, description) = match node {
+        Node::Value(value) => return Ok(value.to_owned()),
+        Node::Add(lhs, rhs) => {
+            let left = eval_child::<C>(lhs, steps)?;
+            let right = eval_child::<C>(rhs, steps)?;
+            let description = describe(steps, || {
+                format!("{} + {}", operand(&left, lhs), operand(&right, rhs))
+            });
+            let sum = checked_add(left, right).ok_or_else(|| overflow::<C>("addition"))?;
+            (sum, description)
+        }
+        Node::Sub(lhs, rhs) => {
+            let left = eval_child::<C>(lhs, steps)?;
+            let right = eval_child::<C>(rhs, steps)?;
+            let description = describe(steps, || {
+                format!("{} - {}", operand(&left, lhs), operand(&right, rhs))
+            });
+            let difference =
+                checked_sub(left, right).ok_or_else(|| overflow::<C>("subtraction"))?;
+            (difference, description)
+        }
+        Node::Mul(inner, factor) => {
+            let value = eval_child::<C>(inner, steps)?;
+            let description =
+                describe(steps, || format!("{} × {}", operand(&value, inner), factor));
+            let product =
+                checked_mul(value, factor).ok_or_else(|| overflow::<C>("multiplication"))?;
+            (product, description)
+        }
+        Node::Div(inner, divisor) => {
+            let value = eval_child::<C>(inner, steps)?;
+            if is_zero(divisor) {
+                return Err(MoneyError::InvalidAmount {
+                    reason: "Division by zero in expression".to_string(),
+                    currency: Some(C::CODE),
+                });
+            }
+            let description = describe(steps, || {
+                format!("{} ÷ {}", operand(&value, inner), divisor)
+            });
+            let quotient = checked_div(value, divisor).ok_or_else(|| overflow::<C>("division"))?;
+            (quotient, description)
+        }
+        Node::Neg(inner) => {
+            let value = eval_child::<C>(inner, steps)?;
+            let description = describe(steps, || format!("-{}", operand(&value, inner)));
+            (-value, description)
+        }
+    };
+
+    if let (Some(steps), Some(description)) = (steps.as_mut(), description) {
+        steps.push(ExplanationStep {
+            label: None,
+            description,
+            value: value.to_owned(),
+        });
+    }
+
+    Ok(value)
+}
+
+/// Evaluates a child and applies its label to the step it produced.
+fn eval_child<C: Currency>(
+    child: &Child,
+    steps: &mut Option<Vec<ExplanationStep>>,
+) -> MoneyResult<Decimal> {
+    eval_labelled::<C>(&child.node, child.label, steps)
+}
+
+/// Evaluates a node and applies `label` to the step it produced, recording a
+/// step for a named leaf.
+fn eval_labelled<C: Currency>(
+    node: &Node,
+    label: Option<&'static str>,
+    steps: &mut Option<Vec<ExplanationStep>>,
+) -> MoneyResult<Decimal> {
+    let before = steps.as_ref().map_or(0, Vec::len);
+    let value = eval::<C>(node, steps)?;
+
+    if let (Some(steps), Some(name)) = (steps.as_mut(), label) {
+        if steps.len() > before {
+            if let Some(last) = steps.last_mut() {
+                last.label = Some(name);
+            }
+        } else {
+            // Named leaf: record its value so the explanation can refer to it.
+            steps.push(ExplanationStep {
+                label: Some(name),
+                description: "value".to_string(),
+                value: value.to_owned(),
+            });
+        }
+    }
+
+    Ok(value)
+}
+
+/// Builds a step description only when an explanation is being recorded.
+fn describe(
+    steps: &Option<Vec<ExplanationStep>>,
+    description: impl FnOnce() -> String,
+) -> Option<String> {
+    steps.as_ref().map(|_| description())
+}
+
+fn operand(value: &Decimal, child: &Child) -> String {
+    match child.label {
+        Some(name) => format!("{} ({})", name, value),
+        None => value.to_string(),
+    }
+}
+
+fn overflow<C: Currency>(operation: &str) -> MoneyError {
+    MoneyError::Overflow {
+        operation: format!("expression {}", operation),
+        currency: C::CODE,
+    }
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn checked_add(a: Decimal, b: Decimal) -> Option<Decimal> {
+    a.checked_add(b)
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn checked_sub(a: Decimal, b: Decimal) -> Option<Decimal> {
+    a.checked_sub(b)
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn checked_mul(a: Decimal, b: &Decimal) -> Option<Decimal> {
+    a.checked_mul(*b)
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn checked_div(a: Decimal, b: &Decimal) -> Option<Decimal> {
+    a.checked_div(*b)
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn is_zero(value: &Decimal) -> bool {
+    value.is_zero()
+}
+
+// bigdecimal has arbitrary precision, so only division by zero can fail.
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn checked_add(a: Decimal, b: Decimal) -> Option<Decimal> {
+    Some(a + b)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn checked_sub(a: Decimal, b: Decimal) -> Option<Decimal> {
+    Some(a - b)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn checked_mul(a: Decimal, b: &Decimal) -> Option<Decimal> {
+    Some(a * b)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn checked_div(a: Decimal, b: &Decimal) -> Option<Decimal> {
+    Some(a / b)
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn is_zero(value: &Decimal) -> bool {
+    use bigdecimal::Zero;
+    value.is_zero()
+}
+
+impl<C: Currency> fmt::Display for MoneyExpr<C> {
+    /// Formats the expression as a formula, using names where given.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label {
+            Some(name) => write!(f, "{}", name),
+            None => write_node(f, &self.node),
+        }
+    }
+}
+
+fn write_child(f: &mut fmt::Formatter<'_>, child: &Child) -> fmt::Result {
+    match (child.label, child.node.as_ref()) {
+        (Some(name), _) => write!(f, "{}", name),
+        (None, node @ Node::Value(_)) => write_node(f, node),
+        (None, node) => {
+            write!(f, "(")?;
+            write_node(f, node)?;
+            write!(f, ")")
+        }
+    }
+}
+
+fn write_node(f: &mut fmt::Formatter<'_>, node: &Node) -> fmt::Result {
+    match node {
+        Node::Value(value) => write!(f, "{}", value),
+        Node::Add(lhs, rhs) => {
+            write_child(f, lhs)?;
+            write!(f, " + ")?;
+            write_child(f, rhs)
+        }
+        Node::Sub(lhs, rhs) => {
+            write_child(f, lhs)?;
+            write!(f, " - ")?;
+            write_child(f, rhs)
+        }
+        Node::Mul(inner, factor) => {
+            write_child(f, inner)?;
+            write!(f, " × {}", factor)
+        }
+        Node::Div(inner, divisor) => {
+            write_child(f, inner)?;
+            write!(f, " ÷ {}", divisor)
+        }
+        Node::Neg(inner) => {
+            write!(f, "-")?;
+            write_child(f, inner)
+        }
+    }
+}
+
+// ============================================================================
+// Operators
+// ============================================================================
+
+impl<C: Currency> Add for MoneyExpr<C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_node(Node::Add(self.into_child(), other.into_child()))
+    }
+}
+
+impl<C: Currency> Add<Amount<C>> for MoneyExpr<C> {
+    type Output = Self;
+
+    fn add(self, other: Amount<C>) -> Self {
+        self + MoneyExpr::new(other)
+    }
+}
+
+impl<C: Currency> Sub for MoneyExpr<C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_node(Node::Sub(self.into_child(), other.into_child()))
+    }
+}
+
+impl<C: Currency> Sub<Amount<C>> for MoneyExpr<C> {
+    type Output = Self;
+
+    fn sub(self, other: Amount<C>) -> Self {
+        self - MoneyExpr::new(other)
+    }
+}
+
+impl<C: Currency> Mul<Decimal> for MoneyExpr<C> {
+    type Output = Self;
+
+    fn mul(self, factor: Decimal) -> Self {
+        Self::from_node(Node::Mul(self.into_child(), factor))
+    }
+}
+
+impl<C: Currency> Mul<i64> for MoneyExpr<C> {
+    type Output = Self;
+
+    fn mul(self, factor: i64) -> Self {
+        self * Decimal::from(factor)
+    }
+}
+
+impl<C: Currency> Div<Decimal> for MoneyExpr<C> {
+    type Output = Self;
+
+    fn div(self, divisor: Decimal) -> Self {
+        Self::from_node(Node::Div(self.into_child(), divisor))
+    }
+}
+
+impl<C: Currency> Div<i64> for MoneyExpr<C> {
+    type Output = Self;
+
+    fn div(self, divisor: i64) -> Self {
+        self / Decimal::from(divisor)
+    }
+}
+
+impl<C: Currency> Neg for MoneyExpr<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::from_node(Node::Neg(self.into_child()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{JPY, USD};
+
+    fn usd(minor: i64) -> Amount<USD> {
+        Amount::<USD>::from_minor(minor)
+    }
+
+    #[test]
+    fn test_single_final_rounding() {
+        // Rounding each step would give 3.33 * 3 = 9.99
+        let expr = usd(1000).expr() / 3 * 3;
+        assert_eq!(
+            expr.evaluate(RoundingMode::HalfEven).unwrap().to_minor(),
+            1000
+        );
+
+        let stepwise = (usd(1000) / 3).round(RoundingMode::HalfEven) * 3;
+        assert_eq!(stepwise.to_minor(), 999);
+    }
+
+    #[test]
+    fn test_invoice_expression() {
+        let total = (MoneyExpr::named("price", usd(1999)) * 3
+            - MoneyExpr::named("discount", usd(500)))
+            * (Decimal::ONE + Decimal::new(8, 2));
+
+        let unrounded = total.evaluate_unrounded().unwrap();
+        assert_eq!(unrounded.value(), &Decimal::new(593676, 4));
+        assert_eq!(
+            total.evaluate(RoundingMode::HalfEven).unwrap().to_minor(),
+            5937
+        );
+        assert_eq!(
+            total.evaluate(RoundingMode::Floor).unwrap().to_minor(),
+            5936
+        );
+    }
+
+    #[test]
+    fn test_add_sub_neg_with_amounts() {
+        let expr = -(usd(100).expr() + usd(250) - usd(50));
+        assert_eq!(
+            expr.evaluate(RoundingMode::HalfEven).unwrap().to_minor(),
+            -300
+        );
+    }
+
+    #[test]
+    fn test_zero_decimal_currency() {
+        let expr = Amount::<JPY>::from_major(1000).expr() / 3;
+        assert_eq!(expr.evaluate(RoundingMode::HalfUp).unwrap().to_minor(), 333);
+    }
+
+    #[test]
+    fn test_division_by_zero_is_error() {
+        let result = (usd(100).expr() / 0).evaluate(RoundingMode::HalfEven);
+        assert!(matches!(result, Err(MoneyError::InvalidAmount { .. })));
+    }
+
+    #[test]
+    fn test_overflow_is_error() {
+        let result = (Amount::<USD>::new(Decimal::MAX).expr() * 2).evaluate(RoundingMode::HalfEven);
+        assert!(matches!(result, Err(MoneyError::Overflow { .. })));
+    }
+
+    #[test]
+    fn test_explain_lists_intermediate_values() {
+        let subtotal = (MoneyExpr::named("price", usd(1999)) * 3).label("subtotal");
+        let total = (subtotal - MoneyExpr::named("discount", usd(500))) * Decimal::new(108, 2);
+
+        let explanation = total.explain(RoundingMode::HalfEven).unwrap();
+        let steps = explanation.steps();
+
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[0].label, Some("price"));
+        assert_eq!(steps[1].label, Some("subtotal"));
+        assert_eq!(steps[1].description, "price (19.99) × 3");
+        assert_eq!(steps[1].value, Decimal::new(5997, 2));
+        assert_eq!(steps[2].label, Some("discount"));
+        assert_eq!(steps[3].description, "subtotal (59.97) - discount (5.00)");
+        assert_eq!(steps[4].description, "54.97 × 1.08");
+        assert_eq!(explanation.result().to_minor(), 5937);
+        assert_eq!(explanation.rounding_mode(), RoundingMode::HalfEven);
+
+        let rendered = explanation.to_string();
+        let expected = format!("round(59.3676, HalfEven) = {}", explanation.result());
+        assert!(rendered.ends_with(&expected));
+    }
+
+    #[test]
+    fn test_explain_labelled_leaf_root() {
+        let explanation = usd(1999)
+            .expr()
+            .label("total")
+            .explain(RoundingMode::HalfEven)
+            .unwrap();
+        assert_eq!(
+            explanation.steps(),
+            [ExplanationStep {
+                label: Some("total"),
+                description: "value".to_string(),
+                value: Decimal::new(1999, 2),
+            }]
+        );
+
+        // Unlabelled leaves still record nothing
+        let plain = usd(1999).expr().explain(RoundingMode::HalfEven).unwrap();
+        assert!(plain.steps().is_empty());
+    }
+
+    #[test]
+    fn test_display_formula() {
+        let total = (MoneyExpr::named("price", usd(1999)) * 3
+            - MoneyExpr::named("discount", usd(500)))
+            * Decimal::new(108, 2);
+        assert_eq!(total.to_string(), "((price × 3) - discount) × 1.08");
+
+        let plain = usd(100).expr() + usd(200);
+        assert_eq!(plain.to_string(), "1.00 + 2.00");
+    }
+
+    #[test]
+    fn test_explain_matches_evaluate() {
+        let expr = (usd(12345).expr() * 7 / 9 + usd(1)) * Decimal::new(3, 1);
+        let explanation = expr.explain(RoundingMode::HalfUp).unwrap();
+        assert_eq!(
+            explanation.result(),
+            &expr.evaluate(RoundingMode::HalfUp).unwrap()
+        );
+    }
+}
@@ -0,0 +1,506 @@
+//! Configurable formatting of amounts.
+//!
+//! The `format_*` methods on [`Amount`] each produce one fixed style. A
+//! [`MoneyFormatter`] describes a style once, using the currency's own
+//! separators and grouping, and applies it to amounts of any currency:
+//!
+//! ```
+//! use typed_money::{Amount, MoneyFormatter, SignDisplay, SymbolStyle, EUR, USD};
+//!
+//! let statement = MoneyFormatter::new()
+//!     .with_symbol_style(SymbolStyle::Code)
+//!     .with_accounting(true);
+//!
+//! assert_eq!(statement.format(&Amount::<USD>::from_minor(-123_456)), "(USD 1,234.56)");
+//! assert_eq!(statement.format(&Amount::<EUR>::from_minor(98_765)), "987,65 EUR");
+//!
+//! let export = MoneyFormatter::new()
+//!     .with_symbol_style(SymbolStyle::None)
+//!     .with_grouping(false)
+//!     .with_sign_display(SignDisplay::Always);
+//!
+//! assert_eq!(export.format(&Amount::<USD>::from_minor(123_456)), "+1234.56");
+//! ```
+
+use crate::{Amount, Currency, SymbolPosition};
+use std::fmt::{self, Write};
+
+/// How the currency is identified next to the number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SymbolStyle {
+    /// The currency symbol (e.g., "A$")
+    #[default]
+    Symbol,
+    /// The narrow symbol, where the currency has one (e.g., "$" for AUD)
+    NarrowSymbol,
+    /// The ISO 4217 code (e.g., "AUD")
+    Code,
+    /// The currency name (e.g., "Australian Dollar"), or the code if the
+    /// currency has no name
+    Name,
+    /// No currency marker at all
+    None,
+}
+
+/// When to show the sign of an amount.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum SignDisplay {
+    /// Only negative amounts get a sign
+    #[default]
+    NegativeOnly,
+    /// Every amount gets a sign, including zero
+    Always,
+    /// No amount gets a sign
+    Never,
+}
+
+/// How much of a compact unit is spelled out by
+/// [`Amount::format_compact`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum CompactStyle {
+    /// Abbreviated units ("$1.2M", "1,2 Mio. €")
+    #[default]
+    Short,
+    /// Spelled-out units ("$1.2 million", "1,2 Millionen €")
+    Long,
+}
+
+/// A reusable formatting style for amounts of any currency.
+///
+/// Options that are not set fall back to the currency's own metadata:
+/// `THOUSANDS_SEPARATOR`, `DECIMAL_SEPARATOR`, `GROUPING`, `DECIMALS`,
+/// `SYMBOL_POSITION` and `SPACE_BETWEEN`. Codes and names are separated from
+/// the number by a space and names are placed after it, unless the position
+/// or spacing is set explicitly.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{Amount, MoneyFormatter, SymbolPosition, SymbolStyle, AUD, JPY};
+///
+/// let label = MoneyFormatter::new()
+///     .with_symbol_style(SymbolStyle::NarrowSymbol)
+///     .with_trim_trailing_zeros(true);
+///
+/// assert_eq!(label.format(&Amount::<AUD>::from_major(25)), "$25");
+/// assert_eq!(label.format(&Amount::<AUD>::from_minor(2_550)), "$25.5");
+///
+/// let rates = MoneyFormatter::new()
+///     .with_decimals(4)
+///     .with_symbol_position(SymbolPosition::After)
+///     .with_spacing(true);
+///
+/// assert_eq!(rates.format(&Amount::<JPY>::from_major(150)), "150.0000 ¥");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MoneyFormatter {
+    symbol_style: SymbolStyle,
+    symbol_position: Option<SymbolPosition>,
+    spacing: Option<bool>,
+    grouping: bool,
+    decimals: Option<u32>,
+    trim_trailing_zeros: bool,
+    sign_display: SignDisplay,
+    accounting: bool,
+    plus_sign: char,
+    minus_sign: char,
+}
+
+impl MoneyFormatter {
+    /// Creates a formatter that matches [`Amount::format_native`].
+    pub const fn new() -> Self {
+        Self {
+            symbol_style: SymbolStyle::Symbol,
+            symbol_position: None,
+            spacing: None,
+            grouping: true,
+            decimals: None,
+            trim_trailing_zeros: false,
+            sign_display: SignDisplay::NegativeOnly,
+            accounting: false,
+            plus_sign: '+',
+            minus_sign: '-',
+        }
+    }
+
+    /// Returns the formatter with a different way of identifying the currency.
+    pub const fn with_symbol_style(mut self, style: SymbolStyle) -> Self {
+        self.symbol_style = style;
+        self
+    }
+
+    /// Returns the formatter with the currency marker always placed at
+    /// `position`.
+    pub const fn with_symbol_position(mut self, position: SymbolPosition) -> Self {
+        self.symbol_position = Some(position);
+        self
+    }
+
+    /// Returns the formatter with a space always (or never) written between
+    /// the currency marker and the number.
+    pub const fn with_spacing(mut self, spacing: bool) -> Self {
+        self.spacing = Some(spacing);
+        self
+    }
+
+    /// Returns the formatter with digit grouping turned on or off.
+    pub const fn with_grouping(mut self, enabled: bool) -> Self {
+        self.grouping = enabled;
+        self
+    }
+
+    /// Returns the formatter with `decimals` decimal places instead of the
+    /// currency's. Amounts are rounded with the currency's `DEFAULT_ROUNDING`.
+    pub const fn with_decimals(mut self, decimals: u32) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Returns the formatter with trailing fractional zeros, and a decimal
+    /// separator left without digits, removed.
+    pub const fn with_trim_trailing_zeros(mut self, trim: bool) -> Self {
+        self.trim_trailing_zeros = trim;
+        self
+    }
+
+    /// Returns the formatter with a different sign display.
+    pub const fn with_sign_display(mut self, display: SignDisplay) -> Self {
+        self.sign_display = display;
+        self
+    }
+
+    /// Returns the formatter with negative amounts wrapped in parentheses
+    /// instead of carrying a minus sign.
+    pub const fn with_accounting(mut self, accounting: bool) -> Self {
+        self.accounting = accounting;
+        self
+    }
+
+    /// Returns the formatter with a different plus sign.
+    pub const fn with_plus_sign(mut self, sign: char) -> Self {
+        self.plus_sign = sign;
+        self
+    }
+
+    /// Returns the formatter with a different minus sign (e.g., U+2212).
+    pub const fn with_minus_sign(mut self, sign: char) -> Self {
+        self.minus_sign = sign;
+        self
+    }
+
+    /// Returns the way the currency is identified.
+    pub const fn symbol_style(&self) -> SymbolStyle {
+        self.symbol_style
+    }
+
+    /// Returns the sign display.
+    pub const fn sign_display(&self) -> SignDisplay {
+        self.sign_display
+    }
+
+    /// Returns the decimal places override, if any.
+    pub const fn decimals(&self) -> Option<u32> {
+        self.decimals
+    }
+
+    /// Formats `amount` into a new string.
+    pub fn format<C: Currency>(&self, amount: &Amount<C>) -> String {
+        let mut out = String::new();
+        // Writing to a String cannot fail
+        let _ = self.write_to(amount, &mut out);
+        out
+    }
+
+    /// Writes `amount` to any [`fmt::Write`], such as a `String` or a
+    /// `fmt::Formatter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use typed_money::{Amount, MoneyFormatter, GBP, USD};
+    ///
+    /// let formatter = MoneyFormatter::new();
+    /// let mut line = String::from("Total: ");
+    /// formatter.write_to(&Amount::<USD>::from_major(5), &mut line)?;
+    /// line.push_str(" / ");
+    /// formatter.write_to(&Amount::<GBP>::from_major(4), &mut line)?;
+    /// assert_eq!(line, "Total: $5.00 / £4.00");
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    pub fn write_to<C: Currency, W: fmt::Write>(
+        &self,
+        amount: &Amount<C>,
+        out: &mut W,
+    ) -> fmt::Result {
+        let plain = match self.decimals {
+            Some(decimals) => amount
+                .round_to_scale(decimals, C::DEFAULT_ROUNDING)
+                .format_plain_scale(decimals),
+            None => amount.format_plain(),
+        };
+        let (negative, digits) = match plain.strip_prefix('-') {
+            // Amounts that display as zero never get a minus sign
+            Some(digits) => (digits.chars().any(|ch| matches!(ch, '1'..='9')), digits),
+            None => (false, plain.as_str()),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) if self.trim_trailing_zeros => {
+                (integer, fraction.trim_end_matches('0'))
+            }
+            Some((integer, fraction)) => (integer, fraction),
+            None => (digits, ""),
+        };
+
+        let mut number = if self.grouping {
+            C::GROUPING.apply(integer, C::THOUSANDS_SEPARATOR)
+        } else {
+            integer.to_string()
+        };
+        if !fraction.is_empty() {
+            number.push(C::DECIMAL_SEPARATOR);
+            number.push_str(fraction);
+        }
+
+        let (marker, default_position, default_spacing) = match self.symbol_style {
+            SymbolStyle::Symbol => (C::SYMBOL, C::SYMBOL_POSITION, C::SPACE_BETWEEN),
+            SymbolStyle::NarrowSymbol if !C::NARROW_SYMBOL.is_empty() => {
+                (C::NARROW_SYMBOL, C::SYMBOL_POSITION, C::SPACE_BETWEEN)
+            }
+            SymbolStyle::NarrowSymbol => (C::SYMBOL, C::SYMBOL_POSITION, C::SPACE_BETWEEN),
+            SymbolStyle::Code => (C::CODE, C::SYMBOL_POSITION, true),
+            SymbolStyle::Name if !C::NAME.is_empty() => (C::NAME, SymbolPosition::After, true),
+            SymbolStyle::Name => (C::CODE, SymbolPosition::After, true),
+            SymbolStyle::None => ("", C::SYMBOL_POSITION, false),
+        };
+        let position = self.symbol_position.unwrap_or(default_position);
+        let space = if !marker.is_empty() && self.spacing.unwrap_or(default_spacing) {
+            " "
+        } else {
+            ""
+        };
+
+        let parentheses = negative && self.accounting && self.sign_display != SignDisplay::Never;
+        if parentheses {
+            out.write_char('(')?;
+        } else {
+            match self.sign_display {
+                SignDisplay::Never => {}
+                _ if negative => out.write_char(self.minus_sign)?,
+                SignDisplay::Always => out.write_char(self.plus_sign)?,
+                SignDisplay::NegativeOnly => {}
+            }
+        }
+
+        match position {
+            SymbolPosition::Before => write!(out, "{}{}{}", marker, space, number)?,
+            SymbolPosition::After => write!(out, "{}{}{}", number, space, marker)?,
+        }
+
+        if parentheses {
+            out.write_char(')')?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for MoneyFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes `text` padded to the formatter's width with its fill character.
+/// Text is right-aligned unless another alignment is requested.
+pub(crate) fn write_padded(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let len = text.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(text),
+    };
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(text)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl<C: Currency> Amount<C> {
+    /// Formats the amount with a [`MoneyFormatter`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, MoneyFormatter, SymbolStyle, USD};
+    ///
+    /// let formatter = MoneyFormatter::new().with_symbol_style(SymbolStyle::Name);
+    /// let amount = Amount::<USD>::from_major(12);
+    /// assert_eq!(amount.format_with(&formatter), "12.00 US Dollar");
+    /// ```
+    pub fn format_with(&self, formatter: &MoneyFormatter) -> String {
+        formatter.format(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AUD, BTC, CHF, EUR, INR, JPY, USD};
+
+    #[test]
+    fn test_default_matches_format_native() {
+        let formatter = MoneyFormatter::default();
+        for minor in [0, 1, -1, 123_456, -123_456_789] {
+            let usd = Amount::<USD>::from_minor(minor);
+            assert_eq!(formatter.format(&usd), usd.format_native());
+            let eur = Amount::<EUR>::from_minor(minor);
+            assert_eq!(formatter.format(&eur), eur.format_native());
+            let inr = Amount::<INR>::from_minor(minor);
+            assert_eq!(formatter.format(&inr), inr.format_native());
+        }
+    }
+
+    #[test]
+    fn test_symbol_styles() {
+        let amount = Amount::<AUD>::from_minor(123_456);
+        let style = |style| MoneyFormatter::new().with_symbol_style(style);
+        assert_eq!(style(SymbolStyle::Symbol).format(&amount), "A$1,234.56");
+        assert_eq!(
+            style(SymbolStyle::NarrowSymbol).format(&amount),
+            "$1,234.56"
+        );
+        assert_eq!(style(SymbolStyle::Code).format(&amount), "AUD 1,234.56");
+        assert_eq!(
+            style(SymbolStyle::Name).format(&amount),
+            "1,234.56 Australian Dollar"
+        );
+        assert_eq!(style(SymbolStyle::None).format(&amount), "1,234.56");
+
+        // Currencies without a narrow symbol keep their symbol
+        let chf = Amount::<CHF>::from_major(5);
+        assert_eq!(
+            style(SymbolStyle::NarrowSymbol).format(&chf),
+            chf.format_native()
+        );
+    }
+
+    #[test]
+    fn test_position_and_spacing_overrides() {
+        let amount = Amount::<EUR>::from_minor(-1_050);
+        let before = MoneyFormatter::new().with_symbol_position(SymbolPosition::Before);
+        assert_eq!(before.format(&amount), "-€ 10,50");
+        assert_eq!(before.with_spacing(false).format(&amount), "-€10,50");
+
+        let code = MoneyFormatter::new()
+            .with_symbol_style(SymbolStyle::Code)
+            .with_spacing(false);
+        assert_eq!(code.format(&Amount::<USD>::from_major(1)), "USD1.00");
+    }
+
+    #[test]
+    fn test_grouping_toggle() {
+        let amount = Amount::<INR>::from_major(12_345_678);
+        assert_eq!(MoneyFormatter::new().format(&amount), "₹1,23,45,678.00");
+        assert_eq!(
+            MoneyFormatter::new().with_grouping(false).format(&amount),
+            "₹12345678.00"
+        );
+    }
+
+    #[test]
+    fn test_decimals_and_trimming() {
+        let amount = Amount::<USD>::from_minor(123_455);
+        let two = MoneyFormatter::new();
+        assert_eq!(two.with_decimals(0).format(&amount), "$1,235");
+        assert_eq!(two.with_decimals(1).format(&amount), "$1,234.6");
+        assert_eq!(two.with_decimals(4).format(&amount), "$1,234.5500");
+
+        let trim = two.with_trim_trailing_zeros(true);
+        assert_eq!(trim.format(&amount), "$1,234.55");
+        assert_eq!(trim.format(&Amount::<USD>::from_minor(1_050)), "$10.5");
+        assert_eq!(trim.format(&Amount::<USD>::from_major(10)), "$10");
+        assert_eq!(trim.with_decimals(4).format(&amount), "$1,234.55");
+        assert_eq!(trim.format(&Amount::<BTC>::from_major(1)), "₿1");
+        assert_eq!(trim.format(&Amount::<JPY>::from_major(500)), "¥500");
+    }
+
+    #[test]
+    fn test_decimals_use_currency_rounding() {
+        // USD rounds half-even by default
+        let amount = Amount::<USD>::from_minor(250);
+        assert_eq!(MoneyFormatter::new().with_decimals(0).format(&amount), "$2");
+    }
+
+    #[test]
+    fn test_sign_display() {
+        let positive = Amount::<USD>::from_major(5);
+        let negative = Amount::<USD>::from_major(-5);
+        let zero = Amount::<USD>::from_major(0);
+
+        let always = MoneyFormatter::new().with_sign_display(SignDisplay::Always);
+        assert_eq!(always.format(&positive), "+$5.00");
+        assert_eq!(always.format(&negative), "-$5.00");
+        assert_eq!(always.format(&zero), "+$0.00");
+
+        let never = MoneyFormatter::new().with_sign_display(SignDisplay::Never);
+        assert_eq!(never.format(&negative), "$5.00");
+        assert_eq!(never.with_accounting(true).format(&negative), "$5.00");
+
+        // Rounding to zero drops the sign
+        let tiny = Amount::<USD>::from_minor(-1);
+        let whole = MoneyFormatter::new().with_decimals(0);
+        assert_eq!(whole.format(&tiny), "$0");
+    }
+
+    #[test]
+    fn test_accounting_and_sign_characters() {
+        let negative = Amount::<EUR>::from_minor(-123_456);
+        let accounting = MoneyFormatter::new().with_accounting(true);
+        assert_eq!(accounting.format(&negative), "(1.234,56 €)");
+        assert_eq!(accounting.format(&Amount::<EUR>::from_major(1)), "1,00 €");
+
+        let typographic = MoneyFormatter::new()
+            .with_sign_display(SignDisplay::Always)
+            .with_minus_sign('\u{2212}')
+            .with_plus_sign('\u{ff0b}');
+        assert_eq!(typographic.format(&negative), "\u{2212}1.234,56 €");
+        assert_eq!(
+            typographic.format(&Amount::<EUR>::from_major(1)),
+            "\u{ff0b}1,00 €"
+        );
+    }
+
+    #[test]
+    fn test_write_to_formatter() {
+        struct Row(Amount<USD>);
+
+        impl fmt::Display for Row {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("| ")?;
+                MoneyFormatter::new()
+                    .with_accounting(true)
+                    .write_to(&self.0, f)?;
+                f.write_str(" |")
+            }
+        }
+
+        assert_eq!(
+            Row(Amount::<USD>::from_major(-3)).to_string(),
+            "| ($3.00) |"
+        );
+    }
+}
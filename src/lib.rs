@@ -68,10 +68,10 @@
 //!
 //! // Locale-specific formatting
 //! let eur_amount = Amount::<EUR>::from_major(1234);
-//! println!("European format: {}", eur_amount); // "€1.234,00 EUR"
+//! println!("European format: {}", eur_amount.format_native()); // "1.234,00 €"
 //!
 //! let brl_amount = Amount::<BRL>::from_major(1234);
-//! println!("Brazilian format: {}", brl_amount); // "R$1.234,00 BRL"
+//! println!("Brazilian format: {}", brl_amount.format_native()); // "R$1.234,00"
 //! ```
 //!
 //! # Precious Metals & Commodities
@@ -214,6 +214,8 @@
 //! - `use_bigdecimal` - Use bigdecimal backend (alternative)
 //! - `serde_support` - Enable serde serialization
 //! - `conversion_tracking` - Enable conversion tracking/logging
+//! - `operation_tracking` - Trace arithmetic, rounding and conversions (implies `conversion_tracking`)
+//! - `cldr_json` - Load [`Locale`] rules from Unicode CLDR JSON files
 //!
 //! # Examples
 //!
@@ -245,9 +247,11 @@
 //! ```
 //!
 //! ### [`rounding`](https://github.com/ricardoferreirades/typed-money/blob/main/examples/rounding.rs)
-//! Demonstrates all 7 rounding modes:
-//! - HalfUp, HalfDown, HalfEven (Banker's)
+//! Demonstrates all rounding modes:
+//! - HalfUp, HalfDown, HalfEven (Banker's), HalfOdd
+//! - HalfAwayFromZero, HalfTowardZero
 //! - Up, Down, Floor, Ceiling
+//! - Round05Up, seeded Stochastic
 //! - Edge cases with negative numbers
 //! - Tax and interest calculations
 //! - When to use each mode
@@ -304,22 +308,44 @@ compile_error!("Either 'use_rust_decimal' or 'use_bigdecimal' feature must be en
 compile_error!("Only one decimal backend can be enabled at a time");
 
 mod amount;
+mod context;
 mod currency;
 mod error;
+mod exact;
+mod expression;
+mod formatter;
+mod locale;
 mod rate;
+mod rational;
 mod rounding;
+mod words;
 
 #[cfg(feature = "conversion_tracking")]
 pub mod conversion_tracking;
 
-pub use amount::{Amount, CurrencyMetadata};
+pub mod csv;
+
+#[cfg(feature = "serde_support")]
+pub mod serde;
+
+pub use amount::{
+    Amount, AmountPolicy, CurrencyHints, CurrencyMetadata, Detection, DynAmount, InputFormat,
+};
+pub use context::{MoneyContext, Trap};
 pub use currency::{
+    find_currency,
+    known_currencies,
+    register_currency,
     // Core currencies
     Currency,
+    // Currency registry
+    CurrencyInfo,
     // Currency metadata types
     CurrencyType,
+    Grouping,
     LiquidityRating,
     SymbolPosition,
+    UnitName,
     VolatilityRating,
     AAVE,
     // Major Cryptocurrencies
@@ -420,5 +446,14 @@ pub use currency::{
     ZAR,
 };
 pub use error::{MoneyError, MoneyResult};
+pub use exact::{ExactAmount, ExactRate};
+pub use expression::{Explanation, ExplanationStep, MoneyExpr};
+pub use formatter::{CompactStyle, MoneyFormatter, SignDisplay, SymbolStyle};
+pub use locale::{
+    Locale, NegativePattern, ARABIC_DIGITS, BENGALI_DIGITS, DEVANAGARI_DIGITS, LATIN_DIGITS,
+    PERSIAN_DIGITS,
+};
 pub use rate::Rate;
+pub use rational::Rational;
 pub use rounding::RoundingMode;
+pub use words::Language;
@@ -0,0 +1,292 @@
+//! Loading locale rules from Unicode CLDR JSON data.
+
+use super::{
+    parse_error, Locale, NegativePattern, Subtags, ARABIC_DIGITS, BENGALI_DIGITS,
+    DEVANAGARI_DIGITS, LATIN_DIGITS, PERSIAN_DIGITS,
+};
+use crate::{Grouping, MoneyResult, SymbolPosition};
+use serde_json::Value;
+use std::path::Path;
+
+impl Locale {
+    /// Builds a locale from the contents of a CLDR `numbers.json` file.
+    ///
+    /// The file is expected in the layout of the `cldr-numbers-full` JSON
+    /// package (`main/<tag>/numbers.json`). Rules are taken from the locale's
+    /// default numbering system, its standard currency pattern and, when
+    /// present, `minimumGroupingDigits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`](crate::MoneyError::ParseError) if
+    /// the JSON is malformed, fields are missing, or the numbering system is
+    /// not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, Locale, CHF};
+    ///
+    /// let json = r#"{ "main": { "de-CH": { "numbers": {
+    ///     "defaultNumberingSystem": "latn",
+    ///     "symbols-numberSystem-latn": { "decimal": ".", "group": "’", "minusSign": "-" },
+    ///     "currencyFormats-numberSystem-latn": { "standard": "¤ #,##0.00;¤-#,##0.00" }
+    /// } } } }"#;
+    ///
+    /// let locale = Locale::from_cldr_json(json)?;
+    /// let amount = Amount::<CHF>::from_minor(-123_456);
+    /// assert_eq!(amount.format_in(&locale), "CHF\u{a0}-1’234.56");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn from_cldr_json(json: &str) -> MoneyResult<Self> {
+        let root: Value =
+            serde_json::from_str(json).map_err(|err| parse_error("CLDR JSON", &err.to_string()))?;
+        let (tag, locale_data) = root
+            .get("main")
+            .and_then(Value::as_object)
+            .and_then(|main| main.iter().next())
+            .ok_or_else(|| parse_error("CLDR JSON", "missing \"main\" section"))?;
+
+        let subtags =
+            Subtags::parse(tag).ok_or_else(|| parse_error(tag, "malformed locale tag"))?;
+        let numbers = locale_data
+            .get("numbers")
+            .ok_or_else(|| parse_error(tag, "missing \"numbers\" section"))?;
+        let field = |section: &Value, name: &str| -> MoneyResult<String> {
+            section
+                .get(name)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| parse_error(tag, &format!("missing \"{}\"", name)))
+        };
+
+        let system = field(numbers, "defaultNumberingSystem")?;
+        let digits = match system.as_str() {
+            "latn" => LATIN_DIGITS,
+            "arab" => ARABIC_DIGITS,
+            "arabext" => PERSIAN_DIGITS,
+            "deva" => DEVANAGARI_DIGITS,
+            "beng" => BENGALI_DIGITS,
+            other => {
+                return Err(parse_error(
+                    tag,
+                    &format!("unsupported numbering system '{}'", other),
+                ))
+            }
+        };
+
+        let symbols_key = format!("symbols-numberSystem-{}", system);
+        let symbols = numbers
+            .get(&symbols_key)
+            .ok_or_else(|| parse_error(tag, &format!("missing \"{}\"", symbols_key)))?;
+        let formats_key = format!("currencyFormats-numberSystem-{}", system);
+        let formats = numbers
+            .get(&formats_key)
+            .ok_or_else(|| parse_error(tag, &format!("missing \"{}\"", formats_key)))?;
+
+        let pattern = field(formats, "standard")?;
+        let (symbol_position, symbol_spacing, grouping, negative_pattern) = parse_pattern(&pattern)
+            .ok_or_else(|| parse_error(tag, &format!("unsupported pattern '{}'", pattern)))?;
+        let grouping = match numbers.get("minimumGroupingDigits") {
+            None => grouping,
+            Some(value) => value
+                .as_str()
+                .and_then(|digits| digits.parse().ok())
+                .or_else(|| value.as_u64().and_then(|digits| u8::try_from(digits).ok()))
+                .map(|digits| grouping.with_min_grouping(digits))
+                .ok_or_else(|| parse_error(tag, "invalid \"minimumGroupingDigits\""))?,
+        };
+
+        Ok(Locale {
+            tag: subtags.to_string(),
+            resolved: subtags.to_string(),
+            decimal_separator: single_char(&field(symbols, "decimal")?)
+                .ok_or_else(|| parse_error(tag, "decimal separator is not one character"))?,
+            grouping_separator: single_char(&field(symbols, "group")?)
+                .ok_or_else(|| parse_error(tag, "group separator is not one character"))?,
+            grouping,
+            minus_sign: single_char(&field(symbols, "minusSign")?)
+                .ok_or_else(|| parse_error(tag, "minus sign is not one character"))?,
+            symbol_position,
+            symbol_spacing,
+            negative_pattern,
+            digits,
+        })
+    }
+
+    /// Reads a CLDR `numbers.json` file from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`](crate::MoneyError::ParseError) if
+    /// the file cannot be read or [`from_cldr_json`](Self::from_cldr_json)
+    /// rejects its contents.
+    pub fn load_cldr(path: impl AsRef<Path>) -> MoneyResult<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|err| parse_error(&path.display().to_string(), &err.to_string()))?;
+        Self::from_cldr_json(&json)
+    }
+}
+
+/// Returns the character if the string holds exactly one, ignoring the
+/// bidi marks CLDR places around some symbols.
+fn single_char(value: &str) -> Option<char> {
+    let mut chars = value
+        .chars()
+        .filter(|ch| !matches!(ch, '\u{200e}' | '\u{200f}' | '\u{61c}'));
+    let ch = chars.next()?;
+    chars.next().is_none().then_some(ch)
+}
+
+/// Extracts symbol placement, grouping and the negative pattern from a CLDR
+/// currency pattern such as `#,##0.00 ¤` or `¤#,##0.00;(¤#,##0.00)`.
+fn parse_pattern(pattern: &str) -> Option<(SymbolPosition, bool, Grouping, NegativePattern)> {
+    let mut subpatterns = pattern.split(';');
+    let positive: Vec<char> = subpatterns.next()?.chars().collect();
+    let negative = subpatterns.next();
+
+    let symbol = positive.iter().position(|&ch| ch == '¤')?;
+    let number_start = positive.iter().position(|ch| matches!(ch, '#' | '0'))?;
+    let number_end = positive.iter().rposition(|ch| matches!(ch, '#' | '0'))?;
+
+    let (position, between) = if symbol < number_start {
+        (SymbolPosition::Before, &positive[symbol + 1..number_start])
+    } else {
+        (SymbolPosition::After, &positive[number_end + 1..symbol])
+    };
+    let spacing = between.iter().any(|ch| ch.is_whitespace());
+
+    let number: String = positive[number_start..=number_end].iter().collect();
+    let integer = number.split('.').next().unwrap_or_default();
+    let groups: Vec<usize> = integer.split(',').map(str::len).collect();
+    let grouping = match groups.as_slice() {
+        [_] => Grouping::NONE,
+        [_, primary] => Grouping::new(u8::try_from(*primary).ok()?, 0),
+        [.., secondary, primary] => {
+            Grouping::new(u8::try_from(*primary).ok()?, u8::try_from(*secondary).ok()?)
+        }
+        [] => return None,
+    };
+
+    let negative_pattern = match negative {
+        Some(negative) if negative.contains('(') => NegativePattern::Parentheses,
+        Some(negative)
+            if position == SymbolPosition::Before && negative.find('-') > negative.find('¤') =>
+        {
+            NegativePattern::AfterSymbol
+        }
+        _ => NegativePattern::Leading,
+    };
+
+    Some((position, spacing, grouping, negative_pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbers_json(tag: &str, system: &str, symbols: &str, pattern: &str) -> String {
+        format!(
+            r#"{{ "main": {{ "{tag}": {{ "identity": {{}}, "numbers": {{
+                "defaultNumberingSystem": "{system}",
+                "symbols-numberSystem-{system}": {symbols},
+                "currencyFormats-numberSystem-{system}": {{ "standard": "{pattern}" }}
+            }} }} }} }}"#
+        )
+    }
+
+    #[test]
+    fn test_parse_pattern() {
+        let (position, spacing, grouping, negative) = parse_pattern("#,##0.00\u{a0}¤").unwrap();
+        assert_eq!(position, SymbolPosition::After);
+        assert!(spacing);
+        assert_eq!(grouping, Grouping::THOUSANDS);
+        assert_eq!(negative, NegativePattern::Leading);
+
+        let (position, spacing, grouping, negative) =
+            parse_pattern("¤#,##,##0.00;(¤#,##,##0.00)").unwrap();
+        assert_eq!(position, SymbolPosition::Before);
+        assert!(!spacing);
+        assert_eq!(grouping, Grouping::INDIAN);
+        assert_eq!(negative, NegativePattern::Parentheses);
+
+        let (_, _, grouping, _) = parse_pattern("¤ 0.00").unwrap();
+        assert_eq!(grouping, Grouping::NONE);
+
+        assert!(parse_pattern("no number here").is_none());
+    }
+
+    #[test]
+    fn test_from_cldr_json_matches_builtin() {
+        let json = numbers_json(
+            "de",
+            "latn",
+            r#"{ "decimal": ",", "group": ".", "minusSign": "-" }"#,
+            "#,##0.00\u{a0}¤",
+        );
+        let loaded = Locale::from_cldr_json(&json).unwrap();
+        let builtin = Locale::parse("de").unwrap();
+        assert_eq!(loaded.tag(), "de");
+        assert_eq!(
+            loaded.format_number("-1234.5", "€"),
+            builtin.format_number("-1234.5", "€")
+        );
+    }
+
+    #[test]
+    fn test_from_cldr_json_minimum_grouping_digits() {
+        let json = numbers_json(
+            "es",
+            "latn",
+            r#"{ "decimal": ",", "group": ".", "minusSign": "-" }"#,
+            "#,##0.00\u{a0}¤",
+        )
+        .replace(
+            r#""defaultNumberingSystem""#,
+            r#""minimumGroupingDigits": "2", "defaultNumberingSystem""#,
+        );
+        let locale = Locale::from_cldr_json(&json).unwrap();
+        assert_eq!(locale.grouping().min_grouping(), 2);
+        assert_eq!(locale.format_number("1234.5", "€"), "1234,5\u{a0}€");
+        assert_eq!(locale.format_number("12345.5", "€"), "12.345,5\u{a0}€");
+
+        let invalid = json.replace("\"2\"", "\"two\"");
+        assert!(Locale::from_cldr_json(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_from_cldr_json_native_digits() {
+        let json = numbers_json(
+            "ar-EG",
+            "arab",
+            r#"{ "decimal": "٫", "group": "٬", "minusSign": "؜-" }"#,
+            "‏#,##0.00 ¤",
+        );
+        let locale = Locale::from_cldr_json(&json).unwrap();
+        assert_eq!(locale.digits(), ARABIC_DIGITS);
+        assert_eq!(locale.minus_sign(), '-');
+        assert_eq!(locale.symbol_position(), SymbolPosition::After);
+    }
+
+    #[test]
+    fn test_from_cldr_json_errors() {
+        assert!(Locale::from_cldr_json("not json").is_err());
+        assert!(Locale::from_cldr_json(r#"{ "main": {} }"#).is_err());
+
+        let json = numbers_json(
+            "th",
+            "thai",
+            r#"{ "decimal": ".", "group": ",", "minusSign": "-" }"#,
+            "¤#,##0.00",
+        );
+        let err = Locale::from_cldr_json(&json).unwrap_err();
+        assert!(err.to_string().contains("numbering system"));
+    }
+
+    #[test]
+    fn test_load_cldr_missing_file() {
+        let err = Locale::load_cldr("/nonexistent/numbers.json").unwrap_err();
+        assert!(matches!(err, crate::MoneyError::ParseError { .. }));
+    }
+}
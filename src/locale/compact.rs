@@ -0,0 +1,327 @@
+//! Abbreviations for compact amounts (1.2K, 1,2 Mio., 1.2万).
+//!
+//! The units follow the short and long decimal formats of the Unicode CLDR.
+//! Suffixes carry their own leading space, written as U+00A0 (no-break
+//! space), so that languages that attach them directly (Japanese, Chinese,
+//! Korean, Indian English) need no special casing.
+
+use super::plural::{PluralCategory, PluralRule};
+use super::{Locale, Subtags};
+use crate::CompactStyle;
+
+/// One power of ten that has a name in some language.
+pub(crate) struct Unit {
+    /// The power of ten the unit stands for
+    pub(crate) exponent: u32,
+    /// Suffix in the short style; empty if the short style does not
+    /// abbreviate this magnitude
+    short: &'static str,
+    /// Suffix in the long style for a count of one
+    long_one: &'static str,
+    /// Suffix in the long style for other counts
+    long_other: &'static str,
+}
+
+impl Unit {
+    const fn new(exponent: u32, short: &'static str, long: &'static str) -> Self {
+        Self::plural(exponent, short, long, long)
+    }
+
+    const fn plural(
+        exponent: u32,
+        short: &'static str,
+        long_one: &'static str,
+        long_other: &'static str,
+    ) -> Self {
+        Self {
+            exponent,
+            short,
+            long_one,
+            long_other,
+        }
+    }
+
+    /// Returns whether the style has a suffix for this unit.
+    pub(crate) fn applies(&self, style: CompactStyle) -> bool {
+        match style {
+            CompactStyle::Short => !self.short.is_empty(),
+            CompactStyle::Long => !self.long_other.is_empty(),
+        }
+    }
+}
+
+/// Compact units of one language, in ascending order.
+pub(crate) struct CompactRules {
+    units: &'static [Unit],
+    plural: PluralRule,
+}
+
+impl CompactRules {
+    /// Returns the largest unit not above `exponent` that the style names.
+    pub(crate) fn unit_for(&self, exponent: u32, style: CompactStyle) -> Option<&'static Unit> {
+        self.units
+            .iter()
+            .rev()
+            .find(|unit| unit.exponent <= exponent && unit.applies(style))
+    }
+
+    /// Returns the suffix for `unit` after the plain number `count`.
+    pub(crate) fn suffix(&self, unit: &Unit, style: CompactStyle, count: &str) -> &'static str {
+        if style == CompactStyle::Short {
+            return unit.short;
+        }
+        match self.plural.category(count) {
+            PluralCategory::One => unit.long_one,
+            _ => unit.long_other,
+        }
+    }
+}
+
+const ENGLISH: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "K", "\u{a0}thousand"),
+        Unit::new(6, "M", "\u{a0}million"),
+        Unit::new(9, "B", "\u{a0}billion"),
+        Unit::new(12, "T", "\u{a0}trillion"),
+    ],
+    plural: PluralRule::One,
+};
+
+const INDIAN_ENGLISH: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "K", "\u{a0}thousand"),
+        Unit::new(5, "L", "\u{a0}lakh"),
+        Unit::new(7, "Cr", "\u{a0}crore"),
+    ],
+    plural: PluralRule::One,
+};
+
+const HINDI: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "\u{a0}हज़ार", "\u{a0}हज़ार"),
+        Unit::new(5, "\u{a0}लाख", "\u{a0}लाख"),
+        Unit::new(7, "\u{a0}क॰", "\u{a0}करोड़"),
+    ],
+    plural: PluralRule::One,
+};
+
+const MARATHI: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "\u{a0}ह", "\u{a0}हजार"),
+        Unit::new(5, "\u{a0}लाख", "\u{a0}लाख"),
+        Unit::new(7, "\u{a0}कोटी", "\u{a0}कोटी"),
+    ],
+    plural: PluralRule::One,
+};
+
+const BENGALI: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "\u{a0}হা", "\u{a0}হাজার"),
+        Unit::new(5, "\u{a0}লা", "\u{a0}লাখ"),
+        Unit::new(7, "\u{a0}কো", "\u{a0}কোটি"),
+    ],
+    plural: PluralRule::One,
+};
+
+const GERMAN: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "", "\u{a0}Tausend"),
+        Unit::plural(6, "\u{a0}Mio.", "\u{a0}Million", "\u{a0}Millionen"),
+        Unit::plural(9, "\u{a0}Mrd.", "\u{a0}Milliarde", "\u{a0}Milliarden"),
+        Unit::plural(12, "\u{a0}Bio.", "\u{a0}Billion", "\u{a0}Billionen"),
+    ],
+    plural: PluralRule::One,
+};
+
+const FRENCH: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "\u{a0}k", "\u{a0}mille"),
+        Unit::plural(6, "\u{a0}M", "\u{a0}million", "\u{a0}millions"),
+        Unit::plural(9, "\u{a0}Md", "\u{a0}milliard", "\u{a0}milliards"),
+        Unit::plural(12, "\u{a0}Bn", "\u{a0}billion", "\u{a0}billions"),
+    ],
+    plural: PluralRule::BelowTwo,
+};
+
+const SPANISH: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "\u{a0}mil", "\u{a0}mil"),
+        Unit::plural(6, "\u{a0}M", "\u{a0}millón", "\u{a0}millones"),
+        Unit::new(9, "\u{a0}mil\u{a0}M", "\u{a0}mil\u{a0}millones"),
+        Unit::plural(12, "\u{a0}B", "\u{a0}billón", "\u{a0}billones"),
+    ],
+    plural: PluralRule::One,
+};
+
+const PORTUGUESE: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "\u{a0}mil", "\u{a0}mil"),
+        Unit::plural(6, "\u{a0}mi", "\u{a0}milhão", "\u{a0}milhões"),
+        Unit::plural(9, "\u{a0}bi", "\u{a0}bilhão", "\u{a0}bilhões"),
+        Unit::plural(12, "\u{a0}tri", "\u{a0}trilhão", "\u{a0}trilhões"),
+    ],
+    plural: PluralRule::One,
+};
+
+const ITALIAN: CompactRules = CompactRules {
+    units: &[
+        Unit::plural(3, "", "\u{a0}mille", "\u{a0}mila"),
+        Unit::plural(6, "\u{a0}Mln", "\u{a0}milione", "\u{a0}milioni"),
+        Unit::plural(9, "\u{a0}Mrd", "\u{a0}miliardo", "\u{a0}miliardi"),
+        Unit::plural(
+            12,
+            "\u{a0}Bln",
+            "\u{a0}mille\u{a0}miliardi",
+            "\u{a0}mila\u{a0}miliardi",
+        ),
+    ],
+    plural: PluralRule::One,
+};
+
+const DUTCH: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "K", "\u{a0}duizend"),
+        Unit::new(6, "\u{a0}mln.", "\u{a0}miljoen"),
+        Unit::new(9, "\u{a0}mld.", "\u{a0}miljard"),
+        Unit::new(12, "\u{a0}bln.", "\u{a0}biljoen"),
+    ],
+    plural: PluralRule::One,
+};
+
+const JAPANESE: CompactRules = CompactRules {
+    units: &[
+        Unit::new(4, "万", "万"),
+        Unit::new(8, "億", "億"),
+        Unit::new(12, "兆", "兆"),
+    ],
+    plural: PluralRule::One,
+};
+
+const SIMPLIFIED_CHINESE: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "千", "千"),
+        Unit::new(4, "万", "万"),
+        Unit::new(8, "亿", "亿"),
+        Unit::new(12, "万亿", "万亿"),
+    ],
+    plural: PluralRule::One,
+};
+
+const TRADITIONAL_CHINESE: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "千", "千"),
+        Unit::new(4, "萬", "萬"),
+        Unit::new(8, "億", "億"),
+        Unit::new(12, "兆", "兆"),
+    ],
+    plural: PluralRule::One,
+};
+
+const KOREAN: CompactRules = CompactRules {
+    units: &[
+        Unit::new(3, "천", "천"),
+        Unit::new(4, "만", "만"),
+        Unit::new(8, "억", "억"),
+        Unit::new(12, "조", "조"),
+    ],
+    plural: PluralRule::One,
+};
+
+/// Compact rules by tag; languages without an entry use English.
+const RULES: &[(&str, &CompactRules)] = &[
+    ("en", &ENGLISH),
+    ("en-IN", &INDIAN_ENGLISH),
+    ("hi", &HINDI),
+    ("mr", &MARATHI),
+    ("bn", &BENGALI),
+    ("de", &GERMAN),
+    ("fr", &FRENCH),
+    ("es", &SPANISH),
+    ("pt", &PORTUGUESE),
+    ("it", &ITALIAN),
+    ("nl", &DUTCH),
+    ("ja", &JAPANESE),
+    ("zh", &SIMPLIFIED_CHINESE),
+    ("zh-Hant", &TRADITIONAL_CHINESE),
+    ("zh-TW", &TRADITIONAL_CHINESE),
+    ("zh-HK", &TRADITIONAL_CHINESE),
+    ("ko", &KOREAN),
+];
+
+impl Locale {
+    /// Returns the compact units for this locale's language.
+    pub(crate) fn compact_rules(&self) -> &'static CompactRules {
+        Subtags::parse(&self.tag)
+            .into_iter()
+            .flat_map(|subtags| subtags.fallback_chain())
+            .find_map(|candidate| {
+                RULES
+                    .iter()
+                    .find(|(tag, _)| *tag == candidate)
+                    .map(|(_, rules)| *rules)
+            })
+            .unwrap_or(&ENGLISH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_units_ascend() {
+        for (tag, rules) in RULES {
+            assert!(
+                rules
+                    .units
+                    .windows(2)
+                    .all(|w| w[0].exponent < w[1].exponent),
+                "{}",
+                tag
+            );
+        }
+    }
+
+    #[test]
+    fn test_rules_lookup() {
+        let rules = |tag| Locale::parse(tag).unwrap().compact_rules();
+        let short = |tag, exponent| {
+            let unit = rules(tag).unit_for(exponent, CompactStyle::Short).unwrap();
+            unit.short
+        };
+        assert_eq!(short("en-IN", 5), "L");
+        assert_eq!(short("en-US", 5), "K");
+        assert_eq!(short("de-AT", 6), "\u{a0}Mio.");
+        assert_eq!(short("zh-Hant-HK", 8), "億");
+        assert_eq!(short("zh-CN", 8), "亿");
+        assert_eq!(short("sv-SE", 6), "M");
+        assert!(rules("de").unit_for(3, CompactStyle::Short).is_none());
+    }
+
+    #[test]
+    fn test_plural_suffix() {
+        let million = &GERMAN.units[1];
+        assert_eq!(
+            GERMAN.suffix(million, CompactStyle::Long, "1"),
+            "\u{a0}Million"
+        );
+        assert_eq!(
+            GERMAN.suffix(million, CompactStyle::Long, "1.5"),
+            "\u{a0}Millionen"
+        );
+        assert_eq!(
+            GERMAN.suffix(million, CompactStyle::Short, "1"),
+            "\u{a0}Mio."
+        );
+
+        let million = &FRENCH.units[1];
+        assert_eq!(
+            FRENCH.suffix(million, CompactStyle::Long, "1.5"),
+            "\u{a0}million"
+        );
+        assert_eq!(
+            FRENCH.suffix(million, CompactStyle::Long, "2"),
+            "\u{a0}millions"
+        );
+    }
+}
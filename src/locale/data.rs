@@ -0,0 +1,282 @@
+//! Built-in locale formatting rules.
+//!
+//! The values follow the standard currency patterns of the Unicode CLDR.
+//! Spaces between symbol and number are always written as U+00A0 (no-break
+//! space); grouping separators use whatever space character CLDR specifies.
+
+use super::{
+    Locale, NegativePattern, ARABIC_DIGITS, BENGALI_DIGITS, DEVANAGARI_DIGITS, LATIN_DIGITS,
+    PERSIAN_DIGITS,
+};
+use crate::{Grouping, SymbolPosition};
+
+const NBSP: char = '\u{a0}';
+const NNBSP: char = '\u{202f}';
+const MINUS: char = '\u{2212}';
+const APOSTROPHE: char = '\u{2019}';
+const ARABIC_DECIMAL: char = '\u{66b}';
+const ARABIC_GROUP: char = '\u{66c}';
+
+/// Groups in threes, but only from five integer digits on (1234, 12.345).
+const MIN_TWO: Grouping = Grouping::THOUSANDS.with_min_grouping(2);
+
+use SymbolPosition::{After, Before};
+
+/// Formatting rules for one built-in locale.
+pub(super) struct Entry {
+    tag: &'static str,
+    decimal_separator: char,
+    grouping_separator: char,
+    grouping: Grouping,
+    minus_sign: char,
+    symbol_position: SymbolPosition,
+    symbol_spacing: bool,
+    negative_pattern: NegativePattern,
+    digits: [char; 10],
+}
+
+impl Entry {
+    const fn new(
+        tag: &'static str,
+        decimal_separator: char,
+        grouping_separator: char,
+        symbol_position: SymbolPosition,
+        symbol_spacing: bool,
+    ) -> Self {
+        Self {
+            tag,
+            decimal_separator,
+            grouping_separator,
+            grouping: Grouping::THOUSANDS,
+            minus_sign: '-',
+            symbol_position,
+            symbol_spacing,
+            negative_pattern: NegativePattern::Leading,
+            digits: LATIN_DIGITS,
+        }
+    }
+
+    const fn grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    const fn minus(mut self, minus_sign: char) -> Self {
+        self.minus_sign = minus_sign;
+        self
+    }
+
+    const fn negative(mut self, pattern: NegativePattern) -> Self {
+        self.negative_pattern = pattern;
+        self
+    }
+
+    const fn digits(mut self, digits: [char; 10]) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    pub(super) fn to_locale(&self) -> Locale {
+        Locale {
+            tag: self.tag.to_string(),
+            resolved: self.tag.to_string(),
+            decimal_separator: self.decimal_separator,
+            grouping_separator: self.grouping_separator,
+            grouping: self.grouping,
+            minus_sign: self.minus_sign,
+            symbol_position: self.symbol_position,
+            symbol_spacing: self.symbol_spacing,
+            negative_pattern: self.negative_pattern,
+            digits: self.digits,
+        }
+    }
+}
+
+const LOCALES: &[Entry] = &[
+    // English
+    Entry::new("en-US", '.', ',', Before, false),
+    Entry::new("en-GB", '.', ',', Before, false),
+    Entry::new("en-AU", '.', ',', Before, false),
+    Entry::new("en-CA", '.', ',', Before, false),
+    Entry::new("en-IE", '.', ',', Before, false),
+    Entry::new("en-IN", '.', ',', Before, false).grouping(Grouping::INDIAN),
+    Entry::new("en-NZ", '.', ',', Before, false),
+    Entry::new("en-SG", '.', ',', Before, false),
+    Entry::new("en-ZA", ',', NBSP, Before, false),
+    // Germanic
+    Entry::new("de-DE", ',', '.', After, true),
+    Entry::new("de-AT", ',', NBSP, Before, true),
+    Entry::new("de-CH", '.', APOSTROPHE, Before, true).negative(NegativePattern::AfterSymbol),
+    Entry::new("nl-NL", ',', '.', Before, true).negative(NegativePattern::AfterSymbol),
+    Entry::new("nl-BE", ',', '.', Before, true).negative(NegativePattern::AfterSymbol),
+    Entry::new("af-ZA", ',', NBSP, Before, false),
+    // Romance
+    Entry::new("fr-FR", ',', NNBSP, After, true),
+    Entry::new("fr-BE", ',', NNBSP, After, true),
+    Entry::new("fr-CA", ',', NBSP, After, true),
+    Entry::new("fr-CH", ',', NNBSP, After, true),
+    Entry::new("es-ES", ',', '.', After, true).grouping(MIN_TWO),
+    Entry::new("es-MX", '.', ',', Before, false),
+    Entry::new("es-AR", ',', '.', Before, true),
+    Entry::new("es-CL", ',', '.', Before, false).negative(NegativePattern::AfterSymbol),
+    Entry::new("es-CO", ',', '.', Before, true),
+    Entry::new("es-419", '.', ',', Before, false),
+    Entry::new("pt-BR", ',', '.', Before, true),
+    Entry::new("pt-PT", ',', NBSP, After, true).grouping(MIN_TWO),
+    Entry::new("it-IT", ',', '.', After, true),
+    Entry::new("it-CH", '.', APOSTROPHE, Before, true).negative(NegativePattern::AfterSymbol),
+    Entry::new("ro-RO", ',', '.', After, true),
+    // Nordic
+    Entry::new("sv-SE", ',', NBSP, After, true).minus(MINUS),
+    Entry::new("nb-NO", ',', NBSP, After, true).minus(MINUS),
+    Entry::new("da-DK", ',', '.', After, true),
+    Entry::new("fi-FI", ',', NBSP, After, true).minus(MINUS),
+    Entry::new("is-IS", ',', '.', After, true),
+    // Central and Eastern European
+    Entry::new("pl-PL", ',', NBSP, After, true).grouping(MIN_TWO),
+    Entry::new("cs-CZ", ',', NBSP, After, true),
+    Entry::new("sk-SK", ',', NBSP, After, true),
+    Entry::new("hu-HU", ',', NBSP, After, true),
+    Entry::new("bg-BG", ',', NBSP, After, true).grouping(MIN_TWO),
+    Entry::new("hr-HR", ',', '.', After, true).minus(MINUS),
+    Entry::new("sr-RS", ',', '.', After, true),
+    Entry::new("sl-SI", ',', '.', After, true).minus(MINUS),
+    Entry::new("el-GR", ',', '.', After, true),
+    Entry::new("ru-RU", ',', NBSP, After, true),
+    Entry::new("uk-UA", ',', NBSP, After, true),
+    Entry::new("tr-TR", ',', '.', Before, false),
+    // Middle East and North Africa
+    Entry::new("he-IL", '.', ',', After, true),
+    Entry::new("ar-EG", ARABIC_DECIMAL, ARABIC_GROUP, After, true).digits(ARABIC_DIGITS),
+    Entry::new("ar-SA", ARABIC_DECIMAL, ARABIC_GROUP, After, true).digits(ARABIC_DIGITS),
+    Entry::new("ar-AE", '.', ',', After, true),
+    Entry::new("ar-MA", ',', '.', After, true),
+    Entry::new("fa-IR", ARABIC_DECIMAL, ARABIC_GROUP, Before, false)
+        .minus(MINUS)
+        .digits(PERSIAN_DIGITS),
+    // South Asia
+    Entry::new("hi-IN", '.', ',', Before, false).grouping(Grouping::INDIAN),
+    Entry::new("mr-IN", '.', ',', Before, false)
+        .grouping(Grouping::INDIAN)
+        .digits(DEVANAGARI_DIGITS),
+    Entry::new("bn-BD", '.', ',', After, false)
+        .grouping(Grouping::INDIAN)
+        .digits(BENGALI_DIGITS),
+    Entry::new("ur-PK", '.', ',', Before, true),
+    // East and Southeast Asia
+    Entry::new("ja-JP", '.', ',', Before, false),
+    Entry::new("zh-CN", '.', ',', Before, false),
+    Entry::new("zh-TW", '.', ',', Before, false),
+    Entry::new("zh-HK", '.', ',', Before, false),
+    Entry::new("ko-KR", '.', ',', Before, false),
+    Entry::new("th-TH", '.', ',', Before, false),
+    Entry::new("vi-VN", ',', '.', After, true),
+    Entry::new("id-ID", ',', '.', Before, false),
+    Entry::new("ms-MY", '.', ',', Before, false),
+    Entry::new("fil-PH", '.', ',', Before, false),
+    // Sub-Saharan Africa
+    Entry::new("sw-KE", '.', ',', Before, true),
+];
+
+/// Default regions for bare languages and scripts, plus legacy codes.
+const ALIASES: &[(&str, &str)] = &[
+    ("af", "af-ZA"),
+    ("ar", "ar-EG"),
+    ("bg", "bg-BG"),
+    ("bn", "bn-BD"),
+    ("cs", "cs-CZ"),
+    ("da", "da-DK"),
+    ("de", "de-DE"),
+    ("el", "el-GR"),
+    ("en", "en-US"),
+    ("es", "es-ES"),
+    ("fa", "fa-IR"),
+    ("fi", "fi-FI"),
+    ("fil", "fil-PH"),
+    ("fr", "fr-FR"),
+    ("he", "he-IL"),
+    ("hi", "hi-IN"),
+    ("hr", "hr-HR"),
+    ("hu", "hu-HU"),
+    ("id", "id-ID"),
+    ("in", "id-ID"),
+    ("is", "is-IS"),
+    ("it", "it-IT"),
+    ("iw", "he-IL"),
+    ("ja", "ja-JP"),
+    ("ko", "ko-KR"),
+    ("mr", "mr-IN"),
+    ("ms", "ms-MY"),
+    ("nb", "nb-NO"),
+    ("nl", "nl-NL"),
+    ("nn", "nb-NO"),
+    ("no", "nb-NO"),
+    ("pl", "pl-PL"),
+    ("pt", "pt-BR"),
+    ("ro", "ro-RO"),
+    ("ru", "ru-RU"),
+    ("sk", "sk-SK"),
+    ("sl", "sl-SI"),
+    ("sr", "sr-RS"),
+    ("sv", "sv-SE"),
+    ("sw", "sw-KE"),
+    ("th", "th-TH"),
+    ("tl", "fil-PH"),
+    ("tr", "tr-TR"),
+    ("uk", "uk-UA"),
+    ("ur", "ur-PK"),
+    ("vi", "vi-VN"),
+    ("zh", "zh-CN"),
+    ("zh-Hans", "zh-CN"),
+    ("zh-Hant", "zh-TW"),
+];
+
+/// Finds the rules for an exact, normalized tag.
+pub(super) fn lookup(tag: &str) -> Option<&'static Entry> {
+    let tag = ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == tag)
+        .map_or(tag, |(_, target)| target);
+    LOCALES.iter().find(|entry| entry.tag == tag)
+}
+
+pub(super) fn tags() -> impl Iterator<Item = &'static str> {
+    LOCALES.iter().map(|entry| entry.tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_are_unique_and_normalized() {
+        for (i, entry) in LOCALES.iter().enumerate() {
+            assert!(
+                LOCALES[i + 1..].iter().all(|other| other.tag != entry.tag),
+                "duplicate {}",
+                entry.tag
+            );
+            let normalized = super::super::Subtags::parse(entry.tag).unwrap().to_string();
+            assert_eq!(normalized, entry.tag);
+        }
+    }
+
+    #[test]
+    fn test_aliases_point_at_entries() {
+        for (alias, target) in ALIASES {
+            assert!(lookup(target).is_some(), "{} -> {}", alias, target);
+            assert!(LOCALES.iter().all(|entry| entry.tag != *alias));
+        }
+    }
+
+    #[test]
+    fn test_separators_differ() {
+        for entry in LOCALES {
+            assert_ne!(
+                entry.decimal_separator, entry.grouping_separator,
+                "{}",
+                entry.tag
+            );
+        }
+    }
+}
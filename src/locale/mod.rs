@@ -0,0 +1,525 @@
+//! Locale-aware number and currency formatting rules.
+//!
+//! A [`Locale`] bundles the conventions a region uses to write amounts of
+//! money: decimal and grouping separators, the digit grouping pattern, where
+//! the currency symbol goes and whether it is spaced off, how negative
+//! amounts are written, and which digits are used.
+//!
+//! Rules for more than 40 locales are built in. [`Locale::parse`] accepts
+//! BCP-47 tags as well as POSIX-style names (`pt_BR.UTF-8`) and falls back
+//! from the most specific tag to the bare language, so `de-LU` resolves to
+//! German and `zh-Hant-HK` to Traditional Chinese.
+//!
+//! With the `cldr_json` feature, locales can also be loaded from the
+//! `numbers.json` files of the Unicode CLDR JSON distribution.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::{Amount, Locale, EUR, INR, USD};
+//!
+//! let usd = Amount::<USD>::from_minor(123_456);
+//! assert_eq!(usd.format_in(&Locale::parse("en-US")?), "$1,234.56");
+//!
+//! // Spaces around the symbol are non-breaking
+//! let eur = Amount::<EUR>::from_minor(123_456);
+//! assert_eq!(eur.format_in(&Locale::parse("de-DE")?), "1.234,56\u{a0}€");
+//! assert_eq!(eur.format_in(&Locale::parse("pt-BR")?), "€\u{a0}1.234,56");
+//!
+//! let inr = Amount::<INR>::from_minor(1_234_567_89);
+//! assert_eq!(inr.format_in(&Locale::parse("en-IN")?), "₹12,34,567.89");
+//! # Ok::<(), typed_money::MoneyError>(())
+//! ```
+
+#[cfg(feature = "cldr_json")]
+mod cldr;
+mod compact;
+mod data;
+mod names;
+mod plural;
+
+use crate::{Grouping, MoneyError, MoneyResult, SymbolPosition};
+
+/// How a locale writes negative amounts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NegativePattern {
+    /// The minus sign leads the whole amount (-$1.00, -1,00 €)
+    Leading,
+    /// The minus sign goes between a leading symbol and the number
+    /// (€ -1,00); behaves like `Leading` when the symbol trails
+    AfterSymbol,
+    /// The amount is wrapped in parentheses (($1.00))
+    Parentheses,
+}
+
+/// Decimal digits 0-9 in Latin script.
+pub const LATIN_DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+/// Decimal digits 0-9 in Arabic-Indic script.
+pub const ARABIC_DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+
+/// Decimal digits 0-9 in Extended Arabic-Indic (Persian) script.
+pub const PERSIAN_DIGITS: [char; 10] = ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'];
+
+/// Decimal digits 0-9 in Devanagari script.
+pub const DEVANAGARI_DIGITS: [char; 10] = ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'];
+
+/// Decimal digits 0-9 in Bengali script.
+pub const BENGALI_DIGITS: [char; 10] = ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'];
+
+/// Formatting rules for writing amounts of money in a particular locale.
+///
+/// Obtain a locale with [`Locale::parse`] and fine-tune it with the `with_*`
+/// methods. The currency symbol itself always comes from the currency; the
+/// locale only decides where it goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale {
+    tag: String,
+    resolved: String,
+    decimal_separator: char,
+    grouping_separator: char,
+    grouping: Grouping,
+    minus_sign: char,
+    symbol_position: SymbolPosition,
+    symbol_spacing: bool,
+    negative_pattern: NegativePattern,
+    digits: [char; 10],
+}
+
+impl Locale {
+    /// Parses a locale tag and looks up its formatting rules.
+    ///
+    /// Tags are matched case-insensitively, `_` is accepted in place of `-`,
+    /// and POSIX suffixes such as `.UTF-8` or `@euro` are ignored, as are
+    /// BCP-47 variants and extensions. If there are no rules for the exact
+    /// tag, the lookup falls back through language-script-region,
+    /// language-region, language-script and finally the bare language.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::ParseError`] if the tag is malformed or no
+    /// built-in locale matches its language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Locale;
+    ///
+    /// let locale = Locale::parse("pt_br.UTF-8")?;
+    /// assert_eq!(locale.tag(), "pt-BR");
+    /// assert_eq!(locale.decimal_separator(), ',');
+    ///
+    /// // No rules for Luxembourgish German, so German rules are used
+    /// let locale = Locale::parse("de-LU")?;
+    /// assert_eq!(locale.resolved_tag(), "de");
+    ///
+    /// assert!(Locale::parse("xx-YY").is_err());
+    /// assert!(Locale::parse("").is_err());
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn parse(tag: &str) -> MoneyResult<Self> {
+        let subtags =
+            Subtags::parse(tag).ok_or_else(|| parse_error(tag, "malformed locale tag"))?;
+        let normalized = subtags.to_string();
+
+        for candidate in subtags.fallback_chain() {
+            if let Some(entry) = data::lookup(&candidate) {
+                let mut locale = entry.to_locale();
+                locale.tag = normalized;
+                locale.resolved = candidate;
+                return Ok(locale);
+            }
+        }
+
+        Err(parse_error(tag, "no formatting rules for this locale"))
+    }
+
+    /// Returns the tags of all built-in locales.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Locale;
+    ///
+    /// assert!(Locale::builtin_tags().count() >= 40);
+    /// assert!(Locale::builtin_tags().any(|tag| tag == "en-IN"));
+    /// ```
+    pub fn builtin_tags() -> impl Iterator<Item = &'static str> {
+        data::tags()
+    }
+
+    /// Returns the normalized tag that was requested, e.g. `pt-BR`.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the tag whose rules were actually used after fallback.
+    pub fn resolved_tag(&self) -> &str {
+        &self.resolved
+    }
+
+    /// Returns the region subtag of the requested tag (e.g. `CA` for
+    /// `fr-CA`), if it has one.
+    pub fn region(&self) -> Option<String> {
+        Subtags::parse(&self.tag).and_then(|subtags| subtags.region)
+    }
+
+    /// Returns the decimal separator.
+    pub const fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Returns the grouping (thousands) separator.
+    pub const fn grouping_separator(&self) -> char {
+        self.grouping_separator
+    }
+
+    /// Returns the digit grouping pattern.
+    pub const fn grouping(&self) -> Grouping {
+        self.grouping
+    }
+
+    /// Returns the minus sign used for negative amounts.
+    pub const fn minus_sign(&self) -> char {
+        self.minus_sign
+    }
+
+    /// Returns where the currency symbol is placed.
+    pub const fn symbol_position(&self) -> SymbolPosition {
+        self.symbol_position
+    }
+
+    /// Returns `true` if a non-breaking space separates symbol and number.
+    pub const fn symbol_spacing(&self) -> bool {
+        self.symbol_spacing
+    }
+
+    /// Returns how negative amounts are written.
+    pub const fn negative_pattern(&self) -> NegativePattern {
+        self.negative_pattern
+    }
+
+    /// Returns the digits 0-9 used by this locale.
+    pub const fn digits(&self) -> [char; 10] {
+        self.digits
+    }
+
+    /// Returns the locale with a different decimal separator.
+    pub fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Returns the locale with a different grouping separator.
+    pub fn with_grouping_separator(mut self, separator: char) -> Self {
+        self.grouping_separator = separator;
+        self
+    }
+
+    /// Returns the locale with a different digit grouping pattern.
+    pub fn with_grouping(mut self, grouping: Grouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Returns the locale with a different minus sign.
+    pub fn with_minus_sign(mut self, minus_sign: char) -> Self {
+        self.minus_sign = minus_sign;
+        self
+    }
+
+    /// Returns the locale with a different symbol placement.
+    pub fn with_symbol_position(mut self, position: SymbolPosition, spacing: bool) -> Self {
+        self.symbol_position = position;
+        self.symbol_spacing = spacing;
+        self
+    }
+
+    /// Returns the locale with a different negative pattern.
+    pub fn with_negative_pattern(mut self, pattern: NegativePattern) -> Self {
+        self.negative_pattern = pattern;
+        self
+    }
+
+    /// Returns the locale with a different digit set.
+    pub fn with_digits(mut self, digits: [char; 10]) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Formats a plain decimal string such as `-1234.50` following this
+    /// locale, placing `symbol` according to its rules.
+    pub(crate) fn format_number(&self, plain: &str, symbol: &str) -> String {
+        self.format_number_with_suffix(plain, "", symbol)
+    }
+
+    /// Like [`format_number`](Self::format_number), with `suffix` (such as a
+    /// compact unit) written right after the digits.
+    pub(crate) fn format_number_with_suffix(
+        &self,
+        plain: &str,
+        suffix: &str,
+        symbol: &str,
+    ) -> String {
+        let (negative, unsigned) = split_sign(plain);
+        let mut number = self.localize_digits(unsigned);
+        number.push_str(suffix);
+
+        let space = if self.symbol_spacing { "\u{a0}" } else { "" };
+        let minus = self.minus_sign.to_string();
+        let (outer_sign, inner_sign) = match (negative, self.negative_pattern) {
+            (false, _) | (true, NegativePattern::Parentheses) => ("", ""),
+            (true, NegativePattern::AfterSymbol)
+                if self.symbol_position == SymbolPosition::Before =>
+            {
+                ("", minus.as_str())
+            }
+            (true, _) => (minus.as_str(), ""),
+        };
+        let body = match self.symbol_position {
+            SymbolPosition::Before => format!("{}{}{}{}", symbol, space, inner_sign, number),
+            SymbolPosition::After => format!("{}{}{}", number, space, symbol),
+        };
+
+        if negative && self.negative_pattern == NegativePattern::Parentheses {
+            format!("({})", body)
+        } else {
+            format!("{}{}", outer_sign, body)
+        }
+    }
+
+    /// Groups and translates the digits of an unsigned plain decimal string.
+    fn localize_digits(&self, unsigned: &str) -> String {
+        let (integer, fraction) = match unsigned.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut number = self.grouping.apply(integer, self.grouping_separator);
+        if let Some(fraction) = fraction {
+            number.push(self.decimal_separator);
+            number.push_str(fraction);
+        }
+        number
+            .chars()
+            .map(|ch| match ch.to_digit(10) {
+                Some(digit) if ch.is_ascii_digit() => self.digits[digit as usize],
+                _ => ch,
+            })
+            .collect()
+    }
+}
+
+/// Splits a plain decimal string into its sign and unsigned digits.
+fn split_sign(plain: &str) -> (bool, &str) {
+    match plain.strip_prefix('-') {
+        // Amounts that display as zero never get a sign
+        Some(rest) => (rest.chars().any(|ch| matches!(ch, '1'..='9')), rest),
+        None => (false, plain),
+    }
+}
+
+/// The language, script and region subtags of a locale tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Subtags {
+    language: String,
+    script: Option<String>,
+    region: Option<String>,
+}
+
+impl Subtags {
+    fn parse(tag: &str) -> Option<Self> {
+        // POSIX names may carry an encoding or modifier: de_DE.UTF-8@euro
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        let mut parts = tag.split(['-', '_']);
+
+        let language = parts.next()?;
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            return None;
+        }
+
+        let mut script = None;
+        let mut region = None;
+        for part in parts {
+            let alphabetic = part.chars().all(|c| c.is_ascii_alphabetic());
+            if script.is_none() && region.is_none() && part.len() == 4 && alphabetic {
+                let mut chars = part.chars();
+                let first = chars.next()?.to_ascii_uppercase();
+                script = Some(
+                    std::iter::once(first)
+                        .chain(chars.map(|c| c.to_ascii_lowercase()))
+                        .collect(),
+                );
+            } else if region.is_none() && part.len() == 2 && alphabetic {
+                region = Some(part.to_ascii_uppercase());
+            } else if region.is_none()
+                && part.len() == 3
+                && part.chars().all(|c| c.is_ascii_digit())
+            {
+                region = Some(part.to_string());
+            } else {
+                // Variants and extensions do not affect number formatting
+                break;
+            }
+        }
+
+        Some(Self {
+            language: language.to_ascii_lowercase(),
+            script,
+            region,
+        })
+    }
+
+    /// Returns candidate tags from most to least specific.
+    fn fallback_chain(&self) -> Vec<String> {
+        let language = self.language.as_str();
+        let mut chain = Vec::with_capacity(4);
+        if let (Some(script), Some(region)) = (&self.script, &self.region) {
+            chain.push(format!("{}-{}-{}", language, script, region));
+        }
+        if let Some(region) = &self.region {
+            chain.push(format!("{}-{}", language, region));
+        }
+        if let Some(script) = &self.script {
+            chain.push(format!("{}-{}", language, script));
+        }
+        chain.push(language.to_string());
+        chain
+    }
+}
+
+impl std::fmt::Display for Subtags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.language)?;
+        if let Some(script) = &self.script {
+            write!(f, "-{}", script)?;
+        }
+        if let Some(region) = &self.region {
+            write!(f, "-{}", region)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_error(input: &str, reason: &str) -> MoneyError {
+    MoneyError::ParseError {
+        input: input.to_string(),
+        expected_currency: None,
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_normalizes_tags() {
+        assert_eq!(Locale::parse("pt-BR").unwrap().tag(), "pt-BR");
+        assert_eq!(Locale::parse("PT_br").unwrap().tag(), "pt-BR");
+        assert_eq!(Locale::parse("de_DE.UTF-8@euro").unwrap().tag(), "de-DE");
+        assert_eq!(Locale::parse("zh-hant-tw").unwrap().tag(), "zh-Hant-TW");
+        assert_eq!(Locale::parse("es-419").unwrap().tag(), "es-419");
+        assert_eq!(Locale::parse("en-US-u-nu-latn").unwrap().tag(), "en-US");
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_tags() {
+        for tag in ["", "e", "english", "12-US", "-US"] {
+            let err = Locale::parse(tag).unwrap_err();
+            assert!(matches!(err, MoneyError::ParseError { .. }), "{}", tag);
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown_language() {
+        let err = Locale::parse("tlh-KX").unwrap_err();
+        assert!(err.to_string().contains("no formatting rules"));
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let subtags = Subtags::parse("zh-Hant-HK").unwrap();
+        assert_eq!(
+            subtags.fallback_chain(),
+            ["zh-Hant-HK", "zh-HK", "zh-Hant", "zh"]
+        );
+    }
+
+    #[test]
+    fn test_fallback_resolution() {
+        assert_eq!(Locale::parse("de-LU").unwrap().resolved_tag(), "de");
+        assert_eq!(
+            Locale::parse("zh-Hant-MO").unwrap().resolved_tag(),
+            "zh-Hant"
+        );
+        assert_eq!(Locale::parse("en-IN").unwrap().resolved_tag(), "en-IN");
+        assert_eq!(Locale::parse("fr").unwrap().resolved_tag(), "fr");
+    }
+
+    #[test]
+    fn test_at_least_forty_locales() {
+        assert!(Locale::builtin_tags().count() >= 40);
+        for tag in Locale::builtin_tags() {
+            let locale = Locale::parse(tag).unwrap();
+            assert_eq!(locale.resolved_tag(), tag);
+        }
+    }
+
+    #[test]
+    fn test_format_number_patterns() {
+        let en = Locale::parse("en-US").unwrap();
+        assert_eq!(en.format_number("1234567.89", "$"), "$1,234,567.89");
+        assert_eq!(en.format_number("-5.00", "$"), "-$5.00");
+        assert_eq!(en.format_number("-0.00", "$"), "$0.00");
+
+        let nl = Locale::parse("nl-NL").unwrap();
+        assert_eq!(nl.format_number("-1234.50", "€"), "€\u{a0}-1.234,50");
+
+        let accounting = en.with_negative_pattern(NegativePattern::Parentheses);
+        assert_eq!(accounting.format_number("-5.00", "$"), "($5.00)");
+
+        let sv = Locale::parse("sv-SE").unwrap();
+        assert_eq!(
+            sv.format_number("-1234.50", "kr"),
+            "\u{2212}1\u{a0}234,50\u{a0}kr"
+        );
+    }
+
+    #[test]
+    fn test_format_number_grouping_patterns() {
+        let en_in = Locale::parse("en-IN").unwrap();
+        assert_eq!(en_in.format_number("123456789.00", "₹"), "₹12,34,56,789.00");
+
+        let es = Locale::parse("es-ES").unwrap();
+        assert_eq!(es.format_number("1234.00", "€"), "1234,00\u{a0}€");
+        assert_eq!(es.format_number("12345.00", "€"), "12.345,00\u{a0}€");
+        assert_eq!(es.format_number("-1234567.00", "€"), "-1.234.567,00\u{a0}€");
+
+        let mx = Locale::parse("es-MX").unwrap();
+        assert_eq!(mx.format_number("1234.00", "$"), "$1,234.00");
+    }
+
+    #[test]
+    fn test_format_number_native_digits() {
+        let ar = Locale::parse("ar-EG").unwrap();
+        assert_eq!(ar.format_number("1234.5", "ج.م."), "١٬٢٣٤٫٥\u{a0}ج.م.");
+
+        let bn = Locale::parse("bn").unwrap();
+        assert_eq!(bn.format_number("1234567", "৳"), "১২,৩৪,৫৬৭৳");
+    }
+
+    #[test]
+    fn test_builders() {
+        let locale = Locale::parse("en-US")
+            .unwrap()
+            .with_decimal_separator(',')
+            .with_grouping_separator(' ')
+            .with_grouping(Grouping::INDIAN)
+            .with_minus_sign('−')
+            .with_symbol_position(SymbolPosition::After, true)
+            .with_digits(DEVANAGARI_DIGITS);
+        assert_eq!(locale.format_number("-123456.7", "$"), "−१ २३ ४५६,७\u{a0}$");
+    }
+}
@@ -0,0 +1,1100 @@
+//! Localized currency names and their plural forms.
+//!
+//! Names follow the currency display names of the Unicode CLDR, including
+//! the count-dependent forms used when an amount is written out in full
+//! ("1 US dollar", "2 US dollars", "5 złotych polskich"). Each language
+//! covers the major currencies and those of the regions it is spoken in.
+//! Languages without a table use the English names, and currencies without a
+//! name are written with their ISO code.
+
+use super::plural::{PluralCategory, PluralRule};
+use super::{split_sign, Locale, Subtags};
+
+/// Display name and plural forms of one currency in one language.
+struct Names {
+    display: &'static str,
+    one: &'static str,
+    few: &'static str,
+    many: &'static str,
+    other: &'static str,
+}
+
+impl Names {
+    const fn new(display: &'static str, one: &'static str, other: &'static str) -> Self {
+        Self {
+            display,
+            one,
+            few: other,
+            many: other,
+            other,
+        }
+    }
+
+    /// Names for languages without plural forms.
+    const fn invariant(name: &'static str) -> Self {
+        Self::new(name, name, name)
+    }
+
+    const fn few(mut self, few: &'static str) -> Self {
+        self.few = few;
+        self
+    }
+
+    const fn many(mut self, many: &'static str) -> Self {
+        self.many = many;
+        self
+    }
+
+    fn form(&self, category: PluralCategory) -> &'static str {
+        match category {
+            PluralCategory::One => self.one,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            PluralCategory::Other => self.other,
+        }
+    }
+}
+
+/// Currency names of one language.
+struct NameTable {
+    names: &'static [(&'static str, Names)],
+    plural: PluralRule,
+    /// Whether a no-break space separates the number from the name
+    spaced: bool,
+}
+
+impl NameTable {
+    fn get(&self, code: &str) -> Option<&'static Names> {
+        self.names
+            .iter()
+            .find(|(candidate, _)| *candidate == code)
+            .map(|(_, names)| names)
+    }
+}
+
+const ENGLISH: NameTable = NameTable {
+    names: &[
+        (
+            "AED",
+            Names::new("United Arab Emirates Dirham", "UAE dirham", "UAE dirhams"),
+        ),
+        (
+            "ARS",
+            Names::new("Argentine Peso", "Argentine peso", "Argentine pesos"),
+        ),
+        (
+            "AUD",
+            Names::new(
+                "Australian Dollar",
+                "Australian dollar",
+                "Australian dollars",
+            ),
+        ),
+        (
+            "BGN",
+            Names::new("Bulgarian Lev", "Bulgarian lev", "Bulgarian leva"),
+        ),
+        (
+            "BHD",
+            Names::new("Bahraini Dinar", "Bahraini dinar", "Bahraini dinars"),
+        ),
+        (
+            "BOB",
+            Names::new(
+                "Bolivian Boliviano",
+                "Bolivian boliviano",
+                "Bolivian bolivianos",
+            ),
+        ),
+        (
+            "BRL",
+            Names::new("Brazilian Real", "Brazilian real", "Brazilian reals"),
+        ),
+        (
+            "CAD",
+            Names::new("Canadian Dollar", "Canadian dollar", "Canadian dollars"),
+        ),
+        (
+            "CHF",
+            Names::new("Swiss Franc", "Swiss franc", "Swiss francs"),
+        ),
+        (
+            "CLP",
+            Names::new("Chilean Peso", "Chilean peso", "Chilean pesos"),
+        ),
+        (
+            "CNY",
+            Names::new("Chinese Yuan", "Chinese yuan", "Chinese yuan"),
+        ),
+        (
+            "COP",
+            Names::new("Colombian Peso", "Colombian peso", "Colombian pesos"),
+        ),
+        (
+            "CZK",
+            Names::new("Czech Koruna", "Czech koruna", "Czech korunas"),
+        ),
+        (
+            "DKK",
+            Names::new("Danish Krone", "Danish krone", "Danish kroner"),
+        ),
+        (
+            "EGP",
+            Names::new("Egyptian Pound", "Egyptian pound", "Egyptian pounds"),
+        ),
+        ("EUR", Names::new("Euro", "euro", "euros")),
+        (
+            "GBP",
+            Names::new("British Pound", "British pound", "British pounds"),
+        ),
+        (
+            "GHS",
+            Names::new("Ghanaian Cedi", "Ghanaian cedi", "Ghanaian cedis"),
+        ),
+        (
+            "HKD",
+            Names::new("Hong Kong Dollar", "Hong Kong dollar", "Hong Kong dollars"),
+        ),
+        (
+            "HRK",
+            Names::new("Croatian Kuna", "Croatian kuna", "Croatian kunas"),
+        ),
+        (
+            "HUF",
+            Names::new("Hungarian Forint", "Hungarian forint", "Hungarian forints"),
+        ),
+        (
+            "IDR",
+            Names::new(
+                "Indonesian Rupiah",
+                "Indonesian rupiah",
+                "Indonesian rupiahs",
+            ),
+        ),
+        (
+            "ILS",
+            Names::new(
+                "Israeli New Shekel",
+                "Israeli new shekel",
+                "Israeli new shekels",
+            ),
+        ),
+        (
+            "INR",
+            Names::new("Indian Rupee", "Indian rupee", "Indian rupees"),
+        ),
+        (
+            "JOD",
+            Names::new("Jordanian Dinar", "Jordanian dinar", "Jordanian dinars"),
+        ),
+        (
+            "JPY",
+            Names::new("Japanese Yen", "Japanese yen", "Japanese yen"),
+        ),
+        (
+            "KES",
+            Names::new("Kenyan Shilling", "Kenyan shilling", "Kenyan shillings"),
+        ),
+        (
+            "KRW",
+            Names::new("South Korean Won", "South Korean won", "South Korean won"),
+        ),
+        (
+            "KWD",
+            Names::new("Kuwaiti Dinar", "Kuwaiti dinar", "Kuwaiti dinars"),
+        ),
+        (
+            "MAD",
+            Names::new("Moroccan Dirham", "Moroccan dirham", "Moroccan dirhams"),
+        ),
+        (
+            "MXN",
+            Names::new("Mexican Peso", "Mexican peso", "Mexican pesos"),
+        ),
+        (
+            "MYR",
+            Names::new(
+                "Malaysian Ringgit",
+                "Malaysian ringgit",
+                "Malaysian ringgits",
+            ),
+        ),
+        (
+            "NGN",
+            Names::new("Nigerian Naira", "Nigerian naira", "Nigerian nairas"),
+        ),
+        (
+            "NOK",
+            Names::new("Norwegian Krone", "Norwegian krone", "Norwegian kroner"),
+        ),
+        (
+            "NZD",
+            Names::new(
+                "New Zealand Dollar",
+                "New Zealand dollar",
+                "New Zealand dollars",
+            ),
+        ),
+        ("OMR", Names::new("Omani Rial", "Omani rial", "Omani rials")),
+        (
+            "PEN",
+            Names::new("Peruvian Sol", "Peruvian sol", "Peruvian soles"),
+        ),
+        (
+            "PHP",
+            Names::new("Philippine Peso", "Philippine peso", "Philippine pesos"),
+        ),
+        (
+            "PLN",
+            Names::new("Polish Zloty", "Polish zloty", "Polish zlotys"),
+        ),
+        (
+            "PYG",
+            Names::new(
+                "Paraguayan Guarani",
+                "Paraguayan guarani",
+                "Paraguayan guaranis",
+            ),
+        ),
+        (
+            "QAR",
+            Names::new("Qatari Riyal", "Qatari riyal", "Qatari riyals"),
+        ),
+        (
+            "RON",
+            Names::new("Romanian Leu", "Romanian leu", "Romanian lei"),
+        ),
+        (
+            "RSD",
+            Names::new("Serbian Dinar", "Serbian dinar", "Serbian dinars"),
+        ),
+        (
+            "SAR",
+            Names::new("Saudi Riyal", "Saudi riyal", "Saudi riyals"),
+        ),
+        (
+            "SEK",
+            Names::new("Swedish Krona", "Swedish krona", "Swedish kronor"),
+        ),
+        (
+            "SGD",
+            Names::new("Singapore Dollar", "Singapore dollar", "Singapore dollars"),
+        ),
+        ("THB", Names::new("Thai Baht", "Thai baht", "Thai baht")),
+        (
+            "TND",
+            Names::new("Tunisian Dinar", "Tunisian dinar", "Tunisian dinars"),
+        ),
+        (
+            "TRY",
+            Names::new("Turkish Lira", "Turkish lira", "Turkish lira"),
+        ),
+        (
+            "TWD",
+            Names::new(
+                "New Taiwan Dollar",
+                "New Taiwan dollar",
+                "New Taiwan dollars",
+            ),
+        ),
+        (
+            "UAH",
+            Names::new(
+                "Ukrainian Hryvnia",
+                "Ukrainian hryvnia",
+                "Ukrainian hryvnias",
+            ),
+        ),
+        ("USD", Names::new("US Dollar", "US dollar", "US dollars")),
+        (
+            "UYU",
+            Names::new("Uruguayan Peso", "Uruguayan peso", "Uruguayan pesos"),
+        ),
+        (
+            "VND",
+            Names::new("Vietnamese Dong", "Vietnamese dong", "Vietnamese dong"),
+        ),
+        (
+            "ZAR",
+            Names::new(
+                "South African Rand",
+                "South African rand",
+                "South African rand",
+            ),
+        ),
+    ],
+    plural: PluralRule::One,
+    spaced: true,
+};
+
+const SPANISH: NameTable = NameTable {
+    names: &[
+        (
+            "ARS",
+            Names::new("peso argentino", "peso argentino", "pesos argentinos"),
+        ),
+        (
+            "AUD",
+            Names::new(
+                "dólar australiano",
+                "dólar australiano",
+                "dólares australianos",
+            ),
+        ),
+        ("BOB", Names::new("boliviano", "boliviano", "bolivianos")),
+        (
+            "BRL",
+            Names::new("real brasileño", "real brasileño", "reales brasileños"),
+        ),
+        (
+            "CAD",
+            Names::new(
+                "dólar canadiense",
+                "dólar canadiense",
+                "dólares canadienses",
+            ),
+        ),
+        (
+            "CHF",
+            Names::new("franco suizo", "franco suizo", "francos suizos"),
+        ),
+        (
+            "CLP",
+            Names::new("peso chileno", "peso chileno", "pesos chilenos"),
+        ),
+        ("CNY", Names::new("yuan", "yuan", "yuanes")),
+        (
+            "COP",
+            Names::new("peso colombiano", "peso colombiano", "pesos colombianos"),
+        ),
+        ("EUR", Names::new("euro", "euro", "euros")),
+        (
+            "GBP",
+            Names::new("libra esterlina", "libra esterlina", "libras esterlinas"),
+        ),
+        (
+            "INR",
+            Names::new("rupia india", "rupia india", "rupias indias"),
+        ),
+        ("JPY", Names::new("yen", "yen", "yenes")),
+        (
+            "KRW",
+            Names::new("won surcoreano", "won surcoreano", "wons surcoreanos"),
+        ),
+        (
+            "MXN",
+            Names::new("peso mexicano", "peso mexicano", "pesos mexicanos"),
+        ),
+        (
+            "PEN",
+            Names::new("sol peruano", "sol peruano", "soles peruanos"),
+        ),
+        ("PLN", Names::new("esloti", "esloti", "eslotis")),
+        (
+            "PYG",
+            Names::new(
+                "guaraní paraguayo",
+                "guaraní paraguayo",
+                "guaraníes paraguayos",
+            ),
+        ),
+        (
+            "USD",
+            Names::new(
+                "dólar estadounidense",
+                "dólar estadounidense",
+                "dólares estadounidenses",
+            ),
+        ),
+        (
+            "UYU",
+            Names::new("peso uruguayo", "peso uruguayo", "pesos uruguayos"),
+        ),
+    ],
+    plural: PluralRule::One,
+    spaced: true,
+};
+
+const PORTUGUESE_NAMES: &[(&str, Names)] = &[
+    (
+        "AUD",
+        Names::new(
+            "Dólar australiano",
+            "dólar australiano",
+            "dólares australianos",
+        ),
+    ),
+    (
+        "BRL",
+        Names::new("Real brasileiro", "real brasileiro", "reais brasileiros"),
+    ),
+    (
+        "CAD",
+        Names::new("Dólar canadense", "dólar canadense", "dólares canadenses"),
+    ),
+    (
+        "CHF",
+        Names::new("Franco suíço", "franco suíço", "francos suíços"),
+    ),
+    (
+        "CNY",
+        Names::new("Yuan chinês", "yuan chinês", "yuans chineses"),
+    ),
+    ("EUR", Names::new("Euro", "euro", "euros")),
+    (
+        "GBP",
+        Names::new("Libra esterlina", "libra esterlina", "libras esterlinas"),
+    ),
+    (
+        "INR",
+        Names::new("Rupia indiana", "rupia indiana", "rupias indianas"),
+    ),
+    (
+        "JPY",
+        Names::new("Iene japonês", "iene japonês", "ienes japoneses"),
+    ),
+    (
+        "KRW",
+        Names::new("Won sul-coreano", "won sul-coreano", "wons sul-coreanos"),
+    ),
+    (
+        "MXN",
+        Names::new("Peso mexicano", "peso mexicano", "pesos mexicanos"),
+    ),
+    (
+        "PLN",
+        Names::new("Zloty polonês", "zloty polonês", "zlotys poloneses"),
+    ),
+    (
+        "USD",
+        Names::new("Dólar americano", "dólar americano", "dólares americanos"),
+    ),
+];
+
+const PORTUGUESE: NameTable = NameTable {
+    names: PORTUGUESE_NAMES,
+    plural: PluralRule::BelowTwo,
+    spaced: true,
+};
+
+/// European Portuguese only uses the singular for exactly one.
+const EUROPEAN_PORTUGUESE: NameTable = NameTable {
+    names: PORTUGUESE_NAMES,
+    plural: PluralRule::One,
+    spaced: true,
+};
+
+const FRENCH: NameTable = NameTable {
+    names: &[
+        (
+            "AUD",
+            Names::new(
+                "dollar australien",
+                "dollar australien",
+                "dollars australiens",
+            ),
+        ),
+        (
+            "BRL",
+            Names::new("réal brésilien", "réal brésilien", "réals brésiliens"),
+        ),
+        (
+            "CAD",
+            Names::new("dollar canadien", "dollar canadien", "dollars canadiens"),
+        ),
+        (
+            "CHF",
+            Names::new("franc suisse", "franc suisse", "francs suisses"),
+        ),
+        (
+            "CNY",
+            Names::new(
+                "yuan renminbi chinois",
+                "yuan renminbi chinois",
+                "yuans renminbi chinois",
+            ),
+        ),
+        ("EUR", Names::new("euro", "euro", "euros")),
+        (
+            "GBP",
+            Names::new("livre sterling", "livre sterling", "livres sterling"),
+        ),
+        (
+            "INR",
+            Names::new("roupie indienne", "roupie indienne", "roupies indiennes"),
+        ),
+        (
+            "JPY",
+            Names::new("yen japonais", "yen japonais", "yens japonais"),
+        ),
+        (
+            "KRW",
+            Names::new("won sud-coréen", "won sud-coréen", "wons sud-coréens"),
+        ),
+        (
+            "MAD",
+            Names::new("dirham marocain", "dirham marocain", "dirhams marocains"),
+        ),
+        (
+            "MXN",
+            Names::new("peso mexicain", "peso mexicain", "pesos mexicains"),
+        ),
+        (
+            "PLN",
+            Names::new("zloty polonais", "zloty polonais", "zlotys polonais"),
+        ),
+        (
+            "TND",
+            Names::new("dinar tunisien", "dinar tunisien", "dinars tunisiens"),
+        ),
+        (
+            "USD",
+            Names::new(
+                "dollar des États-Unis",
+                "dollar des États-Unis",
+                "dollars des États-Unis",
+            ),
+        ),
+    ],
+    plural: PluralRule::BelowTwo,
+    spaced: true,
+};
+
+const GERMAN: NameTable = NameTable {
+    names: &[
+        (
+            "AUD",
+            Names::new(
+                "Australischer Dollar",
+                "Australischer Dollar",
+                "Australische Dollar",
+            ),
+        ),
+        (
+            "BRL",
+            Names::new(
+                "Brasilianischer Real",
+                "Brasilianischer Real",
+                "Brasilianische Real",
+            ),
+        ),
+        (
+            "CAD",
+            Names::new(
+                "Kanadischer Dollar",
+                "Kanadischer Dollar",
+                "Kanadische Dollar",
+            ),
+        ),
+        (
+            "CHF",
+            Names::new(
+                "Schweizer Franken",
+                "Schweizer Franken",
+                "Schweizer Franken",
+            ),
+        ),
+        (
+            "CNY",
+            Names::new("Renminbi Yuan", "Renminbi Yuan", "Renminbi Yuan"),
+        ),
+        (
+            "DKK",
+            Names::new("Dänische Krone", "Dänische Krone", "Dänische Kronen"),
+        ),
+        ("EUR", Names::new("Euro", "Euro", "Euro")),
+        (
+            "GBP",
+            Names::new("Britisches Pfund", "Britisches Pfund", "Britische Pfund"),
+        ),
+        (
+            "INR",
+            Names::new("Indische Rupie", "Indische Rupie", "Indische Rupien"),
+        ),
+        (
+            "JPY",
+            Names::new("Japanischer Yen", "Japanischer Yen", "Japanische Yen"),
+        ),
+        (
+            "KRW",
+            Names::new(
+                "Südkoreanischer Won",
+                "Südkoreanischer Won",
+                "Südkoreanische Won",
+            ),
+        ),
+        (
+            "MXN",
+            Names::new(
+                "Mexikanischer Peso",
+                "Mexikanischer Peso",
+                "Mexikanische Pesos",
+            ),
+        ),
+        (
+            "NOK",
+            Names::new(
+                "Norwegische Krone",
+                "Norwegische Krone",
+                "Norwegische Kronen",
+            ),
+        ),
+        (
+            "PLN",
+            Names::new("Polnischer Złoty", "Polnischer Złoty", "Polnische Złoty"),
+        ),
+        (
+            "SEK",
+            Names::new(
+                "Schwedische Krone",
+                "Schwedische Krone",
+                "Schwedische Kronen",
+            ),
+        ),
+        ("USD", Names::new("US-Dollar", "US-Dollar", "US-Dollar")),
+    ],
+    plural: PluralRule::One,
+    spaced: true,
+};
+
+const ITALIAN: NameTable = NameTable {
+    names: &[
+        (
+            "AUD",
+            Names::new(
+                "dollaro australiano",
+                "dollaro australiano",
+                "dollari australiani",
+            ),
+        ),
+        (
+            "BRL",
+            Names::new("real brasiliano", "real brasiliano", "real brasiliani"),
+        ),
+        (
+            "CAD",
+            Names::new("dollaro canadese", "dollaro canadese", "dollari canadesi"),
+        ),
+        (
+            "CHF",
+            Names::new("franco svizzero", "franco svizzero", "franchi svizzeri"),
+        ),
+        (
+            "CNY",
+            Names::new("renminbi cinese", "renminbi cinese", "renminbi cinesi"),
+        ),
+        ("EUR", Names::new("euro", "euro", "euro")),
+        (
+            "GBP",
+            Names::new(
+                "sterlina britannica",
+                "sterlina britannica",
+                "sterline britanniche",
+            ),
+        ),
+        (
+            "INR",
+            Names::new("rupia indiana", "rupia indiana", "rupie indiane"),
+        ),
+        (
+            "JPY",
+            Names::new("yen giapponese", "yen giapponese", "yen giapponesi"),
+        ),
+        (
+            "KRW",
+            Names::new("won sudcoreano", "won sudcoreano", "won sudcoreani"),
+        ),
+        (
+            "MXN",
+            Names::new("peso messicano", "peso messicano", "pesos messicani"),
+        ),
+        (
+            "PLN",
+            Names::new("złoty polacco", "złoty polacco", "złoty polacchi"),
+        ),
+        (
+            "USD",
+            Names::new(
+                "dollaro statunitense",
+                "dollaro statunitense",
+                "dollari statunitensi",
+            ),
+        ),
+    ],
+    plural: PluralRule::One,
+    spaced: true,
+};
+
+const DUTCH: NameTable = NameTable {
+    names: &[
+        (
+            "AUD",
+            Names::new(
+                "Australische dollar",
+                "Australische dollar",
+                "Australische dollar",
+            ),
+        ),
+        (
+            "BRL",
+            Names::new(
+                "Braziliaanse real",
+                "Braziliaanse real",
+                "Braziliaanse real",
+            ),
+        ),
+        (
+            "CAD",
+            Names::new("Canadese dollar", "Canadese dollar", "Canadese dollar"),
+        ),
+        (
+            "CHF",
+            Names::new("Zwitserse frank", "Zwitserse frank", "Zwitserse frank"),
+        ),
+        (
+            "CNY",
+            Names::new("Chinese yuan", "Chinese yuan", "Chinese yuan"),
+        ),
+        ("EUR", Names::new("Euro", "euro", "euro")),
+        ("GBP", Names::new("Brits pond", "Brits pond", "Brits pond")),
+        (
+            "INR",
+            Names::new("Indiase roepie", "Indiase roepie", "Indiase roepies"),
+        ),
+        (
+            "JPY",
+            Names::new("Japanse yen", "Japanse yen", "Japanse yen"),
+        ),
+        (
+            "KRW",
+            Names::new(
+                "Zuid-Koreaanse won",
+                "Zuid-Koreaanse won",
+                "Zuid-Koreaanse won",
+            ),
+        ),
+        (
+            "MXN",
+            Names::new("Mexicaanse peso", "Mexicaanse peso", "Mexicaanse peso"),
+        ),
+        (
+            "PLN",
+            Names::new("Poolse zloty", "Poolse zloty", "Poolse zloty"),
+        ),
+        (
+            "USD",
+            Names::new(
+                "Amerikaanse dollar",
+                "Amerikaanse dollar",
+                "Amerikaanse dollar",
+            ),
+        ),
+    ],
+    plural: PluralRule::One,
+    spaced: true,
+};
+
+const POLISH: NameTable = NameTable {
+    names: &[
+        (
+            "AUD",
+            Names::new(
+                "dolar australijski",
+                "dolar australijski",
+                "dolara australijskiego",
+            )
+            .few("dolary australijskie")
+            .many("dolarów australijskich"),
+        ),
+        (
+            "BRL",
+            Names::new(
+                "real brazylijski",
+                "real brazylijski",
+                "reala brazylijskiego",
+            )
+            .few("reale brazylijskie")
+            .many("reali brazylijskich"),
+        ),
+        (
+            "CAD",
+            Names::new(
+                "dolar kanadyjski",
+                "dolar kanadyjski",
+                "dolara kanadyjskiego",
+            )
+            .few("dolary kanadyjskie")
+            .many("dolarów kanadyjskich"),
+        ),
+        (
+            "CHF",
+            Names::new(
+                "frank szwajcarski",
+                "frank szwajcarski",
+                "franka szwajcarskiego",
+            )
+            .few("franki szwajcarskie")
+            .many("franków szwajcarskich"),
+        ),
+        (
+            "CNY",
+            Names::new("juan chiński", "juan chiński", "juana chińskiego")
+                .few("juany chińskie")
+                .many("juanów chińskich"),
+        ),
+        ("EUR", Names::new("euro", "euro", "euro")),
+        (
+            "GBP",
+            Names::new("funt szterling", "funt szterling", "funta szterlinga")
+                .few("funty szterlingi")
+                .many("funtów szterlingów"),
+        ),
+        (
+            "INR",
+            Names::new("rupia indyjska", "rupia indyjska", "rupii indyjskiej")
+                .few("rupie indyjskie")
+                .many("rupii indyjskich"),
+        ),
+        (
+            "JPY",
+            Names::new("jen japoński", "jen japoński", "jena japońskiego")
+                .few("jeny japońskie")
+                .many("jenów japońskich"),
+        ),
+        (
+            "KRW",
+            Names::new(
+                "won południowokoreański",
+                "won południowokoreański",
+                "wona południowokoreańskiego",
+            )
+            .few("wony południowokoreańskie")
+            .many("wonów południowokoreańskich"),
+        ),
+        (
+            "MXN",
+            Names::new(
+                "peso meksykańskie",
+                "peso meksykańskie",
+                "peso meksykańskiego",
+            ),
+        ),
+        (
+            "PLN",
+            Names::new("złoty polski", "złoty polski", "złotego polskiego")
+                .few("złote polskie")
+                .many("złotych polskich"),
+        ),
+        (
+            "USD",
+            Names::new(
+                "dolar amerykański",
+                "dolar amerykański",
+                "dolara amerykańskiego",
+            )
+            .few("dolary amerykańskie")
+            .many("dolarów amerykańskich"),
+        ),
+    ],
+    plural: PluralRule::Polish,
+    spaced: true,
+};
+
+const JAPANESE: NameTable = NameTable {
+    names: &[
+        ("AUD", Names::invariant("オーストラリア ドル")),
+        ("BRL", Names::invariant("ブラジル レアル")),
+        ("CAD", Names::invariant("カナダ ドル")),
+        ("CHF", Names::invariant("スイス フラン")),
+        ("CNY", Names::invariant("中国人民元")),
+        ("EUR", Names::invariant("ユーロ")),
+        ("GBP", Names::invariant("英国ポンド")),
+        ("INR", Names::invariant("インド ルピー")),
+        ("JPY", Names::invariant("日本円")),
+        ("KRW", Names::invariant("韓国ウォン")),
+        ("MXN", Names::invariant("メキシコ ペソ")),
+        ("PLN", Names::invariant("ポーランド ズウォティ")),
+        ("USD", Names::invariant("米ドル")),
+    ],
+    plural: PluralRule::Invariant,
+    spaced: false,
+};
+
+const SIMPLIFIED_CHINESE: NameTable = NameTable {
+    names: &[
+        ("AUD", Names::invariant("澳大利亚元")),
+        ("BRL", Names::invariant("巴西雷亚尔")),
+        ("CAD", Names::invariant("加拿大元")),
+        ("CHF", Names::invariant("瑞士法郎")),
+        ("CNY", Names::invariant("人民币")),
+        ("EUR", Names::invariant("欧元")),
+        ("GBP", Names::invariant("英镑")),
+        ("HKD", Names::invariant("港元")),
+        ("INR", Names::invariant("印度卢比")),
+        ("JPY", Names::invariant("日元")),
+        ("KRW", Names::invariant("韩元")),
+        ("MXN", Names::invariant("墨西哥比索")),
+        ("PLN", Names::invariant("波兰兹罗提")),
+        ("TWD", Names::invariant("新台币")),
+        ("USD", Names::invariant("美元")),
+    ],
+    plural: PluralRule::Invariant,
+    spaced: false,
+};
+
+const TRADITIONAL_CHINESE: NameTable = NameTable {
+    names: &[
+        ("AUD", Names::invariant("澳幣")),
+        ("BRL", Names::invariant("巴西里拉")),
+        ("CAD", Names::invariant("加幣")),
+        ("CHF", Names::invariant("瑞士法郎")),
+        ("CNY", Names::invariant("人民幣")),
+        ("EUR", Names::invariant("歐元")),
+        ("GBP", Names::invariant("英鎊")),
+        ("HKD", Names::invariant("港幣")),
+        ("INR", Names::invariant("印度盧比")),
+        ("JPY", Names::invariant("日圓")),
+        ("KRW", Names::invariant("韓元")),
+        ("MXN", Names::invariant("墨西哥披索")),
+        ("PLN", Names::invariant("波蘭茲羅提")),
+        ("TWD", Names::invariant("新台幣")),
+        ("USD", Names::invariant("美元")),
+    ],
+    plural: PluralRule::Invariant,
+    spaced: false,
+};
+
+const KOREAN: NameTable = NameTable {
+    names: &[
+        ("AUD", Names::invariant("호주 달러")),
+        ("BRL", Names::invariant("브라질 레알")),
+        ("CAD", Names::invariant("캐나다 달러")),
+        ("CHF", Names::invariant("스위스 프랑")),
+        ("CNY", Names::invariant("중국 위안화")),
+        ("EUR", Names::invariant("유로")),
+        ("GBP", Names::invariant("영국 파운드")),
+        ("INR", Names::invariant("인도 루피")),
+        ("JPY", Names::invariant("일본 엔화")),
+        ("KRW", Names::invariant("대한민국 원")),
+        ("MXN", Names::invariant("멕시코 페소")),
+        ("PLN", Names::invariant("폴란드 즐로티")),
+        ("USD", Names::invariant("미국 달러")),
+    ],
+    plural: PluralRule::Invariant,
+    spaced: true,
+};
+
+/// Name tables by tag; languages without an entry use English names.
+const TABLES: &[(&str, &NameTable)] = &[
+    ("en", &ENGLISH),
+    ("es", &SPANISH),
+    ("pt", &PORTUGUESE),
+    ("pt-PT", &EUROPEAN_PORTUGUESE),
+    ("fr", &FRENCH),
+    ("de", &GERMAN),
+    ("it", &ITALIAN),
+    ("nl", &DUTCH),
+    ("pl", &POLISH),
+    ("ja", &JAPANESE),
+    ("zh", &SIMPLIFIED_CHINESE),
+    ("zh-Hant", &TRADITIONAL_CHINESE),
+    ("zh-TW", &TRADITIONAL_CHINESE),
+    ("zh-HK", &TRADITIONAL_CHINESE),
+    ("ko", &KOREAN),
+];
+
+impl Locale {
+    /// Returns the currency names for this locale's language.
+    fn name_table(&self) -> &'static NameTable {
+        Subtags::parse(&self.tag)
+            .into_iter()
+            .flat_map(|subtags| subtags.fallback_chain())
+            .find_map(|candidate| {
+                TABLES
+                    .iter()
+                    .find(|(tag, _)| *tag == candidate)
+                    .map(|(_, table)| *table)
+            })
+            .unwrap_or(&ENGLISH)
+    }
+
+    /// Returns the display name of the currency with ISO code `code`, if
+    /// this locale's language has one.
+    pub(crate) fn currency_name(&self, code: &str) -> Option<&'static str> {
+        self.name_table().get(code).map(|names| names.display)
+    }
+
+    /// Writes a plain decimal string followed by the currency name in the
+    /// plural form the number calls for, or by `code` if the language has
+    /// no name for the currency.
+    pub(crate) fn format_long(&self, plain: &str, code: &str) -> String {
+        let (negative, unsigned) = split_sign(plain);
+        let table = self.name_table();
+        let (name, spaced) = match table.get(code) {
+            Some(names) => (names.form(table.plural.category(unsigned)), table.spaced),
+            None => (code, true),
+        };
+
+        let mut text = String::new();
+        if negative {
+            text.push(self.minus_sign);
+        }
+        text.push_str(&self.localize_digits(unsigned));
+        if spaced {
+            text.push('\u{a0}');
+        }
+        text.push_str(name);
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_sorted_and_complete() {
+        for (tag, table) in TABLES {
+            assert!(
+                table.names.windows(2).all(|w| w[0].0 < w[1].0),
+                "{} is not sorted by code",
+                tag
+            );
+            for (code, names) in table.names {
+                assert!(
+                    !names.display.is_empty() && !names.one.is_empty(),
+                    "{} {}",
+                    tag,
+                    code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_table_lookup() {
+        let name = |tag, code| Locale::parse(tag).unwrap().currency_name(code);
+        assert_eq!(name("en-GB", "USD"), Some("US Dollar"));
+        assert_eq!(name("es-MX", "USD"), Some("dólar estadounidense"));
+        assert_eq!(name("zh-Hant-HK", "JPY"), Some("日圓"));
+        assert_eq!(name("zh-CN", "JPY"), Some("日元"));
+        // Swedish has no table, so English names are used
+        assert_eq!(name("sv-SE", "SEK"), Some("Swedish Krona"));
+        assert_eq!(name("de", "THB"), None);
+    }
+
+    #[test]
+    fn test_format_long() {
+        let long = |tag, plain| Locale::parse(tag).unwrap().format_long(plain, "PLN");
+        assert_eq!(long("pl", "1"), "1\u{a0}złoty polski");
+        assert_eq!(long("pl", "3"), "3\u{a0}złote polskie");
+        assert_eq!(long("pl", "5"), "5\u{a0}złotych polskich");
+        assert_eq!(long("pl", "2.50"), "2,50\u{a0}złotego polskiego");
+        assert_eq!(long("pl", "-12345"), "-12\u{a0}345\u{a0}złotych polskich");
+        assert_eq!(long("ja", "1000"), "1,000ポーランド ズウォティ");
+
+        let locale = Locale::parse("fr-FR").unwrap();
+        assert_eq!(locale.format_long("1.50", "EUR"), "1,50\u{a0}euro");
+        assert_eq!(locale.format_long("2", "THB"), "2\u{a0}THB");
+    }
+}
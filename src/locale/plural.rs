@@ -0,0 +1,92 @@
+//! Plural categories of numbers, after the plural rules of the Unicode CLDR.
+//!
+//! The rules look at the number as it is written: "1" and "1.50" fall in
+//! different categories in English, since visible decimals count.
+
+/// CLDR plural category of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PluralCategory {
+    One,
+    Few,
+    Many,
+    Other,
+}
+
+/// How a language picks the plural category of a number.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum PluralRule {
+    /// One for exactly one without decimals (English, German, Spanish)
+    One,
+    /// One for anything below two (French, Brazilian Portuguese)
+    BelowTwo,
+    /// One, few, many and other (Polish)
+    Polish,
+    /// No plural forms (Japanese, Chinese, Korean)
+    Invariant,
+}
+
+impl PluralRule {
+    /// Returns the category of a plain decimal string; the sign is ignored.
+    pub(crate) fn category(self, number: &str) -> PluralCategory {
+        let unsigned = number.trim_start_matches('-');
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let whole = fraction.is_empty();
+        // Only the last digits matter for the rules; keeping a leading 1
+        // stops huge numbers from comparing equal to small ones
+        let i: u128 = if integer.len() > 30 {
+            format!("1{}", &integer[integer.len() - 30..])
+                .parse()
+                .unwrap_or(u128::MAX)
+        } else {
+            integer.parse().unwrap_or(0)
+        };
+        match self {
+            PluralRule::One if i == 1 && whole => PluralCategory::One,
+            PluralRule::BelowTwo if i < 2 => PluralCategory::One,
+            PluralRule::Polish if whole => match (i % 10, i % 100) {
+                _ if i == 1 => PluralCategory::One,
+                (2..=4, tens) if !(12..=14).contains(&tens) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plural_categories() {
+        let one = PluralRule::One;
+        assert_eq!(one.category("1"), PluralCategory::One);
+        assert_eq!(one.category("-1"), PluralCategory::One);
+        assert_eq!(one.category("1.5"), PluralCategory::Other);
+        assert_eq!(one.category("0"), PluralCategory::Other);
+        assert_eq!(one.category("10"), PluralCategory::Other);
+
+        let below_two = PluralRule::BelowTwo;
+        assert_eq!(below_two.category("0.5"), PluralCategory::One);
+        assert_eq!(below_two.category("1.99"), PluralCategory::One);
+        assert_eq!(below_two.category("2"), PluralCategory::Other);
+        // A leading 1 is not enough
+        assert_eq!(below_two.category("10"), PluralCategory::Other);
+        assert_eq!(below_two.category("15.5"), PluralCategory::Other);
+
+        let polish = PluralRule::Polish;
+        assert_eq!(polish.category("1"), PluralCategory::One);
+        assert_eq!(polish.category("2"), PluralCategory::Few);
+        assert_eq!(polish.category("24"), PluralCategory::Few);
+        assert_eq!(polish.category("12"), PluralCategory::Many);
+        assert_eq!(polish.category("5"), PluralCategory::Many);
+        assert_eq!(polish.category("21"), PluralCategory::Many);
+        assert_eq!(polish.category("2.50"), PluralCategory::Other);
+        assert_eq!(
+            polish.category("1000000000000000000000000000000001"),
+            PluralCategory::Many
+        );
+
+        assert_eq!(PluralRule::Invariant.category("1"), PluralCategory::Other);
+    }
+}
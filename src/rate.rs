@@ -67,7 +67,10 @@
 //! assert!(matches!(result, Err(MoneyError::InvalidRate { .. })));
 //! ```
 
-use crate::{Currency, MoneyError, MoneyResult};
+use crate::amount::round_dp;
+use crate::formatter::write_padded;
+use crate::{Currency, MoneyError, MoneyResult, RoundingMode};
+use std::fmt;
 use std::marker::PhantomData;
 
 #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
@@ -340,6 +343,11 @@ impl<From: Currency, To: Currency> Rate<From, To> {
 
     /// Returns the inverse rate (To -> From).
     ///
+    /// The inverse is computed in decimal and may be rounded, so inverting
+    /// twice is not guaranteed to give back the original rate. Use
+    /// [`ExactRate::inverse`](crate::ExactRate::inverse) for a lossless
+    /// inverse.
+    ///
     /// # Examples
     ///
     /// ```
@@ -373,6 +381,103 @@ impl<From: Currency, To: Currency> Rate<From, To> {
     }
 }
 
+/// Prints the rate as `1 USD = 0.85 EUR`, or as the quote `USD/EUR 0.85`
+/// with `{:#}`.
+///
+/// Width, fill and alignment pad the whole string (right-aligned by default)
+/// and precision rounds the rate half-even to that many decimal places. Rates
+/// are always positive, so the `+` flag has no effect.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{Rate, USD, EUR};
+///
+/// let rate = Rate::<USD, EUR>::from_decimal(rust_decimal::Decimal::new(923_456, 6));
+/// assert_eq!(rate.to_string(), "1 USD = 0.923456 EUR");
+/// assert_eq!(format!("{:.4}", rate), "1 USD = 0.9235 EUR");
+/// assert_eq!(format!("[{:<#16.2}]", rate), "[USD/EUR 0.92    ]");
+/// ```
+impl<From: Currency, To: Currency> fmt::Display for Rate<From, To> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match f.precision() {
+            Some(precision) => {
+                let scale = u32::try_from(precision).unwrap_or(u32::MAX);
+                let rounded = round_dp(&self.rate, scale, RoundingMode::HalfEven);
+                format!("{:.prec$}", rounded, prec = precision)
+            }
+            None => self.rate.to_string(),
+        };
+
+        let formatted = if f.alternate() {
+            format!("{}/{} {}", From::CODE, To::CODE, value)
+        } else {
+            format!("1 {} = {} {}", From::CODE, value, To::CODE)
+        };
+        write_padded(f, &formatted)
+    }
+}
+
+/// Serialized form of a [`Rate`]: the pair, the rate as a string and any
+/// metadata.
+#[cfg(feature = "serde_support")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RateSerde {
+    from: String,
+    to: String,
+    rate: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    timestamp_unix_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+}
+
+/// Serializes as `{"from":"USD","to":"EUR","rate":"0.85"}`, plus
+/// `timestamp_unix_secs` and `source` when set.
+#[cfg(feature = "serde_support")]
+impl<From: Currency, To: Currency> serde::Serialize for Rate<From, To> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rate_serde = RateSerde {
+            from: From::CODE.to_string(),
+            to: To::CODE.to_string(),
+            rate: self.rate.to_string(),
+            timestamp_unix_secs: self.metadata_timestamp_unix_secs,
+            source: self.metadata_source.map(str::to_string),
+        };
+        serde::Serialize::serialize(&rate_serde, serializer)
+    }
+}
+
+/// Rejects a different currency pair and rates that are not positive.
+/// Sources are interned, see
+/// [`register_rate_source`](crate::serde::register_rate_source).
+#[cfg(feature = "serde_support")]
+impl<'de, From: Currency, To: Currency> serde::Deserialize<'de> for Rate<From, To> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        use std::str::FromStr;
+
+        let rate_serde = <RateSerde as serde::Deserialize>::deserialize(deserializer)?;
+        if rate_serde.from != From::CODE || rate_serde.to != To::CODE {
+            return Err(D::Error::custom(format!(
+                "Currency pair mismatch: expected {}/{}, found {}/{}",
+                From::CODE,
+                To::CODE,
+                rate_serde.from,
+                rate_serde.to
+            )));
+        }
+
+        let value = Decimal::from_str(&rate_serde.rate).map_err(|_| {
+            D::Error::custom(format!("Invalid decimal value: '{}'", rate_serde.rate))
+        })?;
+        let mut rate = Self::try_from_decimal(value).map_err(D::Error::custom)?;
+        rate.metadata_timestamp_unix_secs = rate_serde.timestamp_unix_secs;
+        rate.metadata_source = rate_serde.source.as_deref().map(crate::serde::rate_source);
+        Ok(rate)
+    }
+}
+
 #[cfg(test)]
 #[cfg(not(all(feature = "use_rust_decimal", feature = "use_bigdecimal")))]
 mod tests {
@@ -580,4 +685,80 @@ mod tests {
             assert!(suggestion.contains("positive"));
         }
     }
+
+    #[test]
+    fn test_rate_display() {
+        let rate = Rate::<USD, EUR>::from_decimal(Decimal::new(85, 2));
+        assert_eq!(rate.to_string(), "1 USD = 0.85 EUR");
+        assert_eq!(format!("{:#}", rate), "USD/EUR 0.85");
+        assert_eq!(format!("{:.4}", rate), "1 USD = 0.8500 EUR");
+        assert_eq!(format!("{:.1}", rate), "1 USD = 0.8 EUR");
+        assert_eq!(format!("{:>20}", rate), "    1 USD = 0.85 EUR");
+        assert_eq!(format!("{:-^#18}", rate), "---USD/EUR 0.85---");
+        assert_eq!(format!("{:+}", rate), rate.to_string());
+    }
+
+    #[test]
+    fn test_rate_display_precision_of_float_rate() {
+        let rate = Rate::<GBP, USD>::new(1.27);
+        assert_eq!(format!("{:.2}", rate), "1 GBP = 1.27 USD");
+        assert_eq!(format!("{:#.3}", rate.inverse()), "USD/GBP 0.787");
+    }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_rate_serde_round_trip() {
+        crate::serde::register_rate_source("ECB");
+        let rate =
+            Rate::<USD, EUR>::from_decimal(Decimal::new(85, 2)).with_metadata(1_700_000_000, "ECB");
+        let json = serde_json::to_string(&rate).unwrap();
+        assert_eq!(
+            json,
+            r#"{"from":"USD","to":"EUR","rate":"0.85","timestamp_unix_secs":1700000000,"source":"ECB"}"#
+        );
+        assert_eq!(serde_json::from_str::<Rate<USD, EUR>>(&json).unwrap(), rate);
+
+        let bare: Rate<USD, EUR> =
+            serde_json::from_str(r#"{"from":"USD","to":"EUR","rate":"0.85"}"#).unwrap();
+        assert_eq!(bare.source(), None);
+        assert_eq!(bare.timestamp_unix_secs(), None);
+    }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_rate_snapshot_loads_unregistered_source() {
+        // Written by another process; this one never registered the source
+        let snapshot = r#"{"from":"USD","to":"EUR","rate":"0.85","timestamp_unix_secs":1700000000,"source":"Snapshot desk"}"#;
+        let first: Rate<USD, EUR> = serde_json::from_str(snapshot).unwrap();
+        assert_eq!(first.source(), Some("Snapshot desk"));
+        assert_eq!(first.timestamp_unix_secs(), Some(1_700_000_000));
+        assert_eq!(serde_json::to_string(&first).unwrap(), snapshot);
+
+        // Each distinct source is interned once
+        let second: Rate<USD, EUR> = serde_json::from_str(snapshot).unwrap();
+        assert!(std::ptr::eq(
+            first.source().unwrap(),
+            second.source().unwrap()
+        ));
+    }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_rate_deserialize_validates_pair_and_value() {
+        let swapped =
+            serde_json::from_str::<Rate<USD, EUR>>(r#"{"from":"EUR","to":"USD","rate":"1.1"}"#)
+                .unwrap_err();
+        assert!(swapped
+            .to_string()
+            .contains("expected USD/EUR, found EUR/USD"));
+
+        assert!(
+            serde_json::from_str::<Rate<USD, EUR>>(r#"{"from":"USD","to":"EUR","rate":"0"}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Rate<USD, EUR>>(
+            r#"{"from":"USD","to":"EUR","rate":"abc"}"#
+        )
+        .is_err());
+    }
 }
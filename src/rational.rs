@@ -0,0 +1,628 @@
+//! Exact rational numbers for deferred rounding.
+//!
+//! Decimal backends have to round every quotient that does not terminate, so
+//! splitting a bill three ways and multiplying back can drift by a unit in the
+//! last place. A [`Rational`] stores a value as a reduced
+//! `numerator / denominator` pair of `i128`s, which makes division exact.
+//! Rounding happens only when the value is turned back into a decimal with
+//! [`to_decimal`](Rational::to_decimal).
+//!
+//! Arithmetic is checked: the `checked_*` methods return `None` when a
+//! numerator or denominator would not fit in an `i128`, and the operators
+//! panic in that case, like the built-in integer types do in debug builds.
+//!
+//! # Examples
+//!
+//! ```
+//! use typed_money::{Rational, RoundingMode};
+//!
+//! let third = Rational::new(1, 3);
+//! assert_eq!(third * Rational::from(3), Rational::ONE);
+//!
+//! let rounded = third.to_decimal(2, RoundingMode::HalfEven)?;
+//! assert_eq!(rounded.to_string(), "0.33");
+//! # Ok::<(), typed_money::MoneyError>(())
+//! ```
+
+use crate::{MoneyError, MoneyResult, RoundingMode};
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// An exact fraction with an `i128` numerator and denominator.
+///
+/// Values are always kept in lowest terms with a positive denominator, so two
+/// equal fractions compare equal regardless of how they were built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// The rational number zero.
+    pub const ZERO: Rational = Rational {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// The rational number one.
+    pub const ONE: Rational = Rational {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Tries to create the fraction `numerator / denominator`.
+    ///
+    /// The fraction is reduced to lowest terms.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the denominator is zero or the
+    /// reduced fraction does not fit in `i128`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Rational;
+    ///
+    /// let half = Rational::try_new(2, 4)?;
+    /// assert_eq!(half.numerator(), 1);
+    /// assert_eq!(half.denominator(), 2);
+    ///
+    /// assert!(Rational::try_new(1, 0).is_err());
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn try_new(numerator: i128, denominator: i128) -> MoneyResult<Self> {
+        if denominator == 0 {
+            return Err(MoneyError::InvalidAmount {
+                reason: "Rational denominator must be non-zero".to_string(),
+                currency: None,
+            });
+        }
+        Self::reduce(numerator, denominator).ok_or_else(|| MoneyError::InvalidAmount {
+            reason: format!("{}/{} does not fit in a rational", numerator, denominator),
+            currency: None,
+        })
+    }
+
+    /// Creates the fraction `numerator / denominator`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the denominator is zero or the reduced fraction does not fit
+    /// in `i128`. For a non-panicking version, use [`try_new`](Self::try_new).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Rational;
+    ///
+    /// let third = Rational::new(-2, -6);
+    /// assert_eq!(third.to_string(), "1/3");
+    /// ```
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        Self::try_new(numerator, denominator).expect("Invalid rational")
+    }
+
+    /// Returns the numerator in lowest terms.
+    ///
+    /// The sign of the fraction is carried by the numerator.
+    #[inline]
+    pub const fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    /// Returns the denominator in lowest terms, which is always positive.
+    #[inline]
+    pub const fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Returns `true` if the value is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    /// Returns `true` if the value is strictly negative.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    /// Returns `true` if the value is strictly positive.
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        self.numerator > 0
+    }
+
+    /// Returns `true` if the value is a whole number.
+    #[inline]
+    pub const fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Returns `self + other`, or `None` on overflow.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let gcd = gcd(
+            self.denominator.unsigned_abs(),
+            other.denominator.unsigned_abs(),
+        );
+        let gcd = i128::try_from(gcd).ok()?;
+        let left = self.numerator.checked_mul(other.denominator / gcd)?;
+        let right = other.numerator.checked_mul(self.denominator / gcd)?;
+        let denominator = (self.denominator / gcd).checked_mul(other.denominator)?;
+        Self::reduce(left.checked_add(right)?, denominator)
+    }
+
+    /// Returns `self - other`, or `None` on overflow.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    /// Returns `self * other`, or `None` on overflow.
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        // Cross-cancel first so that already-reduced operands stay small
+        let a = gcd(
+            self.numerator.unsigned_abs(),
+            other.denominator.unsigned_abs(),
+        )
+        .max(1);
+        let b = gcd(
+            other.numerator.unsigned_abs(),
+            self.denominator.unsigned_abs(),
+        )
+        .max(1);
+        let (a, b) = (i128::try_from(a).ok()?, i128::try_from(b).ok()?);
+        let numerator = (self.numerator / a).checked_mul(other.numerator / b)?;
+        let denominator = (self.denominator / b).checked_mul(other.denominator / a)?;
+        Self::reduce(numerator, denominator)
+    }
+
+    /// Returns `self / other`, or `None` if `other` is zero or on overflow.
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_mul(&other.checked_recip()?)
+    }
+
+    /// Returns `-self`, or `None` on overflow.
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns `1 / self`, or `None` if the value is zero.
+    ///
+    /// Inversion is exact: inverting twice always gives back the original
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Rational;
+    ///
+    /// let rate = Rational::new(17, 20); // 0.85
+    /// let inverse = rate.checked_recip().unwrap();
+    /// assert_eq!(inverse, Rational::new(20, 17));
+    /// assert_eq!(inverse.checked_recip(), Some(rate));
+    /// ```
+    pub fn checked_recip(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Self::reduce(self.denominator, self.numerator)
+    }
+
+    /// Tries to convert a decimal into an exact fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the decimal has too many
+    /// digits to be represented with an `i128` numerator and denominator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Rational;
+    /// use rust_decimal::Decimal;
+    ///
+    /// let rate = Rational::try_from_decimal(&Decimal::new(85, 2))?;
+    /// assert_eq!(rate, Rational::new(17, 20));
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    #[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+    pub fn try_from_decimal(value: &Decimal) -> MoneyResult<Self> {
+        10_i128
+            .checked_pow(value.scale())
+            .and_then(|denominator| Self::reduce(value.mantissa(), denominator))
+            .ok_or_else(|| out_of_range(value))
+    }
+
+    /// Tries to convert a decimal into an exact fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the decimal has too many
+    /// digits to be represented with an `i128` numerator and denominator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::Rational;
+    /// use bigdecimal::BigDecimal;
+    ///
+    /// let rate = Rational::try_from_decimal(&"0.85".parse::<BigDecimal>().unwrap())?;
+    /// assert_eq!(rate, Rational::new(17, 20));
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    #[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+    pub fn try_from_decimal(value: &Decimal) -> MoneyResult<Self> {
+        use bigdecimal::ToPrimitive;
+
+        let (digits, exponent) = value.normalized().as_bigint_and_exponent();
+        let digits = digits.to_i128().ok_or_else(|| out_of_range(value))?;
+        let power = u32::try_from(exponent.unsigned_abs())
+            .ok()
+            .and_then(|exponent| 10_i128.checked_pow(exponent));
+        let fraction = match power {
+            Some(power) if exponent >= 0 => Self::reduce(digits, power),
+            Some(power) => digits
+                .checked_mul(power)
+                .and_then(|numerator| Self::reduce(numerator, 1)),
+            None => None,
+        };
+        fraction.ok_or_else(|| out_of_range(value))
+    }
+
+    /// Rounds the value to `scale` decimal places with the given mode.
+    ///
+    /// This is the only place where a rational value loses precision.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the rounded value does not fit
+    /// in the decimal backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Rational, RoundingMode};
+    ///
+    /// let two_thirds = Rational::new(-2, 3);
+    /// assert_eq!(two_thirds.to_decimal(2, RoundingMode::HalfUp)?.to_string(), "-0.67");
+    /// assert_eq!(two_thirds.to_decimal(2, RoundingMode::Down)?.to_string(), "-0.66");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_decimal(&self, scale: u32, mode: RoundingMode) -> MoneyResult<Decimal> {
+        10_i128
+            .checked_pow(scale)
+            .and_then(|power| self.numerator.checked_mul(power))
+            .and_then(|scaled| round_quotient(scaled, self.denominator, mode))
+            .and_then(|units| decimal_from_units(units, scale))
+            .ok_or_else(|| MoneyError::InvalidAmount {
+                reason: format!(
+                    "{} does not fit in the decimal backend at {} decimal places",
+                    self, scale
+                ),
+                currency: None,
+            })
+    }
+
+    /// Builds a reduced fraction with a positive denominator.
+    fn reduce(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let gcd = gcd(numerator.unsigned_abs(), denominator.unsigned_abs());
+        let magnitude = i128::try_from(numerator.unsigned_abs() / gcd).ok()?;
+        let denominator_magnitude = i128::try_from(denominator.unsigned_abs() / gcd).ok()?;
+        let negative = (numerator < 0) != (denominator < 0);
+        Some(Self {
+            numerator: if negative { -magnitude } else { magnitude },
+            denominator: denominator_magnitude,
+        })
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self {
+            numerator: i128::from(value),
+            denominator: 1,
+        }
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        self.checked_add(&other)
+            .expect("Rational addition overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self.checked_sub(&other)
+            .expect("Rational subtraction overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        self.checked_mul(&other)
+            .expect("Rational multiplication overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// Panics if `other` is zero or on overflow.
+    fn div(self, other: Rational) -> Rational {
+        assert!(!other.is_zero(), "Division by zero");
+        self.checked_div(&other)
+            .expect("Rational division overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("Rational negation overflow")
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn out_of_range(value: &Decimal) -> MoneyError {
+    MoneyError::InvalidAmount {
+        reason: format!("{} does not fit in a rational", value),
+        currency: None,
+    }
+}
+
+/// Divides `numerator` by a positive `denominator` and rounds the quotient to
+/// an integer, with the same semantics as decimal rounding.
+fn round_quotient(numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    let quotient = numerator / denominator;
+    let remainder = (numerator % denominator).unsigned_abs();
+    if remainder == 0 {
+        return Some(quotient);
+    }
+
+    let negative = numerator < 0;
+    let half = remainder.cmp(&(denominator.unsigned_abs() - remainder));
+    let tie = |odd_wins: bool| match half {
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => (quotient % 2 != 0) != odd_wins,
+    };
+    let away = match mode {
+        RoundingMode::Up => true,
+        RoundingMode::Down => false,
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceiling => !negative,
+        RoundingMode::HalfUp | RoundingMode::HalfAwayFromZero => half.is_ge(),
+        RoundingMode::HalfDown | RoundingMode::HalfTowardZero => half.is_gt(),
+        // On a tie, round away only if that lands on an even (resp. odd) digit
+        RoundingMode::HalfEven => tie(false),
+        RoundingMode::HalfOdd => tie(true),
+        RoundingMode::Round05Up => quotient % 5 == 0,
+        RoundingMode::Stochastic { seed } => {
+            let threshold = fraction_bits(remainder, denominator.unsigned_abs(), 53);
+            crate::rounding::stochastic_draw(seed, quotient, threshold) >> 11 < threshold
+        }
+    };
+
+    if !away {
+        Some(quotient)
+    } else if negative {
+        quotient.checked_sub(1)
+    } else {
+        quotient.checked_add(1)
+    }
+}
+
+/// Returns `floor(remainder / denominator * 2^bits)` for `remainder < denominator`.
+fn fraction_bits(mut remainder: u128, denominator: u128, bits: u32) -> u64 {
+    let mut result = 0_u64;
+    for _ in 0..bits {
+        // Compare before doubling so the remainder never overflows
+        let bit = remainder >= denominator - remainder;
+        remainder = if bit {
+            remainder - (denominator - remainder)
+        } else {
+            remainder * 2
+        };
+        result = (result << 1) | u64::from(bit);
+    }
+    result
+}
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+fn decimal_from_units(units: i128, scale: u32) -> Option<Decimal> {
+    Decimal::try_from_i128_with_scale(units, scale).ok()
+}
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+fn decimal_from_units(units: i128, scale: u32) -> Option<Decimal> {
+    Some(Decimal::new(units.into(), i64::from(scale)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Amount, USD};
+
+    #[test]
+    fn test_new_reduces_and_normalizes_sign() {
+        let value = Rational::new(6, -8);
+        assert_eq!(value.numerator(), -3);
+        assert_eq!(value.denominator(), 4);
+        assert_eq!(Rational::new(0, -5), Rational::ZERO);
+    }
+
+    #[test]
+    fn test_zero_denominator_is_rejected() {
+        let err = Rational::try_new(1, 0).unwrap_err();
+        assert!(matches!(err, MoneyError::InvalidAmount { .. }));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid rational")]
+    fn test_new_panics_on_zero_denominator() {
+        let _ = Rational::new(1, 0);
+    }
+
+    #[test]
+    fn test_arithmetic_is_exact() {
+        let third = Rational::new(1, 3);
+        let sixth = Rational::new(1, 6);
+        assert_eq!(third + sixth, Rational::new(1, 2));
+        assert_eq!(third - sixth, sixth);
+        assert_eq!(third * Rational::from(3), Rational::ONE);
+        assert_eq!(Rational::ONE / Rational::from(3), third);
+        assert_eq!(-third, Rational::new(-1, 3));
+    }
+
+    #[test]
+    fn test_checked_arithmetic_overflow() {
+        let huge = Rational::new(i128::MAX, 1);
+        assert_eq!(huge.checked_add(&Rational::ONE), None);
+        assert_eq!(huge.checked_mul(&Rational::from(2)), None);
+        assert_eq!(Rational::ONE.checked_div(&Rational::ZERO), None);
+        assert!(Rational::try_new(i128::MIN, 1).is_err());
+    }
+
+    #[test]
+    fn test_recip_round_trips() {
+        let rate = Rational::try_from_decimal(&Decimal::new(85, 2)).unwrap();
+        let inverse = rate.checked_recip().unwrap();
+        assert_eq!(inverse, Rational::new(20, 17));
+        assert_eq!(inverse.checked_recip(), Some(rate));
+        assert_eq!(Rational::ZERO.checked_recip(), None);
+    }
+
+    #[test]
+    fn test_try_from_decimal() {
+        let value = Rational::try_from_decimal(&Decimal::new(-12_500, 3)).unwrap();
+        assert_eq!(value, Rational::new(-25, 2));
+        assert_eq!(
+            Rational::try_from_decimal(&Decimal::ZERO).unwrap(),
+            Rational::ZERO
+        );
+    }
+
+    #[test]
+    fn test_to_decimal_matches_decimal_rounding() {
+        let modes = [
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+            RoundingMode::HalfOdd,
+            RoundingMode::HalfAwayFromZero,
+            RoundingMode::HalfTowardZero,
+            RoundingMode::Up,
+            RoundingMode::Down,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+            RoundingMode::Round05Up,
+        ];
+        let values = [
+            12_345_i64, -12_345, 12_355, -12_355, 12_341, -12_349, 12_301, 12_351,
+        ];
+        for mode in modes {
+            for mantissa in values {
+                let decimal = Decimal::new(mantissa, 3);
+                let rational = Rational::try_from_decimal(&decimal).unwrap();
+                assert_eq!(
+                    rational.to_decimal(2, mode).unwrap(),
+                    *Amount::<USD>::new(decimal).round_to_scale(2, mode).value(),
+                    "{} with {:?}",
+                    decimal,
+                    mode
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_decimal_non_terminating() {
+        let two_thirds = Rational::new(2, 3);
+        assert_eq!(
+            two_thirds.to_decimal(4, RoundingMode::HalfEven).unwrap(),
+            Decimal::new(6667, 4)
+        );
+        assert_eq!(
+            two_thirds.to_decimal(0, RoundingMode::Floor).unwrap(),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_to_decimal_stochastic_matches_decimal_rounding() {
+        let decimal = Decimal::new(12_345, 3);
+        let rational = Rational::try_from_decimal(&decimal).unwrap();
+        for seed in 0..20 {
+            let mode = RoundingMode::Stochastic { seed };
+            let expected = *Amount::<USD>::new(decimal).round_to_scale(2, mode).value();
+            assert_eq!(rational.to_decimal(2, mode).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_to_decimal_out_of_range() {
+        let huge = Rational::new(i128::MAX, 1);
+        assert!(huge.to_decimal(2, RoundingMode::HalfEven).is_err());
+    }
+
+    #[test]
+    fn test_fraction_bits() {
+        assert_eq!(fraction_bits(1, 2, 53), 1 << 52);
+        assert_eq!(fraction_bits(1, 4, 53), 1 << 51);
+        assert_eq!(fraction_bits(u128::MAX - 1, u128::MAX, 4), 0b1111);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Rational::new(10, 4).to_string(), "5/2");
+        assert_eq!(Rational::from(-7).to_string(), "-7");
+    }
+}
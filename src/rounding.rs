@@ -8,10 +8,16 @@
 //!
 //! - **HalfEven** (Banker's Rounding) - Recommended for most financial applications
 //!   as it minimizes cumulative rounding errors
-//! - **HalfUp** - Common in retail and consumer applications
+//! - **HalfUp** / **HalfAwayFromZero** - Common in retail and consumer applications
+//! - **HalfDown** / **HalfTowardZero** - Ties favour the smaller magnitude
+//! - **HalfOdd** - Ties to the odd neighbour, used by some legacy systems
 //! - **Floor/Ceiling** - Useful for conservative estimates (always round down/up)
 //! - **Down** (Truncate) - Fast but can accumulate errors
 //! - **Up** - Conservative rounding away from zero
+//! - **Round05Up** - Truncation that preserves information for later re-rounding
+//!   (IEEE 754-2008 / General Decimal Arithmetic)
+//! - **Stochastic** - Seeded probabilistic rounding for unbiased aggregates of
+//!   many small amounts
 //!
 //! # Examples
 //!
@@ -59,15 +65,21 @@
 /// Different rounding strategies are appropriate for different use cases.
 /// Financial applications often use `HalfEven` (banker's rounding) to minimize bias.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde_support",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[non_exhaustive]
 pub enum RoundingMode {
-    /// Round half towards positive infinity.
+    /// Round half away from zero.
     ///
-    /// Also known as "round half up" or "arithmetic rounding".
+    /// Also known as "round half up" or "arithmetic rounding". Ties on negative
+    /// numbers move further from zero; use `HalfAwayFromZero` to make that explicit.
     ///
     /// # Examples
     /// - 2.5 → 3
     /// - 2.4 → 2
-    /// - -2.5 → -2
+    /// - -2.5 → -3
     HalfUp,
 
     /// Round half towards zero.
@@ -89,7 +101,7 @@ pub enum RoundingMode {
     /// - 4.5 → 4 (even)
     HalfEven,
 
-    /// Round towards positive infinity (always round up).
+    /// Round away from zero (always round up in magnitude).
     ///
     /// Always rounds away from zero, regardless of sign.
     ///
@@ -132,6 +144,95 @@ pub enum RoundingMode {
     /// - -2.1 → -2
     /// - -2.9 → -2
     Ceiling,
+
+    /// Round half to nearest odd number.
+    ///
+    /// The mirror image of `HalfEven`.
+    ///
+    /// # Examples
+    /// - 2.5 → 3 (odd)
+    /// - 3.5 → 3 (odd)
+    /// - -2.5 → -3 (odd)
+    HalfOdd,
+
+    /// Round half away from zero.
+    ///
+    /// Same results as `HalfUp`, with a name that states the behaviour on
+    /// negative numbers.
+    ///
+    /// # Examples
+    /// - 2.5 → 3
+    /// - -2.5 → -3
+    HalfAwayFromZero,
+
+    /// Round half towards zero.
+    ///
+    /// Same results as `HalfDown`, with a name that states the behaviour on
+    /// negative numbers.
+    ///
+    /// # Examples
+    /// - 2.5 → 2
+    /// - -2.5 → -2
+    HalfTowardZero,
+
+    /// Round towards zero, unless the last kept digit would be 0 or 5, in which
+    /// case round away from zero.
+    ///
+    /// This is the `ROUND_05UP` mode of IEEE 754-2008 and the General Decimal
+    /// Arithmetic specification. Exact values are never changed.
+    ///
+    /// # Examples
+    /// - 2.1 → 2
+    /// - 5.1 → 6
+    /// - 10.9 → 11
+    /// - -5.1 → -6
+    Round05Up,
+
+    /// Round up or down at random, with a probability proportional to the
+    /// distance from each neighbour.
+    ///
+    /// 2.3 rounds to 3 with probability 0.3 and to 2 with probability 0.7. The
+    /// draw is derived from `seed` and the value alone, so the same value with
+    /// the same seed always rounds the same way, on any thread. To keep the
+    /// total of many equal amounts unbiased, give each one its own seed (for
+    /// example the line-item index). Exact values are never changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, USD, RoundingMode};
+    ///
+    /// let fee = Amount::<USD>::from_minor(1) / 4; // $0.0025
+    /// let mode = RoundingMode::Stochastic { seed: 7 };
+    /// assert_eq!(fee.round(mode), fee.round(mode));
+    ///
+    /// let total: i64 = (0..1_000)
+    ///     .map(|seed| fee.round(RoundingMode::Stochastic { seed }).to_minor())
+    ///     .sum();
+    /// assert!((150..=350).contains(&total)); // 250 cents expected
+    /// ```
+    Stochastic {
+        /// Seed mixed into the pseudo-random draw
+        seed: u64,
+    },
+}
+
+/// Returns the pseudo-random draw for a stochastic rounding.
+///
+/// `units` is the value truncated to the target scale, in units of that scale,
+/// and `fraction` the discarded part in units of 2^-53. The draw depends only
+/// on these and `seed`, which are mixed with the SplitMix64 finalizer.
+pub(crate) fn stochastic_draw(seed: u64, units: i128, fraction: u64) -> u64 {
+    [units as u64, (units >> 64) as u64, fraction]
+        .into_iter()
+        .fold(seed, |state, word| split_mix(state ^ word))
+}
+
+fn split_mix(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
 #[cfg(test)]
@@ -150,4 +251,32 @@ mod tests {
         assert_eq!(RoundingMode::HalfUp, RoundingMode::HalfUp);
         assert_ne!(RoundingMode::HalfUp, RoundingMode::HalfDown);
     }
+
+    #[test]
+    fn test_stochastic_draw_is_deterministic() {
+        assert_eq!(stochastic_draw(42, 1_234, 7), stochastic_draw(42, 1_234, 7));
+        assert_ne!(stochastic_draw(42, 1_234, 7), stochastic_draw(43, 1_234, 7));
+        assert_ne!(
+            stochastic_draw(42, 1_234, 7),
+            stochastic_draw(42, -1_234, 7)
+        );
+        assert_ne!(stochastic_draw(42, 1_234, 7), stochastic_draw(42, 1_234, 8));
+    }
+
+    #[cfg(feature = "serde_support")]
+    #[test]
+    fn test_rounding_mode_serde() {
+        assert_eq!(
+            serde_json::to_string(&RoundingMode::HalfEven).unwrap(),
+            r#""HalfEven""#
+        );
+        let stochastic = RoundingMode::Stochastic { seed: 7 };
+        let json = serde_json::to_string(&stochastic).unwrap();
+        assert_eq!(json, r#"{"Stochastic":{"seed":7}}"#);
+        assert_eq!(
+            serde_json::from_str::<RoundingMode>(&json).unwrap(),
+            stochastic
+        );
+        assert!(serde_json::from_str::<RoundingMode>(r#""Sideways""#).is_err());
+    }
 }
@@ -0,0 +1,552 @@
+//! Alternative serde representations for [`Amount`], selectable per field.
+//!
+//! `Amount<C>` serializes as `{"value":"12.34","currency":"USD"}` by
+//! default. The modules below use other shapes with
+//! `#[serde(with = "...")]`:
+//!
+//! | Module | Shape |
+//! |--------|-------|
+//! | [`string`] | `"12.34"` |
+//! | [`number`] | `12.34` |
+//! | [`minor_units`] | `1234` |
+//! | [`compact`] | `"12.34 USD"` |
+//! | [`object`] | `{"amount":"12.34","currency":"USD"}` |
+//! | [`lenient`] | writes `"12.34"`, reads any string [`Amount::parse`] accepts or a number |
+//!
+//! Shapes that carry a currency code reject other currencies on
+//! deserialize. Every module validates against the current
+//! [`AmountPolicy`].
+//!
+//! [`Rate`](crate::Rate), [`RoundingMode`](crate::RoundingMode), the currency
+//! metadata enums, [`MoneyError`](crate::MoneyError) and the conversion
+//! tracking events implement `Serialize` and `Deserialize` directly. Their
+//! currency codes must be known currencies. Rate sources are interned, see
+//! [`register_rate_source`].
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use typed_money::{Amount, EUR, USD};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Invoice {
+//!     #[serde(with = "typed_money::serde::minor_units")]
+//!     total: Amount<USD>,
+//!     #[serde(with = "typed_money::serde::compact")]
+//!     fee: Amount<EUR>,
+//! }
+//!
+//! let invoice: Invoice = serde_json::from_str(r#"{"total":1234,"fee":"0.50 EUR"}"#)?;
+//! assert_eq!(invoice.total.to_minor(), 1234);
+//! assert_eq!(
+//!     serde_json::to_string(&invoice)?,
+//!     r#"{"total":1234,"fee":"0.50 EUR"}"#
+//! );
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::amount::cloned_value;
+use crate::currency::with_known_currencies;
+use crate::{Amount, AmountPolicy, Currency};
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::Serializer;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+#[cfg(all(feature = "use_rust_decimal", not(feature = "use_bigdecimal")))]
+use rust_decimal::Decimal;
+
+#[cfg(all(feature = "use_bigdecimal", not(feature = "use_rust_decimal")))]
+use bigdecimal::BigDecimal as Decimal;
+
+/// Rate sources seen so far, registered or interned on deserialize.
+static RATE_SOURCES: RwLock<BTreeSet<&'static str>> = RwLock::new(BTreeSet::new());
+
+/// Registers a rate source, so that [`Rate`](crate::Rate)s and conversion
+/// events naming it deserialize to `source` itself.
+///
+/// Rates keep their source as a `&'static str`. Deserializing a source that
+/// has not been seen before interns a copy of it, which lives for the rest
+/// of the process; each distinct source is stored once. Registering the
+/// sources an application uses up front avoids those copies.
+///
+/// # Examples
+///
+/// ```
+/// use typed_money::{Rate, EUR, USD};
+///
+/// typed_money::serde::register_rate_source("ECB");
+///
+/// let rate: Rate<USD, EUR> =
+///     serde_json::from_str(r#"{"from":"USD","to":"EUR","rate":"0.85","source":"ECB"}"#)?;
+/// assert_eq!(rate.source(), Some("ECB"));
+///
+/// // Other sources are interned
+/// let desk = r#"{"from":"USD","to":"EUR","rate":"0.85","source":"Desk"}"#;
+/// assert_eq!(serde_json::from_str::<Rate<USD, EUR>>(desk)?.source(), Some("Desk"));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn register_rate_source(source: &'static str) {
+    RATE_SOURCES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(source);
+}
+
+/// Returns the rate source equal to `text`, interning it if it is new.
+pub(crate) fn rate_source(text: &str) -> &'static str {
+    let known = RATE_SOURCES
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .get(text)
+        .copied();
+    known.unwrap_or_else(|| {
+        let mut sources = RATE_SOURCES
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Another thread may have interned it in the meantime
+        match sources.get(text) {
+            Some(source) => source,
+            None => {
+                let source: &'static str = Box::leak(text.into());
+                sources.insert(source);
+                source
+            }
+        }
+    })
+}
+
+/// Returns the code of the known currency whose code is `text`.
+pub(crate) fn currency_code<E: de::Error>(text: &str) -> Result<&'static str, E> {
+    with_known_currencies(|mut currencies| {
+        currencies
+            .find(|info| info.code() == text)
+            .map(|info| info.code())
+    })
+    .ok_or_else(|| E::custom(format!("Unknown currency code '{}'", text)))
+}
+
+/// Deserializes a known currency code, for `#[serde(deserialize_with)]`.
+pub(crate) fn deserialize_code<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<&'static str, D::Error> {
+    currency_code(&<String as ::serde::Deserialize>::deserialize(
+        deserializer,
+    )?)
+}
+
+/// Deserializes an optional known currency code, for
+/// `#[serde(deserialize_with)]`.
+pub(crate) fn deserialize_optional_code<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<&'static str>, D::Error> {
+    <Option<String> as ::serde::Deserialize>::deserialize(deserializer)?
+        .map(|code| currency_code(&code))
+        .transpose()
+}
+
+/// Builds an amount from a deserialized value, applying the current policy.
+fn validated<C: Currency, E: de::Error>(input: &str, value: Decimal) -> Result<Amount<C>, E> {
+    AmountPolicy::with_current(|policy| {
+        if input.len() > policy.max_input_len() {
+            return Err(E::custom(format!(
+                "max_input_len: Value too long (max {} characters)",
+                policy.max_input_len()
+            )));
+        }
+        let amount = Amount::new(value);
+        policy.check_value(input, &amount).map_err(E::custom)?;
+        Ok(amount)
+    })
+}
+
+fn parse_decimal<E: de::Error>(text: &str) -> Result<Decimal, E> {
+    Decimal::from_str(text).map_err(|_| E::custom(format!("Invalid decimal value: '{}'", text)))
+}
+
+fn check_currency<C: Currency, E: de::Error>(code: &str) -> Result<(), E> {
+    if code == C::CODE {
+        Ok(())
+    } else {
+        Err(E::custom(format!(
+            "Currency mismatch: expected {}, found {}",
+            C::CODE,
+            code
+        )))
+    }
+}
+
+/// Reads a JSON number (or, leniently, a string) into an amount.
+struct NumberVisitor<C: Currency> {
+    strings: bool,
+    _currency: std::marker::PhantomData<C>,
+}
+
+impl<C: Currency> NumberVisitor<C> {
+    fn new(strings: bool) -> Self {
+        Self {
+            strings,
+            _currency: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<C: Currency> Visitor<'_> for NumberVisitor<C> {
+    type Value = Amount<C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.strings {
+            write!(f, "a {} amount as a number or string", C::CODE)
+        } else {
+            write!(f, "a {} amount as a number", C::CODE)
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        validated(&value.to_string(), Decimal::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        validated(&value.to_string(), Decimal::from(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        if !value.is_finite() {
+            return Err(E::custom("Amount must be a finite number"));
+        }
+        // The shortest representation that reads back as the same f64
+        let text = value.to_string();
+        validated(&text, parse_decimal(&text)?)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        if !self.strings {
+            return Err(E::invalid_type(de::Unexpected::Str(value), &self));
+        }
+        Amount::parse(value).map_err(E::custom)
+    }
+}
+
+/// A bare decimal string: `"12.34"`.
+///
+/// The currency is not recorded, so any currency of the field's type is
+/// assumed on deserialize.
+pub mod string {
+    use super::*;
+
+    /// Serializes the amount as a decimal string.
+    pub fn serialize<C: Currency, S: Serializer>(
+        amount: &Amount<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount.value())
+    }
+
+    /// Deserializes an amount from a decimal string.
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount<C>, D::Error> {
+        let text = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        validated(&text, parse_decimal(&text)?)
+    }
+}
+
+/// A JSON number: `12.34`.
+///
+/// Whole amounts are written as integers. Others go through `f64`, which
+/// keeps about 15 significant digits; use [`string`] or [`minor_units`] where
+/// that is not enough.
+pub mod number {
+    use super::*;
+
+    /// Serializes the amount as a number.
+    pub fn serialize<C: Currency, S: Serializer>(
+        amount: &Amount<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let text = amount.value().to_string();
+        if let Ok(whole) = text.parse::<i64>() {
+            return serializer.serialize_i64(whole);
+        }
+        let value = text
+            .parse::<f64>()
+            .map_err(|_| ::serde::ser::Error::custom(format!("Not a number: '{}'", text)))?;
+        serializer.serialize_f64(value)
+    }
+
+    /// Deserializes an amount from a number.
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount<C>, D::Error> {
+        deserializer.deserialize_any(NumberVisitor::new(false))
+    }
+}
+
+/// Integer minor units: `1234` for $12.34.
+pub mod minor_units {
+    use super::*;
+
+    /// Serializes the amount as whole minor units.
+    ///
+    /// Fails if the amount has more decimal places than the currency or does
+    /// not fit in an `i64`.
+    pub fn serialize<C: Currency, S: Serializer>(
+        amount: &Amount<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let minor = amount.checked_to_minor().ok_or_else(|| {
+            ::serde::ser::Error::custom(format!(
+                "{} {} does not fit in i64 minor units",
+                amount.value(),
+                C::CODE
+            ))
+        })?;
+        if Amount::<C>::from_minor(minor).value() != amount.value() {
+            return Err(::serde::ser::Error::custom(format!(
+                "{} {} is not a whole number of minor units; round it first",
+                amount.value(),
+                C::CODE
+            )));
+        }
+        serializer.serialize_i64(minor)
+    }
+
+    /// Deserializes an amount from whole minor units.
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount<C>, D::Error> {
+        let minor = <i64 as ::serde::Deserialize>::deserialize(deserializer)?;
+        let amount = Amount::<C>::from_minor(minor);
+        validated(&minor.to_string(), cloned_value(amount.value()))
+    }
+}
+
+/// The amount followed by its code: `"12.34 USD"`.
+pub mod compact {
+    use super::*;
+
+    /// Serializes the amount as `"<value> <code>"`.
+    pub fn serialize<C: Currency, S: Serializer>(
+        amount: &Amount<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{} {}", amount.value(), C::CODE))
+    }
+
+    /// Deserializes an amount from `"<value> <code>"`, rejecting other
+    /// currencies.
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount<C>, D::Error> {
+        let text = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+        let (value, code) = text.trim().rsplit_once(' ').ok_or_else(|| {
+            de::Error::custom(format!("Expected '<value> {}', found '{}'", C::CODE, text))
+        })?;
+        check_currency::<C, D::Error>(code)?;
+        validated(&text, parse_decimal(value.trim_end())?)
+    }
+}
+
+/// An object with `amount` and `currency` keys:
+/// `{"amount":"12.34","currency":"USD"}`.
+pub mod object {
+    use super::*;
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Object<T> {
+        amount: T,
+        currency: String,
+    }
+
+    /// Serializes the amount as an `{amount, currency}` object.
+    pub fn serialize<C: Currency, S: Serializer>(
+        amount: &Amount<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        Object {
+            amount: amount.value().to_string(),
+            currency: C::CODE.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes an amount from an `{amount, currency}` object, rejecting
+    /// other currencies.
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount<C>, D::Error> {
+        let object = Object::<String>::deserialize(deserializer)?;
+        check_currency::<C, D::Error>(&object.currency)?;
+        validated(&object.amount, parse_decimal(&object.amount)?)
+    }
+}
+
+/// Writes a bare decimal string; reads a number or any string that
+/// [`Amount::parse`] accepts, such as `"12.34"`, `"$12.34"` or
+/// `"12.34 USD"`.
+pub mod lenient {
+    use super::*;
+
+    /// Serializes the amount as a decimal string.
+    pub fn serialize<C: Currency, S: Serializer>(
+        amount: &Amount<C>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::string::serialize(amount, serializer)
+    }
+
+    /// Deserializes an amount from a number or a string.
+    pub fn deserialize<'de, C: Currency, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Amount<C>, D::Error> {
+        deserializer.deserialize_any(NumberVisitor::new(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Amount, AmountPolicy, JPY, USD};
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Shapes {
+        #[serde(with = "super::string")]
+        string: Amount<USD>,
+        #[serde(with = "super::number")]
+        number: Amount<USD>,
+        #[serde(with = "super::minor_units")]
+        minor: Amount<USD>,
+        #[serde(with = "super::compact")]
+        compact: Amount<USD>,
+        #[serde(with = "super::object")]
+        object: Amount<USD>,
+        #[serde(with = "super::lenient")]
+        lenient: Amount<USD>,
+    }
+
+    fn usd(minor: i64) -> Amount<USD> {
+        Amount::from_minor(minor)
+    }
+
+    #[test]
+    fn test_round_trip_all_shapes() {
+        let shapes = Shapes {
+            string: usd(1234),
+            number: usd(1234),
+            minor: usd(1234),
+            compact: usd(1234),
+            object: usd(1234),
+            lenient: usd(1234),
+        };
+        let json = serde_json::to_string(&shapes).unwrap();
+        assert_eq!(
+            json,
+            r#"{"string":"12.34","number":12.34,"minor":1234,"compact":"12.34 USD","object":{"amount":"12.34","currency":"USD"},"lenient":"12.34"}"#
+        );
+        assert_eq!(serde_json::from_str::<Shapes>(&json).unwrap(), shapes);
+    }
+
+    macro_rules! read {
+        ($module:literal, $json:expr) => {{
+            #[derive(Debug, Deserialize)]
+            struct Field {
+                #[serde(with = $module)]
+                #[allow(dead_code)]
+                value: Amount<USD>,
+            }
+            serde_json::from_str::<Field>(&format!(r#"{{"value":{}}}"#, $json))
+                .map(|field| field.value)
+                .map_err(|e| e.to_string())
+        }};
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(read!("super::number", "12"), Ok(usd(1200)));
+        assert_eq!(read!("super::number", "-0.5"), Ok(usd(-50)));
+        assert!(read!("super::number", r#""12.34""#).is_err());
+
+        #[derive(Serialize)]
+        struct Field {
+            #[serde(with = "super::number")]
+            value: Amount<JPY>,
+        }
+        let json = serde_json::to_string(&Field {
+            value: Amount::from_major(500),
+        })
+        .unwrap();
+        assert_eq!(json, r#"{"value":500}"#);
+    }
+
+    #[test]
+    fn test_minor_units() {
+        assert_eq!(read!("super::minor_units", "-250"), Ok(usd(-250)));
+        assert!(read!("super::minor_units", "2.5").is_err());
+
+        #[derive(Serialize)]
+        struct Field {
+            #[serde(with = "super::minor_units")]
+            value: Amount<USD>,
+        }
+        let precise = Field {
+            value: usd(1000) / 3,
+        };
+        let err = serde_json::to_string(&precise).unwrap_err();
+        assert!(err.to_string().contains("minor units"));
+
+        // Too large for i64 minor units, and for the scaling itself
+        for major in ["92233720368547758.08", "79228162514264337593543950335"] {
+            let huge = Field {
+                value: major.parse().unwrap(),
+            };
+            let err = serde_json::to_string(&huge).unwrap_err();
+            assert!(err.to_string().contains("does not fit"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_currency_is_validated() {
+        assert!(read!("super::compact", r#""12.34 EUR""#)
+            .unwrap_err()
+            .contains("Currency mismatch"));
+        assert!(read!("super::compact", r#""12.34""#).is_err());
+        assert!(
+            read!("super::object", r#"{"amount":"12.34","currency":"EUR"}"#)
+                .unwrap_err()
+                .contains("Currency mismatch")
+        );
+        assert!(read!(
+            "super::object",
+            r#"{"amount":"1","currency":"USD","extra":1}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_lenient() {
+        assert_eq!(read!("super::lenient", "12.34"), Ok(usd(1234)));
+        assert_eq!(read!("super::lenient", "7"), Ok(usd(700)));
+        assert_eq!(read!("super::lenient", r#""$12.34""#), Ok(usd(1234)));
+        assert_eq!(read!("super::lenient", r#""12.34 USD""#), Ok(usd(1234)));
+        assert!(read!("super::lenient", r#""€12.34""#).is_err());
+        assert!(read!("super::lenient", "true").is_err());
+    }
+
+    #[test]
+    fn test_policy_applies_to_every_shape() {
+        AmountPolicy::new().without_negatives().scope(|| {
+            assert!(read!("super::string", r#""-1""#).is_err());
+            assert!(read!("super::number", "-1").is_err());
+            assert!(read!("super::minor_units", "-1").is_err());
+            assert!(read!("super::compact", r#""-1 USD""#).is_err());
+            assert!(read!("super::object", r#"{"amount":"-1","currency":"USD"}"#).is_err());
+            assert!(read!("super::lenient", "-1").is_err());
+            assert!(read!("super::lenient", r#""-1""#).is_err());
+        });
+    }
+}
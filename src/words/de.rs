@@ -0,0 +1,156 @@
+//! German number words, long scale.
+
+use super::{thousands, Noun};
+
+const UNITS: [&str; 20] = [
+    "null",
+    "eins",
+    "zwei",
+    "drei",
+    "vier",
+    "fünf",
+    "sechs",
+    "sieben",
+    "acht",
+    "neun",
+    "zehn",
+    "elf",
+    "zwölf",
+    "dreizehn",
+    "vierzehn",
+    "fünfzehn",
+    "sechzehn",
+    "siebzehn",
+    "achtzehn",
+    "neunzehn",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+];
+
+/// Powers of a thousand from a million up, all feminine: singular and
+/// plural.
+const SCALES: [(&str, &str); 13] = [
+    ("", ""),
+    ("", ""),
+    ("Million", "Millionen"),
+    ("Milliarde", "Milliarden"),
+    ("Billion", "Billionen"),
+    ("Billiarde", "Billiarden"),
+    ("Trillion", "Trillionen"),
+    ("Trilliarde", "Trilliarden"),
+    ("Quadrillion", "Quadrillionen"),
+    ("Quadrilliarde", "Quadrilliarden"),
+    ("Quintillion", "Quintillionen"),
+    ("Quintilliarde", "Quintilliarden"),
+    ("Sextillion", "Sextillionen"),
+];
+
+/// Spells out 1 to 999 as one word.
+fn below_thousand(n: u32) -> String {
+    let mut word = String::new();
+    if n >= 100 {
+        word.push_str(match n / 100 {
+            1 => "ein",
+            h => UNITS[h as usize],
+        });
+        word.push_str("hundert");
+    }
+    let rest = n % 100;
+    match rest {
+        0 => {}
+        1..=19 => word.push_str(UNITS[rest as usize]),
+        _ => {
+            match rest % 10 {
+                0 => {}
+                1 => word.push_str("einund"),
+                units => {
+                    word.push_str(UNITS[units as usize]);
+                    word.push_str("und");
+                }
+            }
+            word.push_str(TENS[(rest / 10) as usize]);
+        }
+    }
+    word
+}
+
+/// Spells out 1 to 999 999 as one word.
+fn below_million(n: u32) -> String {
+    match (n / 1000, n % 1000) {
+        (0, rest) => below_thousand(rest),
+        (1, rest) => format!("eintausend{}", below_thousand(rest)),
+        (thousands, rest) => format!(
+            "{}tausend{}",
+            below_thousand(thousands),
+            below_thousand(rest)
+        ),
+    }
+}
+
+/// Spells out `n`; millions and above are separate words.
+fn number(n: u128) -> Option<String> {
+    if n == 0 {
+        return Some(UNITS[0].to_string());
+    }
+    let groups = thousands(n);
+    if groups.len() > SCALES.len() {
+        return None;
+    }
+    let mut words = Vec::new();
+    for scale in (2..groups.len()).rev() {
+        match groups[scale] {
+            0 => {}
+            1 => words.push(format!("eine {}", SCALES[scale].0)),
+            group => words.push(format!("{} {}", below_thousand(group), SCALES[scale].1)),
+        }
+    }
+    let rest = groups[0] + groups.get(1).map_or(0, |thousands| thousands * 1000);
+    if rest > 0 {
+        words.push(below_million(rest));
+    }
+    Some(words.join(" "))
+}
+
+pub(super) fn count(n: u128, noun: &Noun) -> Option<String> {
+    if n == 1 {
+        let one = if noun.feminine { "eine" } else { "ein" };
+        return Some(format!("{} {}", one, noun.one));
+    }
+    Some(format!("{} {}", number(n)?, noun.other))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let spell = |n| number(n).unwrap();
+        assert_eq!(spell(0), "null");
+        assert_eq!(spell(1), "eins");
+        assert_eq!(spell(17), "siebzehn");
+        assert_eq!(spell(21), "einundzwanzig");
+        assert_eq!(spell(30), "dreißig");
+        assert_eq!(spell(101), "einhunderteins");
+        assert_eq!(spell(1_234), "eintausendzweihundertvierunddreißig");
+        assert_eq!(spell(21_000), "einundzwanzigtausend");
+        assert_eq!(spell(1_000_000), "eine Million");
+        assert_eq!(spell(2_500_000), "zwei Millionen fünfhunderttausend");
+        assert_eq!(spell(3_000_000_000), "drei Milliarden");
+        assert!(number(u128::MAX).is_some());
+    }
+
+    #[test]
+    fn test_count() {
+        let krone = Noun {
+            one: "Krone",
+            other: "Kronen",
+            feminine: true,
+        };
+        assert_eq!(count(1, &krone).unwrap(), "eine Krone");
+        assert_eq!(count(2, &krone).unwrap(), "zwei Kronen");
+        assert_eq!(count(1_000_000, &krone).unwrap(), "eine Million Kronen");
+    }
+}
@@ -0,0 +1,111 @@
+//! English number words, short scale.
+
+use super::{thousands, Noun};
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [&str; 13] = [
+    "",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Spells out 1 to 999.
+fn below_thousand(n: u32) -> String {
+    let mut words = Vec::new();
+    if n >= 100 {
+        words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+    }
+    let rest = n % 100;
+    if rest >= 20 {
+        let tens = TENS[(rest / 10) as usize];
+        words.push(match rest % 10 {
+            0 => tens.to_string(),
+            ones => format!("{}-{}", tens, ONES[ones as usize]),
+        });
+    } else if rest > 0 {
+        words.push(ONES[rest as usize].to_string());
+    }
+    words.join(" ")
+}
+
+/// Spells out `n`.
+pub(super) fn number(n: u128) -> Option<String> {
+    if n == 0 {
+        return Some(ONES[0].to_string());
+    }
+    let groups = thousands(n);
+    if groups.len() > SCALES.len() {
+        return None;
+    }
+    let words: Vec<String> = groups
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, group)| **group > 0)
+        .map(|(scale, group)| match SCALES[scale] {
+            "" => below_thousand(*group),
+            name => format!("{} {}", below_thousand(*group), name),
+        })
+        .collect();
+    Some(words.join(" "))
+}
+
+pub(super) fn count(n: u128, noun: &Noun) -> Option<String> {
+    let name = if n == 1 { noun.one } else { noun.other };
+    Some(format!("{} {}", number(n)?, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let spell = |n| number(n).unwrap();
+        assert_eq!(spell(0), "zero");
+        assert_eq!(spell(13), "thirteen");
+        assert_eq!(spell(40), "forty");
+        assert_eq!(spell(99), "ninety-nine");
+        assert_eq!(spell(100), "one hundred");
+        assert_eq!(spell(101), "one hundred one");
+        assert_eq!(spell(1_000_001), "one million one");
+        assert_eq!(spell(2_000_300_000), "two billion three hundred thousand");
+        assert!(number(u128::MAX).is_some());
+    }
+}
@@ -0,0 +1,210 @@
+//! Spanish number words, long scale.
+
+use super::Noun;
+
+const UNITS: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+/// Powers of a million: singular and plural.
+const SCALES: [(&str, &str); 7] = [
+    ("", ""),
+    ("millón", "millones"),
+    ("billón", "billones"),
+    ("trillón", "trillones"),
+    ("cuatrillón", "cuatrillones"),
+    ("quintillón", "quintillones"),
+    ("sextillón", "sextillones"),
+];
+
+const MILLION: u128 = 1_000_000;
+
+/// Spells out 1 to 99 in front of a word, where "uno" shortens to "un" or
+/// agrees as "una".
+fn below_hundred(n: u32, feminine: bool) -> String {
+    let one = if feminine { "una" } else { "un" };
+    match n {
+        1 => one.to_string(),
+        21 if feminine => "veintiuna".to_string(),
+        21 => "veintiún".to_string(),
+        0..=29 => UNITS[n as usize].to_string(),
+        _ => match n % 10 {
+            0 => TENS[(n / 10) as usize].to_string(),
+            1 => format!("{} y {}", TENS[(n / 10) as usize], one),
+            units => format!("{} y {}", TENS[(n / 10) as usize], UNITS[units as usize]),
+        },
+    }
+}
+
+/// Spells out 1 to 999; hundreds agree with feminine nouns.
+fn below_thousand(n: u32, feminine: bool) -> String {
+    let hundreds = n / 100;
+    let rest = n % 100;
+    let hundreds = match (hundreds, rest) {
+        (0, _) => String::new(),
+        (1, 0) => "cien".to_string(),
+        (1, _) => HUNDREDS[1].to_string(),
+        (h, _) if feminine => HUNDREDS[h as usize].replace("ientos", "ientas"),
+        (h, _) => HUNDREDS[h as usize].to_string(),
+    };
+    match rest {
+        0 => hundreds,
+        _ if hundreds.is_empty() => below_hundred(rest, feminine),
+        _ => format!("{} {}", hundreds, below_hundred(rest, feminine)),
+    }
+}
+
+/// Spells out 1 to 999 999.
+fn below_million(n: u32, feminine: bool) -> String {
+    let thousands = n / 1000;
+    let rest = n % 1000;
+    let thousands = match thousands {
+        0 => String::new(),
+        1 => "mil".to_string(),
+        t => format!("{} mil", below_thousand(t, feminine)),
+    };
+    match rest {
+        0 => thousands,
+        _ if thousands.is_empty() => below_thousand(rest, feminine),
+        _ => format!("{} {}", thousands, below_thousand(rest, feminine)),
+    }
+}
+
+/// Spells out `n` in front of a noun of the given gender.
+fn number(n: u128, feminine: bool) -> Option<String> {
+    if n == 0 {
+        return Some(UNITS[0].to_string());
+    }
+    let mut chunks = Vec::new();
+    let mut rest = n;
+    while rest > 0 {
+        chunks.push((rest % MILLION) as u32);
+        rest /= MILLION;
+    }
+    if chunks.len() > SCALES.len() {
+        return None;
+    }
+    let words: Vec<String> = chunks
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, chunk)| **chunk > 0)
+        .map(|(scale, chunk)| match (scale, chunk) {
+            (0, chunk) => below_million(*chunk, feminine),
+            (scale, 1) => format!("un {}", SCALES[scale].0),
+            (scale, chunk) => format!("{} {}", below_million(*chunk, false), SCALES[scale].1),
+        })
+        .collect();
+    Some(words.join(" "))
+}
+
+pub(super) fn count(n: u128, noun: &Noun) -> Option<String> {
+    let number = number(n, noun.feminine)?;
+    let name = if n == 1 { noun.one } else { noun.other };
+    Some(if n >= MILLION && n % MILLION == 0 {
+        format!("{} de {}", number, name)
+    } else {
+        format!("{} {}", number, name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let spell = |n| number(n, false).unwrap();
+        assert_eq!(spell(0), "cero");
+        assert_eq!(spell(16), "dieciséis");
+        assert_eq!(spell(21), "veintiún");
+        assert_eq!(spell(31), "treinta y un");
+        assert_eq!(spell(100), "cien");
+        assert_eq!(spell(101), "ciento un");
+        assert_eq!(spell(500), "quinientos");
+        assert_eq!(spell(1_000), "mil");
+        assert_eq!(spell(21_000), "veintiún mil");
+        assert_eq!(spell(1_000_000), "un millón");
+        assert_eq!(spell(2_500_000), "dos millones quinientos mil");
+        assert_eq!(spell(1_000_000_000), "mil millones");
+        assert_eq!(spell(3_000_000_000_000), "tres billones");
+        assert!(number(u128::MAX, false).is_some());
+    }
+
+    #[test]
+    fn test_feminine() {
+        let spell = |n| number(n, true).unwrap();
+        assert_eq!(spell(1), "una");
+        assert_eq!(spell(21), "veintiuna");
+        assert_eq!(spell(41), "cuarenta y una");
+        assert_eq!(spell(200_200), "doscientas mil doscientas");
+        assert_eq!(spell(200_000_000), "doscientos millones");
+    }
+
+    #[test]
+    fn test_count() {
+        let peso = Noun {
+            one: "peso",
+            other: "pesos",
+            feminine: false,
+        };
+        assert_eq!(count(1, &peso).unwrap(), "un peso");
+        assert_eq!(count(1_000_000, &peso).unwrap(), "un millón de pesos");
+        assert_eq!(count(1_000_001, &peso).unwrap(), "un millón un pesos");
+    }
+}
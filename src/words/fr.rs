@@ -0,0 +1,155 @@
+//! French number words in traditional spelling, long scale.
+
+use super::{thousands, Noun};
+
+const UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze",
+    "douze", "treize", "quatorze", "quinze", "seize",
+];
+
+const TENS: [&str; 6] = ["", "", "vingt", "trente", "quarante", "cinquante"];
+
+/// Powers of a thousand above "mille", alternating -illion and -illiard.
+const SCALES: [&str; 13] = [
+    "",
+    "mille",
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "quadrillion",
+    "quadrilliard",
+    "quintillion",
+    "quintilliard",
+    "sextillion",
+];
+
+const MILLION: u128 = 1_000_000;
+
+/// Spells out 1 to 99. `last` is false when "mille" follows, which keeps
+/// "quatre-vingt" singular.
+fn below_hundred(n: u32, last: bool, feminine: bool) -> String {
+    let one = if feminine { "une" } else { "un" };
+    match n {
+        1 => one.to_string(),
+        0..=16 => UNITS[n as usize].to_string(),
+        17..=19 => format!("dix-{}", UNITS[(n - 10) as usize]),
+        20..=59 => match n % 10 {
+            0 => TENS[(n / 10) as usize].to_string(),
+            1 => format!("{} et {}", TENS[(n / 10) as usize], one),
+            units => format!("{}-{}", TENS[(n / 10) as usize], UNITS[units as usize]),
+        },
+        60 => "soixante".to_string(),
+        61 | 71 => format!("soixante et {}", below_hundred(n - 60, last, feminine)),
+        62..=79 => format!("soixante-{}", below_hundred(n - 60, last, feminine)),
+        80 if last => "quatre-vingts".to_string(),
+        80 => "quatre-vingt".to_string(),
+        _ => format!("quatre-vingt-{}", below_hundred(n - 80, last, feminine)),
+    }
+}
+
+/// Spells out 1 to 999; "cents" takes its plural only at the end.
+fn below_thousand(n: u32, last: bool, feminine: bool) -> String {
+    let hundreds = n / 100;
+    let rest = n % 100;
+    let hundreds = match (hundreds, rest) {
+        (0, _) => String::new(),
+        (1, _) => "cent".to_string(),
+        (h, 0) if last => format!("{} cents", UNITS[h as usize]),
+        (h, _) => format!("{} cent", UNITS[h as usize]),
+    };
+    match rest {
+        0 => hundreds,
+        _ if hundreds.is_empty() => below_hundred(rest, last, feminine),
+        _ => format!("{} {}", hundreds, below_hundred(rest, last, feminine)),
+    }
+}
+
+/// Spells out `n` in front of a noun of the given gender.
+fn number(n: u128, feminine: bool) -> Option<String> {
+    if n == 0 {
+        return Some(UNITS[0].to_string());
+    }
+    let groups = thousands(n);
+    if groups.len() > SCALES.len() {
+        return None;
+    }
+    let words: Vec<String> = groups
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, group)| **group > 0)
+        .map(|(scale, group)| match (scale, *group) {
+            (0, group) => below_thousand(group, true, feminine),
+            (1, 1) => "mille".to_string(),
+            (1, group) => format!("{} mille", below_thousand(group, false, false)),
+            (scale, 1) => format!("un {}", SCALES[scale]),
+            (scale, group) => format!("{} {}s", below_thousand(group, true, false), SCALES[scale]),
+        })
+        .collect();
+    Some(words.join(" "))
+}
+
+pub(super) fn count(n: u128, noun: &Noun) -> Option<String> {
+    let number = number(n, noun.feminine)?;
+    let name = if n < 2 { noun.one } else { noun.other };
+    Some(if n >= MILLION && n % MILLION == 0 {
+        if name.starts_with(['a', 'e', 'i', 'o', 'u', 'é']) {
+            format!("{} d'{}", number, name)
+        } else {
+            format!("{} de {}", number, name)
+        }
+    } else {
+        format!("{} {}", number, name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let spell = |n| number(n, false).unwrap();
+        assert_eq!(spell(0), "zéro");
+        assert_eq!(spell(17), "dix-sept");
+        assert_eq!(spell(21), "vingt et un");
+        assert_eq!(spell(22), "vingt-deux");
+        assert_eq!(spell(70), "soixante-dix");
+        assert_eq!(spell(71), "soixante et onze");
+        assert_eq!(spell(77), "soixante-dix-sept");
+        assert_eq!(spell(80), "quatre-vingts");
+        assert_eq!(spell(81), "quatre-vingt-un");
+        assert_eq!(spell(91), "quatre-vingt-onze");
+        assert_eq!(spell(200), "deux cents");
+        assert_eq!(spell(201), "deux cent un");
+        assert_eq!(spell(1_000), "mille");
+        assert_eq!(spell(80_000), "quatre-vingt mille");
+        assert_eq!(spell(200_000), "deux cent mille");
+        assert_eq!(spell(200_000_000), "deux cents millions");
+        assert_eq!(spell(1_000_000_000), "un milliard");
+        assert!(number(u128::MAX, false).is_some());
+    }
+
+    #[test]
+    fn test_count() {
+        let livre = Noun {
+            one: "livre",
+            other: "livres",
+            feminine: true,
+        };
+        assert_eq!(count(1, &livre).unwrap(), "une livre");
+        assert_eq!(count(31, &livre).unwrap(), "trente et une livres");
+        assert_eq!(count(1_000_000, &livre).unwrap(), "un million de livres");
+
+        let euro = Noun {
+            one: "euro",
+            other: "euros",
+            feminine: false,
+        };
+        assert_eq!(count(0, &euro).unwrap(), "zéro euro");
+        assert_eq!(count(3_000_000, &euro).unwrap(), "trois millions d'euros");
+    }
+}
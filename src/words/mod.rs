@@ -0,0 +1,609 @@
+//! Spelling out amounts in words, for cheques and legal documents.
+//!
+//! [`Amount::to_words`] writes the major and minor units in full;
+//! [`Amount::to_cheque_words`] writes the minor units as a fraction, the way
+//! cheques are filled in:
+//!
+//! ```
+//! use typed_money::{Amount, Language, BRL, USD};
+//!
+//! let usd = Amount::<USD>::from_minor(123_456);
+//! assert_eq!(
+//!     usd.to_cheque_words(Language::English)?,
+//!     "One thousand two hundred thirty-four dollars and 56/100"
+//! );
+//!
+//! let brl = Amount::<BRL>::from_minor(123_456);
+//! assert_eq!(
+//!     brl.to_words(Language::Portuguese)?,
+//!     "mil duzentos e trinta e quatro reais e cinquenta e seis centavos"
+//! );
+//! # Ok::<(), typed_money::MoneyError>(())
+//! ```
+//!
+//! Unit names come from the currency's `MAJOR_UNIT_NAME` and
+//! `MINOR_UNIT_NAME`. They are English; common units (dollar, euro, pound,
+//! peso, real, ...) are translated, and other names are used as they are.
+//! A currency without a major unit name is written with its code, and one
+//! without a minor unit name gets the fraction form.
+
+mod de;
+mod en;
+mod es;
+mod fr;
+mod pt;
+
+use crate::{Amount, Currency, MoneyError, MoneyResult, UnitName};
+
+/// Languages that amounts can be spelled out in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// English (one thousand two hundred thirty-four)
+    English,
+    /// Spanish (mil doscientos treinta y cuatro)
+    Spanish,
+    /// Brazilian Portuguese (mil duzentos e trinta e quatro)
+    Portuguese,
+    /// French (mille deux cent trente-quatre)
+    French,
+    /// German (eintausendzweihundertvierunddreißig)
+    German,
+}
+
+impl Language {
+    /// Word for a negative amount.
+    fn minus(self) -> &'static str {
+        match self {
+            Language::English | Language::German => "minus",
+            Language::Spanish | Language::Portuguese => "menos",
+            Language::French => "moins",
+        }
+    }
+
+    /// Word joining the major and minor units.
+    fn and(self) -> &'static str {
+        match self {
+            Language::English => "and",
+            Language::Spanish => "con",
+            Language::Portuguese => "e",
+            Language::French => "et",
+            Language::German => "und",
+        }
+    }
+
+    /// Spells out `count` followed by the noun in the right form, or `None`
+    /// if the number is too large for the language's scale words.
+    fn count(self, count: u128, noun: &Noun) -> Option<String> {
+        match self {
+            Language::English => en::count(count, noun),
+            Language::Spanish => es::count(count, noun),
+            Language::Portuguese => pt::count(count, noun),
+            Language::French => fr::count(count, noun),
+            Language::German => de::count(count, noun),
+        }
+    }
+}
+
+/// A unit name in one language.
+#[derive(Debug, Clone, Copy)]
+struct Noun {
+    one: &'static str,
+    other: &'static str,
+    feminine: bool,
+}
+
+const fn m(one: &'static str, other: &'static str) -> Noun {
+    Noun {
+        one,
+        other,
+        feminine: false,
+    }
+}
+
+const fn f(one: &'static str, other: &'static str) -> Noun {
+    Noun {
+        one,
+        other,
+        feminine: true,
+    }
+}
+
+/// Translations of common English unit names: Spanish, Portuguese, French
+/// and German.
+const TRANSLATIONS: &[(&str, [Noun; 4])] = &[
+    (
+        "dollar",
+        [
+            m("dólar", "dólares"),
+            m("dólar", "dólares"),
+            m("dollar", "dollars"),
+            m("Dollar", "Dollar"),
+        ],
+    ),
+    (
+        "cent",
+        [
+            m("centavo", "centavos"),
+            m("centavo", "centavos"),
+            m("cent", "cents"),
+            m("Cent", "Cent"),
+        ],
+    ),
+    (
+        "euro",
+        [
+            m("euro", "euros"),
+            m("euro", "euros"),
+            m("euro", "euros"),
+            m("Euro", "Euro"),
+        ],
+    ),
+    (
+        "real",
+        [
+            m("real", "reales"),
+            m("real", "reais"),
+            m("réal", "réaux"),
+            m("Real", "Real"),
+        ],
+    ),
+    (
+        "centavo",
+        [
+            m("centavo", "centavos"),
+            m("centavo", "centavos"),
+            m("centavo", "centavos"),
+            m("Centavo", "Centavos"),
+        ],
+    ),
+    (
+        "peso",
+        [
+            m("peso", "pesos"),
+            m("peso", "pesos"),
+            m("peso", "pesos"),
+            m("Peso", "Pesos"),
+        ],
+    ),
+    (
+        "pound",
+        [
+            f("libra", "libras"),
+            f("libra", "libras"),
+            f("livre", "livres"),
+            m("Pfund", "Pfund"),
+        ],
+    ),
+    (
+        "penny",
+        [
+            m("penique", "peniques"),
+            m("pêni", "pence"),
+            m("penny", "pence"),
+            m("Penny", "Pence"),
+        ],
+    ),
+    (
+        "yen",
+        [
+            m("yen", "yenes"),
+            m("iene", "ienes"),
+            m("yen", "yens"),
+            m("Yen", "Yen"),
+        ],
+    ),
+    (
+        "franc",
+        [
+            m("franco", "francos"),
+            m("franco", "francos"),
+            m("franc", "francs"),
+            m("Franken", "Franken"),
+        ],
+    ),
+    (
+        "centime",
+        [
+            m("céntimo", "céntimos"),
+            m("cêntimo", "cêntimos"),
+            m("centime", "centimes"),
+            m("Rappen", "Rappen"),
+        ],
+    ),
+    (
+        "rupee",
+        [
+            f("rupia", "rupias"),
+            f("rupia", "rupias"),
+            f("roupie", "roupies"),
+            f("Rupie", "Rupien"),
+        ],
+    ),
+    (
+        "yuan",
+        [
+            m("yuan", "yuanes"),
+            m("yuan", "yuans"),
+            m("yuan", "yuans"),
+            m("Yuan", "Yuan"),
+        ],
+    ),
+    (
+        "krona",
+        [
+            f("corona", "coronas"),
+            f("coroa", "coroas"),
+            f("couronne", "couronnes"),
+            f("Krone", "Kronen"),
+        ],
+    ),
+    (
+        "krone",
+        [
+            f("corona", "coronas"),
+            f("coroa", "coroas"),
+            f("couronne", "couronnes"),
+            f("Krone", "Kronen"),
+        ],
+    ),
+    (
+        "lira",
+        [
+            f("lira", "liras"),
+            f("lira", "liras"),
+            f("livre", "livres"),
+            f("Lira", "Lira"),
+        ],
+    ),
+    (
+        "dinar",
+        [
+            m("dinar", "dinares"),
+            m("dinar", "dinares"),
+            m("dinar", "dinars"),
+            m("Dinar", "Dinar"),
+        ],
+    ),
+    (
+        "shilling",
+        [
+            m("chelín", "chelines"),
+            m("xelim", "xelins"),
+            m("shilling", "shillings"),
+            m("Schilling", "Schilling"),
+        ],
+    ),
+];
+
+/// Returns the noun for `name` in `language`, translating common units.
+fn noun(name: UnitName, language: Language) -> Noun {
+    let index = match language {
+        Language::English => None,
+        Language::Spanish => Some(0),
+        Language::Portuguese => Some(1),
+        Language::French => Some(2),
+        Language::German => Some(3),
+    };
+    index
+        .and_then(|index| {
+            TRANSLATIONS
+                .iter()
+                .find(|(english, _)| *english == name.singular())
+                .map(|(_, nouns)| nouns[index])
+        })
+        .unwrap_or(m(name.singular(), name.plural()))
+}
+
+/// Splits `n` into groups of three digits, least significant first.
+fn thousands(mut n: u128) -> Vec<u32> {
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push((n % 1000) as u32);
+        n /= 1000;
+    }
+    groups
+}
+
+impl<C: Currency> Amount<C> {
+    /// Spells the amount out in words, major and minor units in full.
+    ///
+    /// The amount is first rounded to the currency's decimal places with its
+    /// `DEFAULT_ROUNDING`. Minor units are left out when there are none.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the amount is too large to be
+    /// named with the language's scale words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, Language, EUR, GBP, USD};
+    ///
+    /// let amount = Amount::<USD>::from_minor(123_456);
+    /// assert_eq!(
+    ///     amount.to_words(Language::English)?,
+    ///     "one thousand two hundred thirty-four dollars and fifty-six cents"
+    /// );
+    /// assert_eq!(
+    ///     amount.to_words(Language::Spanish)?,
+    ///     "mil doscientos treinta y cuatro dólares con cincuenta y seis centavos"
+    /// );
+    ///
+    /// let amount = Amount::<EUR>::from_major(2_000_000);
+    /// assert_eq!(amount.to_words(Language::French)?, "deux millions d'euros");
+    /// assert_eq!(amount.to_words(Language::German)?, "zwei Millionen Euro");
+    ///
+    /// let amount = Amount::<GBP>::from_minor(101);
+    /// assert_eq!(amount.to_words(Language::English)?, "one pound and one penny");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_words(&self, language: Language) -> MoneyResult<String> {
+        self.spell(language, false)
+    }
+
+    /// Spells the amount out the way cheques are written: the major units in
+    /// words, starting with a capital letter, and the minor units as a
+    /// fraction.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidAmount`] if the amount is too large to be
+    /// named with the language's scale words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_money::{Amount, Language, EUR, JPY};
+    ///
+    /// let amount = Amount::<EUR>::from_major(1_500);
+    /// assert_eq!(
+    ///     amount.to_cheque_words(Language::German)?,
+    ///     "Eintausendfünfhundert Euro und 00/100"
+    /// );
+    ///
+    /// let amount = Amount::<JPY>::from_major(21);
+    /// assert_eq!(amount.to_cheque_words(Language::Spanish)?, "Veintiún yenes");
+    /// # Ok::<(), typed_money::MoneyError>(())
+    /// ```
+    pub fn to_cheque_words(&self, language: Language) -> MoneyResult<String> {
+        let words = self.spell(language, true)?;
+        let mut chars = words.chars();
+        Ok(match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => words,
+        })
+    }
+
+    fn spell(&self, language: Language, fraction: bool) -> MoneyResult<String> {
+        let too_large = || MoneyError::InvalidAmount {
+            reason: "Amount is too large to spell out in words".to_string(),
+            currency: Some(C::CODE),
+        };
+
+        let decimals = u32::from(C::DECIMALS);
+        let plain = self
+            .round_to_scale(decimals, C::DEFAULT_ROUNDING)
+            .format_plain();
+        let (negative, unsigned) = match plain.strip_prefix('-') {
+            Some(unsigned) => (unsigned.chars().any(|ch| matches!(ch, '1'..='9')), unsigned),
+            None => (false, plain.as_str()),
+        };
+        let (major_digits, minor_digits) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let major: u128 = major_digits.parse().map_err(|_| too_large())?;
+
+        let major_noun = if C::MAJOR_UNIT_NAME.is_empty() {
+            m(C::CODE, C::CODE)
+        } else {
+            noun(C::MAJOR_UNIT_NAME, language)
+        };
+        let mut words = language.count(major, &major_noun).ok_or_else(too_large)?;
+        if negative {
+            words = format!("{} {}", language.minus(), words);
+        }
+
+        if !minor_digits.is_empty() {
+            let minor: u128 = minor_digits.parse().map_err(|_| too_large())?;
+            if fraction || C::MINOR_UNIT_NAME.is_empty() {
+                let denominator = format!("1{}", "0".repeat(minor_digits.len()));
+                words = format!(
+                    "{} {} {}/{}",
+                    words,
+                    language.and(),
+                    minor_digits,
+                    denominator
+                );
+            } else if minor > 0 {
+                let minor_noun = noun(C::MINOR_UNIT_NAME, language);
+                let minor_words = language.count(minor, &minor_noun).ok_or_else(too_large)?;
+                words = format!("{} {} {}", words, language.and(), minor_words);
+            }
+        }
+
+        Ok(words)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BHD, BRL, BTC, CHF, EUR, GBP, INR, JPY, SEK, USD, XAU};
+
+    #[test]
+    fn test_to_words_every_language() {
+        let amount = Amount::<USD>::from_minor(123_456);
+        assert_eq!(
+            amount.to_words(Language::English).unwrap(),
+            "one thousand two hundred thirty-four dollars and fifty-six cents"
+        );
+        assert_eq!(
+            amount.to_words(Language::Spanish).unwrap(),
+            "mil doscientos treinta y cuatro dólares con cincuenta y seis centavos"
+        );
+        assert_eq!(
+            amount.to_words(Language::Portuguese).unwrap(),
+            "mil duzentos e trinta e quatro dólares e cinquenta e seis centavos"
+        );
+        assert_eq!(
+            amount.to_words(Language::French).unwrap(),
+            "mille deux cent trente-quatre dollars et cinquante-six cents"
+        );
+        assert_eq!(
+            amount.to_words(Language::German).unwrap(),
+            "eintausendzweihundertvierunddreißig Dollar und sechsundfünfzig Cent"
+        );
+    }
+
+    #[test]
+    fn test_to_words_request_examples() {
+        assert_eq!(
+            Amount::<USD>::from_minor(123_456)
+                .to_cheque_words(Language::English)
+                .unwrap(),
+            "One thousand two hundred thirty-four dollars and 56/100"
+        );
+        assert_eq!(
+            Amount::<BRL>::from_minor(123_456)
+                .to_words(Language::Portuguese)
+                .unwrap(),
+            "mil duzentos e trinta e quatro reais e cinquenta e seis centavos"
+        );
+    }
+
+    #[test]
+    fn test_singular_and_zero() {
+        let one = Amount::<EUR>::from_minor(101);
+        assert_eq!(
+            one.to_words(Language::English).unwrap(),
+            "one euro and one cent"
+        );
+        assert_eq!(
+            one.to_words(Language::Spanish).unwrap(),
+            "un euro con un centavo"
+        );
+        assert_eq!(
+            one.to_words(Language::Portuguese).unwrap(),
+            "um euro e um centavo"
+        );
+        assert_eq!(
+            one.to_words(Language::French).unwrap(),
+            "un euro et un cent"
+        );
+        assert_eq!(
+            one.to_words(Language::German).unwrap(),
+            "ein Euro und ein Cent"
+        );
+
+        let zero = Amount::<EUR>::from_minor(5);
+        assert_eq!(
+            zero.to_words(Language::English).unwrap(),
+            "zero euros and five cents"
+        );
+        assert_eq!(
+            zero.to_words(Language::French).unwrap(),
+            "zéro euro et cinq cents"
+        );
+        assert_eq!(
+            Amount::<EUR>::from_major(0)
+                .to_words(Language::German)
+                .unwrap(),
+            "null Euro"
+        );
+    }
+
+    #[test]
+    fn test_feminine_units() {
+        let pounds = Amount::<GBP>::from_major(21);
+        assert_eq!(
+            pounds.to_words(Language::Spanish).unwrap(),
+            "veintiuna libras"
+        );
+        assert_eq!(
+            pounds.to_words(Language::Portuguese).unwrap(),
+            "vinte e uma libras"
+        );
+        assert_eq!(
+            pounds.to_words(Language::French).unwrap(),
+            "vingt et une livres"
+        );
+
+        let rupees = Amount::<INR>::from_major(200);
+        assert_eq!(
+            rupees.to_words(Language::Spanish).unwrap(),
+            "doscientas rupias"
+        );
+        assert_eq!(
+            Amount::<SEK>::from_major(1)
+                .to_words(Language::German)
+                .unwrap(),
+            "eine Krone"
+        );
+    }
+
+    #[test]
+    fn test_negative_amounts() {
+        let amount = Amount::<CHF>::from_minor(-250);
+        assert_eq!(
+            amount.to_words(Language::English).unwrap(),
+            "minus two francs and fifty centimes"
+        );
+        assert_eq!(
+            amount.to_words(Language::French).unwrap(),
+            "moins deux francs et cinquante centimes"
+        );
+        assert_eq!(
+            amount.to_cheque_words(Language::Spanish).unwrap(),
+            "Menos dos francos con 50/100"
+        );
+    }
+
+    #[test]
+    fn test_other_decimal_places() {
+        assert_eq!(
+            Amount::<JPY>::from_major(10_000)
+                .to_cheque_words(Language::English)
+                .unwrap(),
+            "Ten thousand yen"
+        );
+        assert_eq!(
+            Amount::<BHD>::from_minor(1_005)
+                .to_words(Language::English)
+                .unwrap(),
+            "one dinar and five fils"
+        );
+        assert_eq!(
+            Amount::<BTC>::from_minor(150_000_000)
+                .to_cheque_words(Language::English)
+                .unwrap(),
+            "One bitcoin and 50000000/100000000"
+        );
+    }
+
+    #[test]
+    fn test_currency_without_unit_names() {
+        let amount = Amount::<XAU>::from_minor(31_250);
+        assert_eq!(
+            amount.to_words(Language::English).unwrap(),
+            "three XAU and 1250/10000"
+        );
+    }
+
+    #[test]
+    fn test_rounds_with_default_rounding() {
+        // USD rounds half-even by default
+        let amount = Amount::<USD>::from_minor(1) / 2;
+        assert_eq!(amount.to_words(Language::English).unwrap(), "zero dollars");
+    }
+
+    #[test]
+    fn test_large_amounts() {
+        let amount = Amount::<USD>::from_major(i64::MAX);
+        assert_eq!(
+            amount.to_words(Language::English).unwrap(),
+            "nine quintillion two hundred twenty-three quadrillion three hundred seventy-two \
+             trillion thirty-six billion eight hundred fifty-four million seven hundred \
+             seventy-five thousand eight hundred seven dollars"
+        );
+        assert!(amount.to_words(Language::German).is_ok());
+    }
+}
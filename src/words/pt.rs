@@ -0,0 +1,193 @@
+//! Brazilian Portuguese number words, short scale.
+
+use super::{thousands, Noun};
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "um",
+    "dois",
+    "três",
+    "quatro",
+    "cinco",
+    "seis",
+    "sete",
+    "oito",
+    "nove",
+    "dez",
+    "onze",
+    "doze",
+    "treze",
+    "catorze",
+    "quinze",
+    "dezesseis",
+    "dezessete",
+    "dezoito",
+    "dezenove",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+/// Powers of a thousand: singular and plural.
+const SCALES: [(&str, &str); 13] = [
+    ("", ""),
+    ("mil", "mil"),
+    ("milhão", "milhões"),
+    ("bilhão", "bilhões"),
+    ("trilhão", "trilhões"),
+    ("quatrilhão", "quatrilhões"),
+    ("quintilhão", "quintilhões"),
+    ("sextilhão", "sextilhões"),
+    ("septilhão", "septilhões"),
+    ("octilhão", "octilhões"),
+    ("nonilhão", "nonilhões"),
+    ("decilhão", "decilhões"),
+    ("undecilhão", "undecilhões"),
+];
+
+const MILLION: u128 = 1_000_000;
+
+/// Spells out 1 to 19, agreeing "um" and "dois" with feminine nouns.
+fn unit(n: u32, feminine: bool) -> &'static str {
+    match n {
+        1 if feminine => "uma",
+        2 if feminine => "duas",
+        _ => UNITS[n as usize],
+    }
+}
+
+/// Spells out 1 to 999, joining the parts with "e".
+fn below_thousand(n: u32, feminine: bool) -> String {
+    let mut parts = Vec::new();
+    let hundreds = n / 100;
+    let rest = n % 100;
+    match (hundreds, rest) {
+        (0, _) => {}
+        (1, 0) => parts.push("cem".to_string()),
+        (h, _) if feminine && h > 1 => parts.push(HUNDREDS[h as usize].replace("entos", "entas")),
+        (h, _) => parts.push(HUNDREDS[h as usize].to_string()),
+    }
+    if rest >= 20 {
+        parts.push(TENS[(rest / 10) as usize].to_string());
+        if rest % 10 != 0 {
+            parts.push(unit(rest % 10, feminine).to_string());
+        }
+    } else if rest > 0 {
+        parts.push(unit(rest, feminine).to_string());
+    }
+    parts.join(" e ")
+}
+
+/// Spells out `n` in front of a noun of the given gender.
+fn number(n: u128, feminine: bool) -> Option<String> {
+    if n == 0 {
+        return Some(UNITS[0].to_string());
+    }
+    let groups = thousands(n);
+    if groups.len() > SCALES.len() {
+        return None;
+    }
+    let nonzero: Vec<(usize, u32)> = groups
+        .iter()
+        .copied()
+        .enumerate()
+        .rev()
+        .filter(|(_, group)| *group > 0)
+        .collect();
+    let mut words = String::new();
+    for (position, (scale, group)) in nonzero.iter().copied().enumerate() {
+        let part = match (scale, group) {
+            (0, group) => below_thousand(group, feminine),
+            (1, 1) => "mil".to_string(),
+            (1, group) => format!("{} mil", below_thousand(group, feminine)),
+            (scale, 1) => format!("um {}", SCALES[scale].0),
+            (scale, group) => format!("{} {}", below_thousand(group, false), SCALES[scale].1),
+        };
+        if position > 0 {
+            let last = position == nonzero.len() - 1;
+            words.push_str(if last && (group < 100 || group % 100 == 0) {
+                " e "
+            } else {
+                " "
+            });
+        }
+        words.push_str(&part);
+    }
+    Some(words)
+}
+
+pub(super) fn count(n: u128, noun: &Noun) -> Option<String> {
+    let number = number(n, noun.feminine)?;
+    let name = if n == 1 { noun.one } else { noun.other };
+    Some(if n >= MILLION && n % MILLION == 0 {
+        format!("{} de {}", number, name)
+    } else {
+        format!("{} {}", number, name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let spell = |n| number(n, false).unwrap();
+        assert_eq!(spell(0), "zero");
+        assert_eq!(spell(16), "dezesseis");
+        assert_eq!(spell(21), "vinte e um");
+        assert_eq!(spell(100), "cem");
+        assert_eq!(spell(101), "cento e um");
+        assert_eq!(spell(1_000), "mil");
+        assert_eq!(spell(1_100), "mil e cem");
+        assert_eq!(spell(1_234), "mil duzentos e trinta e quatro");
+        assert_eq!(spell(2_005), "dois mil e cinco");
+        assert_eq!(spell(1_500_000), "um milhão e quinhentos mil");
+        assert_eq!(spell(2_000_000_000), "dois bilhões");
+        assert!(number(u128::MAX, false).is_some());
+    }
+
+    #[test]
+    fn test_feminine() {
+        let spell = |n| number(n, true).unwrap();
+        assert_eq!(spell(1), "uma");
+        assert_eq!(spell(2), "duas");
+        assert_eq!(spell(22), "vinte e duas");
+        assert_eq!(spell(200_200), "duzentas mil e duzentas");
+        assert_eq!(spell(2_000_000), "dois milhões");
+    }
+
+    #[test]
+    fn test_count() {
+        let real = Noun {
+            one: "real",
+            other: "reais",
+            feminine: false,
+        };
+        assert_eq!(count(1, &real).unwrap(), "um real");
+        assert_eq!(count(3_000_000, &real).unwrap(), "três milhões de reais");
+    }
+}